@@ -5,8 +5,9 @@
 //! - Tokio runtime inspection
 //! - Future handling
 //! - Concurrent task debugging
+//! - Feature-gated code (`--features tracing`)
 
-use tokio::time::{sleep, Duration};
+use tokio::time::{sleep, Duration, Instant};
 
 #[tokio::main]
 async fn main() {
@@ -48,15 +49,28 @@ async fn fetch_data(id: u32) -> String {
 
 async fn async_task(task_id: u32) -> u32 {
     println!("Task {} starting", task_id);
+    let started = Instant::now();
     
-    // Simulate async work
+    // Simulate async work; `task_id`, `started` and `delay` are kept in the
+    // task's future while it is suspended here
     let delay = Duration::from_millis(task_id as u64 * 100);
     sleep(delay).await;
     
-    println!("Task {} completed", task_id);
+    trace_task(task_id, started.elapsed());
+    println!("Task {} completed after sleeping {:?}", task_id, delay);
     task_id * 10
 }
 
+/// Only compiled with `--features tracing`; set a breakpoint here to check
+/// that the feature reached the build
+#[cfg(feature = "tracing")]
+fn trace_task(task_id: u32, elapsed: Duration) {
+    eprintln!("[trace] task {} finished after {:?}", task_id, elapsed);
+}
+
+#[cfg(not(feature = "tracing"))]
+fn trace_task(_task_id: u32, _elapsed: Duration) {}
+
 async fn process_item(item: u32) {
     println!("Processing item: {}", item);
     sleep(Duration::from_millis(50)).await;
@@ -0,0 +1,21 @@
+//! Binary crate of the workspace example
+//!
+//! Debug this file with `start_debugging`: the adapter finds the `app`
+//! package, builds it and launches `<workspace>/target/debug/app` (or the
+//! directory `CARGO_TARGET_DIR` points to).
+
+use mathlib::{fibonacci, stats};
+
+fn main() {
+    println!("Workspace example");
+
+    let fib: Vec<u64> = (0..10).map(fibonacci).collect();
+    println!("Fibonacci: {:?}", fib);
+
+    let samples = vec![4, 8, 15, 16, 23, 42];
+    let summary = stats(&samples); // Step into the mathlib crate here
+    println!(
+        "count={}, sum={}, mean={:.2}",
+        summary.count, summary.sum, summary.mean
+    );
+}
@@ -0,0 +1,39 @@
+//! Library crate of the workspace example
+//!
+//! Set breakpoints here while debugging `app/src/main.rs` to step across
+//! crate boundaries.
+
+/// Summary statistics of a slice of samples
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub sum: i64,
+    pub mean: f64,
+}
+
+/// Compute the n-th Fibonacci number iteratively
+pub fn fibonacci(n: u32) -> u64 {
+    let mut previous = 0u64;
+    let mut current = 1u64;
+    for _ in 0..n {
+        let next = previous + current; // Inspect previous/current here
+        previous = current;
+        current = next;
+    }
+    previous
+}
+
+/// Compute count, sum and mean of the samples
+pub fn stats(samples: &[i64]) -> Stats {
+    let sum: i64 = samples.iter().sum();
+    let mean = if samples.is_empty() {
+        0.0
+    } else {
+        sum as f64 / samples.len() as f64
+    };
+    Stats {
+        count: samples.len(),
+        sum,
+        mean,
+    }
+}
@@ -0,0 +1,265 @@
+"""
+LLDB formatter for the state machines behind Rust async functions and blocks.
+
+rustc describes the future returned by an `async fn` as an enum-like type
+`path::to::func::{async_fn_env#0}` whose variants are `Unresumed`, `Returned`,
+`Panicked` and one `SuspendN` per `.await` point. Without this formatter a
+debugger shows the raw variant with fields such as `__awaitee` or `__0`.
+
+With it, a future is summarized as e.g.
+
+    app::work (suspended at .await #1, main.rs:56)
+
+and its children are the locals that are live across that `.await`, under
+their source names, plus `[awaiting]` for the future being awaited:
+
+    task_id = 2, started = {...}, delay = 200ms, [awaiting] = tokio::time::sleep::Sleep
+
+The N-th suspend point is mapped to the N-th `.await` in the source of the
+async body, which matches rustc's numbering for code without macros that
+expand to `.await`. Closures and async blocks nested in the body are separate
+state machines with their own numbering, so their `.await`s are skipped.
+"""
+
+import os
+import re
+
+import lldb
+
+CATEGORY = 'rust-async'
+
+# Types of async state machines; brackets instead of backslashes keep the
+# pattern intact through LLDB's command parser
+ENV_TYPE_REGEX = '::[{](async_fn|async_block|async_closure|generator|coroutine)_env#[0-9]+[}](<.+>)?$'
+ENV_TYPE = re.compile(r'^(.*)::\{(async_fn|async_block|async_closure|generator|coroutine)_env#(\d+)\}(<.+>)?$')
+
+AWAIT = re.compile(r'\.await\b')
+UPVAR = re.compile(r'^__(\d+)$')
+
+# Per-type caches: await point locations and async fn parameter names
+_await_points = {}
+_parameters = {}
+
+
+def type_name(value):
+    return value.GetType().GetUnqualifiedType().GetName() or ''
+
+
+def enum_variant(value):
+    """
+    Return (variant name, variant fields) of a Rust enum value.
+
+    LLDB encodes enums as a `$variants$` union of `$variant$N` members holding a
+    `$discr$` and the variant's `value`; the member without `$discr$` is the
+    default (niche) variant.
+    """
+    value = value.GetNonSyntheticValue()
+    variants = value.GetChildAtIndex(0)
+    if variants.GetName() != '$variants$':
+        return None, value
+    chosen = None
+    for i in range(variants.GetNumChildren()):
+        variant = variants.GetChildAtIndex(i)
+        discr = variant.GetChildMemberWithName('$discr$')
+        if not discr.IsValid():
+            if chosen is None:
+                chosen = variant
+        elif variant.GetName() == '$variant$%d' % discr.GetValueAsUnsigned():
+            chosen = variant
+            break
+    if chosen is None:
+        return None, value
+    fields = chosen.GetChildMemberWithName('value')
+    name = type_name(fields).split('::')[-1]
+    if name.endswith('$Variant'):
+        name = name[:-len('$Variant')]
+    return name, fields
+
+
+def describe_future(env_type):
+    """The async fn of a state machine type, or the function containing an async block."""
+    match = ENV_TYPE.match(env_type)
+    if not match:
+        return env_type
+    owner, kind = match.group(1), match.group(2)
+    if kind == 'async_block':
+        return 'async block in %s' % owner
+    if kind == 'async_closure':
+        return 'async closure in %s' % owner
+    return owner
+
+
+def find_function(target, name):
+    """Find a function by its full DWARF name, e.g. `app::work::{async_fn#0}`."""
+    for lookup in (name, name.rsplit('::', 1)[-1]):
+        contexts = target.FindFunctions(lookup)
+        for i in range(contexts.GetSize()):
+            function = contexts.GetContextAtIndex(i).GetFunction()
+            if function.IsValid() and name in (function.GetName(), function.GetDisplayName()):
+                return contexts.GetContextAtIndex(i)
+    return None
+
+
+def is_nested_in(function, body):
+    """Whether `function` is a closure or async block defined inside `body`."""
+    prefix = body + '::'
+    return any((name or '').startswith(prefix) for name in (function.GetName(), function.GetDisplayName()))
+
+
+def await_points(target, env_type):
+    """Source locations of the `.await` expressions of an async body, in order."""
+    if env_type in _await_points:
+        return _await_points[env_type]
+    points = []
+    match = ENV_TYPE.match(env_type)
+    context = None
+    if match:
+        body = '%s::{%s#%s}' % (match.group(1), match.group(2).replace('_env', ''), match.group(3))
+        context = find_function(target, body)
+    if context is not None:
+        function = context.GetFunction()
+        start = function.GetStartAddress()
+        path = start.GetLineEntry().GetFileSpec().fullpath
+        begin, end = start.GetFileAddress(), function.GetEndAddress().GetFileAddress()
+        unit = context.GetCompileUnit()
+        lines = []
+        # (line, column) extent of each nested closure or async block; column 0 means unknown
+        nested = {}
+        for i in range(unit.GetNumLineEntries()):
+            entry = unit.GetLineEntryAtIndex(i)
+            if not entry.GetLine() or entry.GetFileSpec().fullpath != path:
+                continue
+            address = entry.GetStartAddress()
+            # Lines of the body itself, not of code inlined into it
+            if begin <= address.GetFileAddress() < end:
+                lines.append(entry.GetLine())
+                continue
+            inner = address.GetFunction()
+            if inner.IsValid() and is_nested_in(inner, body):
+                line, column = entry.GetLine(), entry.GetColumn()
+                first, last = nested.get(inner.GetName(), ((line, column), (line, column or float('inf'))))
+                nested[inner.GetName()] = (min(first, (line, column)), max(last, (line, column or float('inf'))))
+        if lines and path and os.path.isfile(path):
+            with open(path, encoding='utf-8', errors='replace') as source:
+                text = source.read().split('\n')
+            for number in range(min(lines), max(lines) + 1):
+                code = text[number - 1].split('//', 1)[0] if number <= len(text) else ''
+                for found in AWAIT.finditer(code):
+                    position = (number, found.start() + 1)
+                    if not any(first <= position <= last for first, last in nested.values()):
+                        points.append((path, number))
+    _await_points[env_type] = points
+    return points
+
+
+def parameter_names(target, env_type):
+    """Parameters of an async fn; older rustc names them `__0`, `__1`... in the state machine."""
+    if env_type in _parameters:
+        return _parameters[env_type]
+    names = []
+    match = ENV_TYPE.match(env_type)
+    if match and match.group(2) == 'async_fn':
+        context = find_function(target, match.group(1))
+        if context is not None:
+            arguments = context.GetFunction().GetBlock().GetVariables(target, True, False, False)
+            names = [arguments.GetValueAtIndex(i).GetName() for i in range(arguments.GetSize())]
+    _parameters[env_type] = names
+    return names
+
+
+def suspend_index(variant):
+    match = re.match(r'^Suspend(\d+)$', variant or '')
+    return int(match.group(1)) if match else None
+
+
+class AsyncFutureProvider:
+    """Synthetic children: the locals live at the current suspend point, and `[awaiting]`."""
+
+    def __init__(self, valobj, internal_dict):
+        self.valobj = valobj
+        self.children = []
+        self.update()
+
+    def update(self):
+        self.children = []
+        value = self.valobj.GetNonSyntheticValue()
+        env_type = type_name(value)
+        variant, fields = enum_variant(value)
+        if variant is None:
+            return False
+        target = value.GetTarget()
+        parameters = parameter_names(target, env_type)
+        seen = set()
+        awaitee = None
+        for i in range(fields.GetNumChildren()):
+            field = fields.GetChildAtIndex(i)
+            name = field.GetName()
+            if name == '__awaitee':
+                awaitee = field
+                continue
+            upvar = UPVAR.match(name or '')
+            if upvar and int(upvar.group(1)) < len(parameters):
+                name = parameters[int(upvar.group(1))]
+            # Arguments appear both as upvars and as saved locals
+            if name in seen:
+                continue
+            seen.add(name)
+            self.children.append((name, field))
+        if awaitee is not None:
+            self.children.append(('[awaiting]', awaitee))
+        return False
+
+    def num_children(self):
+        return len(self.children)
+
+    def get_child_index(self, name):
+        for index, (child_name, _) in enumerate(self.children):
+            if child_name == name:
+                return index
+        return -1
+
+    def get_child_at_index(self, index):
+        if index < 0 or index >= len(self.children):
+            return None
+        name, field = self.children[index]
+        address = field.GetLoadAddress()
+        if address != lldb.LLDB_INVALID_ADDRESS:
+            return self.valobj.CreateValueFromAddress(name, address, field.GetType())
+        return self.valobj.CreateValueFromData(name, field.GetData(), field.GetType())
+
+    def has_children(self):
+        return True
+
+
+def summarize_future(valobj, internal_dict):
+    value = valobj.GetNonSyntheticValue()
+    env_type = type_name(value)
+    future = describe_future(env_type)
+    variant, _ = enum_variant(value)
+    if variant == 'Unresumed':
+        return '%s (not started)' % future
+    if variant == 'Returned':
+        return '%s (completed)' % future
+    if variant == 'Panicked':
+        return '%s (panicked)' % future
+    index = suspend_index(variant)
+    if index is None:
+        return future
+    points = await_points(value.GetTarget(), env_type)
+    if index < len(points):
+        path, line = points[index]
+        return '%s (suspended at .await #%d, %s:%d)' % (future, index + 1, os.path.basename(path), line)
+    return '%s (suspended at .await #%d)' % (future, index + 1)
+
+
+def __lldb_init_module(debugger, internal_dict):
+    debugger.HandleCommand(
+        'type synthetic add -x "%s" --python-class async_futures.AsyncFutureProvider --category %s'
+        % (ENV_TYPE_REGEX, CATEGORY)
+    )
+    debugger.HandleCommand(
+        'type summary add -x "%s" --python-function async_futures.summarize_future --expand --category %s'
+        % (ENV_TYPE_REGEX, CATEGORY)
+    )
+    # Enabled last, so it takes priority over the catch-all Rust enum formatters
+    debugger.HandleCommand('type category enable %s' % CATEGORY)
@@ -0,0 +1,327 @@
+"""
+LLDB command that lists the tasks owned by the Tokio runtimes of a stopped process.
+
+    (lldb) command script import /path/to/tokio_tasks.py
+    (lldb) tokio-tasks
+
+The command prints a single JSON line so the MCP debugger can parse it:
+
+    {"runtimes": [{"flavor": "multi_thread", "tasks": [
+        {"id": 2, "state": 204, "spawnLocation": {"file": "src/main.rs", "line": 21, "column": 17},
+         "stage": "Running",
+         "futures": [{"type": "app::work::{async_fn_env#0}", "variant": "Suspend0"},
+                     {"type": "tokio::time::sleep::Sleep"}]}]}]}
+
+`state` is the raw task state word; `futures` is the chain of futures the task
+is suspended in, starting with the spawned future and following `__awaitee`.
+Spawn locations are only recorded by Tokio when built with `--cfg tokio_unstable`.
+
+Runtime handles are found by following the arguments and locals of Tokio frames
+on every thread, so at least one thread must be inside the runtime (a worker
+thread or a `block_on` call).
+"""
+
+import json
+import re
+
+import lldb
+
+from async_futures import enum_variant, type_name
+
+HANDLE_TYPE = re.compile(r'^tokio::runtime::scheduler::(multi_thread|current_thread)(?:::handle)?::Handle$')
+
+# Limits for the search for runtime handles
+MAX_SEARCH_DEPTH = 16
+MAX_SEARCH_NODES = 20000
+
+MAX_TASKS = 10000
+MAX_AWAIT_DEPTH = 32
+
+# Fallback layouts (Tokio 1.x on 64-bit targets) when the types lack debug info
+HEADER_FIELDS = {'state': 0, 'vtable': 16}
+VTABLE_FIELDS = {'poll': 0, 'trailer_offset': 56, 'id_offset': 72}
+
+
+def field_offsets(target, type_name, fallback):
+    sbtype = target.FindFirstType(type_name)
+    if not sbtype.IsValid() or sbtype.GetNumberOfFields() == 0:
+        return dict(fallback)
+    offsets = {}
+    for i in range(sbtype.GetNumberOfFields()):
+        member = sbtype.GetFieldAtIndex(i)
+        offsets[member.GetName()] = member.GetOffsetInBytes()
+    return offsets
+
+
+def find_member(value, name, depth=4):
+    """Find a member by name, looking through wrappers like UnsafeCell or Mutex."""
+    value = value.GetNonSyntheticValue()
+    queue = [(value, 0)]
+    while queue:
+        current, level = queue.pop(0)
+        child = current.GetChildMemberWithName(name)
+        if child.IsValid():
+            return child
+        if level >= depth:
+            continue
+        for i in range(current.GetNumChildren()):
+            nested = current.GetChildAtIndex(i)
+            if not nested.GetType().IsPointerType():
+                queue.append((nested, level + 1))
+    return None
+
+
+def find_typed(value, prefix, depth=6):
+    """Find the first member whose type name starts with prefix."""
+    queue = [(value.GetNonSyntheticValue(), 0)]
+    while queue:
+        current, level = queue.pop(0)
+        if type_name(current).startswith(prefix):
+            return current
+        if level >= depth:
+            continue
+        for i in range(current.GetNumChildren()):
+            nested = current.GetChildAtIndex(i)
+            if not nested.GetType().IsPointerType():
+                queue.append((nested, level + 1))
+    return None
+
+
+def read_pointer(process, address):
+    error = lldb.SBError()
+    pointer = process.ReadPointerFromMemory(address, error)
+    return pointer if error.Success() else 0
+
+
+def read_unsigned(process, address, size=None):
+    error = lldb.SBError()
+    size = size or process.GetAddressByteSize()
+    number = process.ReadUnsignedFromMemory(address, size, error)
+    if not error.Success():
+        raise ValueError('cannot read memory at 0x%x' % address)
+    return number
+
+
+def find_runtime_handles(process):
+    """Search the variables of Tokio frames for scheduler handles."""
+    handles = {}
+    visited = set()
+    nodes = 0
+    queue = []
+    for thread in process:
+        for frame in thread:
+            function = frame.GetFunctionName() or ''
+            if 'tokio::' not in function:
+                continue
+            variables = frame.GetVariables(True, True, False, True)
+            for i in range(variables.GetSize()):
+                queue.append((variables.GetValueAtIndex(i), 0))
+
+    while queue and nodes < MAX_SEARCH_NODES:
+        value, depth = queue.pop(0)
+        value = value.GetNonSyntheticValue()
+        name = type_name(value)
+        key = (value.GetLoadAddress(), name)
+        if key in visited:
+            continue
+        visited.add(key)
+        nodes += 1
+
+        match = HANDLE_TYPE.match(name)
+        if match:
+            handles.setdefault(value.GetLoadAddress(), (match.group(1), value))
+            continue
+        if depth >= MAX_SEARCH_DEPTH:
+            continue
+
+        sbtype = value.GetType()
+        if sbtype.IsPointerType() or sbtype.IsReferenceType():
+            # Only follow pointers into the runtime (Arc<Handle>, &Context, ...)
+            if 'tokio::' in (sbtype.GetPointeeType().GetName() or '') and value.GetValueAsUnsigned():
+                queue.append((value.Dereference(), depth + 1))
+            continue
+        for i in range(value.GetNumChildren()):
+            queue.append((value.GetChildAtIndex(i), depth + 1))
+    return list(handles.values())
+
+
+def unwrap_future(value):
+    """Look through Pin<Box<F>>, Box<F> and &mut F to the future itself."""
+    for _ in range(8):
+        value = value.GetNonSyntheticValue()
+        sbtype = value.GetType()
+        if sbtype.IsPointerType() or sbtype.IsReferenceType():
+            if not value.GetValueAsUnsigned():
+                return value
+            value = value.Dereference()
+        elif type_name(value).startswith('core::pin::Pin<'):
+            value = value.GetChildAtIndex(0)
+        else:
+            return value
+    return value
+
+
+def await_chain(future):
+    chain = []
+    current = unwrap_future(future)
+    while current.IsValid() and len(chain) < MAX_AWAIT_DEPTH:
+        entry = {'type': type_name(current)}
+        chain.append(entry)
+        if '_env#' not in entry['type']:
+            break
+        variant, fields = enum_variant(current)
+        if variant is None:
+            break
+        entry['variant'] = variant
+        awaitee = fields.GetChildMemberWithName('__awaitee')
+        if not awaitee.IsValid():
+            break
+        current = unwrap_future(awaitee)
+    return chain
+
+
+def cell_type_for(target, poll_address, cache):
+    """
+    Each task type has its own vtable whose `poll` is raw::poll::<T, S>; the
+    type of its `harness` local gives Cell<T, S>, the layout of the task.
+    """
+    if poll_address in cache:
+        return cache[poll_address]
+    cell_type = None
+    function = target.ResolveLoadAddress(poll_address).GetFunction()
+    if function.IsValid():
+        blocks = [function.GetBlock()]
+        while blocks and cell_type is None:
+            block = blocks.pop()
+            if not block.IsValid():
+                continue
+            variables = block.GetVariables(target, True, True, False)
+            for i in range(variables.GetSize()):
+                variable = variables.GetValueAtIndex(i)
+                if variable.GetName() == 'harness':
+                    # Harness { cell: NonNull<Cell<T, S>> }
+                    non_null = variable.GetType().GetFieldAtIndex(0).GetType()
+                    cell_type = non_null.GetFieldAtIndex(0).GetType().GetPointeeType()
+                    break
+            child = block.GetFirstChild()
+            while child.IsValid():
+                blocks.append(child)
+                child = child.GetSibling()
+        if cell_type is None:
+            # raw::poll<T, S> -> core::Cell<T, S>
+            match = re.match(r'^tokio::runtime::task::raw::poll<(.*)>$', function.GetName() or '')
+            if match:
+                candidate = target.FindFirstType('tokio::runtime::task::core::Cell<%s>' % match.group(1))
+                cell_type = candidate if candidate.IsValid() else None
+    if cell_type is not None and not cell_type.IsValid():
+        cell_type = None
+    cache[poll_address] = cell_type
+    return cell_type
+
+
+def read_location(target, process, address):
+    location_type = target.FindFirstType('core::panic::location::Location')
+    if not address or not location_type.IsValid():
+        return None
+    location = target.CreateValueFromAddress('location', lldb.SBAddress(address, target), location_type)
+    name = location.GetChildMemberWithName('filename')
+    if not name.IsValid():
+        name = location.GetChildMemberWithName('file')
+    data = find_member(name, 'data_ptr') if name.IsValid() else None
+    length = find_member(name, 'length') if name.IsValid() else None
+    if data is None or length is None:
+        return None
+    error = lldb.SBError()
+    raw = process.ReadMemory(data.GetValueAsUnsigned(), length.GetValueAsUnsigned(), error)
+    if not error.Success():
+        return None
+    return {
+        'file': raw.decode('utf-8', 'replace'),
+        'line': location.GetChildMemberWithName('line').GetValueAsUnsigned(),
+        'column': location.GetChildMemberWithName('col').GetValueAsUnsigned(),
+    }
+
+
+def describe_task(target, process, header, layout, cell_types):
+    pointer_size = process.GetAddressByteSize()
+    vtable = read_pointer(process, header + layout['header']['vtable'])
+    task = {
+        # task::Id wraps a NonZeroU64
+        'id': read_unsigned(process, header + read_unsigned(process, vtable + layout['vtable']['id_offset']), 8),
+        'state': read_unsigned(process, header + layout['header']['state']),
+    }
+
+    if 'spawn_location_offset' in layout['vtable']:
+        offset = read_unsigned(process, vtable + layout['vtable']['spawn_location_offset'])
+        task['spawnLocation'] = read_location(target, process, read_pointer(process, header + offset))
+
+    cell_type = cell_type_for(target, read_pointer(process, vtable + layout['vtable']['poll']), cell_types)
+    if cell_type is not None:
+        cell = target.CreateValueFromAddress('cell', lldb.SBAddress(header, target), cell_type)
+        # Cell.core.stage: CoreStage { stage: UnsafeCell<Stage<T>> }
+        stage = find_typed(cell, 'tokio::runtime::task::core::Stage<')
+        if stage is not None:
+            variant, fields = enum_variant(stage)
+            task['stage'] = variant
+            if variant == 'Running':
+                task['futures'] = await_chain(fields.GetChildAtIndex(0))
+
+    # Trailer.owned: Pointers { inner: UnsafeCell<PointersInner { prev, next }> }
+    trailer = header + read_unsigned(process, vtable + layout['vtable']['trailer_offset'])
+    return task, read_pointer(process, trailer + pointer_size)
+
+
+def list_tasks(target, process, handle, layout, cell_types):
+    owned = find_member(handle, 'owned')
+    lists = find_member(owned, 'lists') if owned is not None else None
+    data = find_member(lists, 'data_ptr') if lists is not None else None
+    length = find_member(lists, 'length') if lists is not None else None
+    if data is None or length is None:
+        raise ValueError('unsupported Tokio version: owned task list not found in %s' % type_name(handle))
+
+    shard_type = data.GetType().GetPointeeType()
+    tasks = []
+    seen = set()
+    for index in range(length.GetValueAsUnsigned()):
+        address = data.GetValueAsUnsigned() + index * shard_type.GetByteSize()
+        shard = target.CreateValueFromAddress('shard', lldb.SBAddress(address, target), shard_type)
+        head = find_member(shard, 'head', 6)
+        # Option<NonNull<Header>> is a plain pointer, null for None
+        header = read_pointer(process, head.GetLoadAddress()) if head is not None else 0
+        while header and header not in seen and len(tasks) < MAX_TASKS:
+            seen.add(header)
+            task, header = describe_task(target, process, header, layout, cell_types)
+            tasks.append(task)
+    return tasks
+
+
+def tokio_tasks(debugger, command, result, internal_dict):
+    target = debugger.GetSelectedTarget()
+    process = target.GetProcess()
+    if not process.IsValid() or process.GetState() != lldb.eStateStopped:
+        result.AppendMessage(json.dumps({'error': 'The process must be stopped'}))
+        return
+
+    try:
+        handles = find_runtime_handles(process)
+        if not handles:
+            result.AppendMessage(json.dumps({
+                'error': 'No Tokio runtime found; pause while a thread is inside the runtime'
+            }))
+            return
+        layout = {
+            'header': field_offsets(target, 'tokio::runtime::task::core::Header', HEADER_FIELDS),
+            'vtable': field_offsets(target, 'tokio::runtime::task::raw::Vtable', VTABLE_FIELDS),
+        }
+        cell_types = {}
+        runtimes = [
+            {'flavor': flavor, 'tasks': list_tasks(target, process, handle, layout, cell_types)}
+            for flavor, handle in handles
+        ]
+        result.AppendMessage(json.dumps({'runtimes': runtimes}))
+    except Exception as error:  # Report instead of printing a Python traceback
+        result.AppendMessage(json.dumps({'error': str(error)}))
+
+
+def __lldb_init_module(debugger, internal_dict):
+    debugger.HandleCommand('command script add -f tokio_tasks.tokio_tasks tokio-tasks')
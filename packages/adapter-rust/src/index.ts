@@ -9,7 +9,12 @@
 export { RustDebugAdapter } from './rust-debug-adapter.js';
 export { RustAdapterFactory } from './rust-adapter-factory.js';
 export { resolveCodeLLDBPath, checkCargoInstallation } from './utils/rust-utils.js';
-export { resolveCargoProject, getCargoTargets } from './utils/cargo-utils.js';
+export { resolveCargoProject, getCargoTargets, resolveCargoBinary, listRustTargets } from './utils/cargo-utils.js';
+export type { CargoBinaryResolution, CargoBuildOptions, CargoStaleUnit, RustTargetInfo } from './utils/cargo-utils.js';
+export { resolveRustTest } from './utils/cargo-test.js';
+export type { RustTestLaunch, RustTestOptions } from './utils/cargo-test.js';
+export { loadCargoMetadata, parseCargoMetadata, getProfileDirName } from './utils/cargo-metadata.js';
+export type { CargoMetadata, CargoPackage, CargoProfile, CargoTarget } from './utils/cargo-metadata.js';
 export { resolveCodeLLDBExecutable } from './utils/codelldb-resolver.js';
 export { detectBinaryFormat } from './utils/binary-detector.js';
 export type { BinaryInfo } from './utils/binary-detector.js';
@@ -29,23 +29,29 @@ import {
   AdapterConfig,
   GenericLaunchConfig,
   LanguageSpecificLaunchConfig,
+  LaunchPreparationOptions,
   DebugFeature,
   FeatureRequirement,
   AdapterCapabilities,
   AdapterError,
   AdapterErrorCode,
-  AdapterEvents
+  AdapterEvents,
+  BuildFailedError
 } from '@debugmcp/shared';
 import { DebugLanguage } from '@debugmcp/shared';
 import { AdapterDependencies } from '@debugmcp/shared';
-import { resolveCodeLLDBExecutable } from './utils/codelldb-resolver.js';
+import { resolveCodeLLDBExecutable, getCodeLLDBVersion } from './utils/codelldb-resolver.js';
 import {
   checkCargoInstallation,
   checkRustInstallation,
   getRustHostTriple,
+  getRustSysroot,
   findDlltoolExecutable,
 } from './utils/rust-utils.js';
 import { detectBinaryFormat, BinaryInfo } from './utils/binary-detector.js';
+import { getBundledScriptImports, resolveRustPrettyPrinters } from './utils/lldb-scripts.js';
+import { summarizeDiagnostics } from './utils/cargo-diagnostics.js';
+import type { CargoBuildOptions, CargoBuildResult } from './utils/cargo-utils.js';
 
 export type MsvcBehavior = 'warn' | 'error' | 'continue';
 
@@ -58,6 +64,30 @@ export interface ToolchainValidationResult {
   binaryInfo: BinaryInfo;
 }
 
+/**
+ * Minimum CodeLLDB versions for version-gated features
+ */
+const CODELLDB_FEATURE_VERSIONS: Partial<Record<DebugFeature, string>> = {
+  [DebugFeature.DATA_BREAKPOINTS]: '1.7.0',
+  [DebugFeature.LOG_POINTS]: '1.6.0'
+};
+
+/**
+ * Compare two dotted version strings (a leading 'v' is ignored)
+ */
+function compareVersions(a: string, b: string): number {
+  const parse = (v: string) => v.replace(/^v/i, '').split(/[.+-]/).map(part => parseInt(part, 10) || 0);
+  const left = parse(a);
+  const right = parse(b);
+  for (let i = 0; i < Math.max(left.length, right.length); i++) {
+    const diff = (left[i] ?? 0) - (right[i] ?? 0);
+    if (diff !== 0) {
+      return diff;
+    }
+  }
+  return 0;
+}
+
 /**
  * Cache entry for executable paths
  */
@@ -80,9 +110,12 @@ interface RustLaunchConfig extends LanguageSpecificLaunchConfig {
     example?: string;                    // Example target name
     test?: string;                       // Test target name
     release?: boolean;                   // Build in release mode
+    profile?: string;                    // Custom profile, e.g. "debugging" (overrides release)
     features?: string[];                 // Cargo features to enable
     allFeatures?: boolean;               // Enable all features
     noDefaultFeatures?: boolean;         // Disable default features
+    target?: string;                     // Target triple to build for
+    package?: string;                    // Workspace member to build (-p)
   };
   sourceMap?: Record<string, string>;    // Source path mappings
   initCommands?: string[];              // LLDB commands to run on init
@@ -105,6 +138,7 @@ export class RustDebugAdapter extends EventEmitter implements IDebugAdapter {
   private readonly msvcBehavior: MsvcBehavior;
   private readonly autoSuggestGnu: boolean;
   private dlltoolPath: string | undefined;
+  private codelldbVersion: string | null | undefined;
   
   // Caching
   private executablePathCache = new Map<string, ExecutablePathCacheEntry>();
@@ -244,6 +278,22 @@ export class RustDebugAdapter extends EventEmitter implements IDebugAdapter {
         }
       }
       
+      // Check std pretty-printers (Vec, String, HashMap... formatting)
+      const prettyPrinters = await resolveRustPrettyPrinters(await getRustSysroot());
+      if (!prettyPrinters) {
+        warnings.push({
+          code: 'RUST_PRETTY_PRINTERS_NOT_FOUND',
+          message: 'Rust pretty-printers (lldb_lookup.py) not found in the toolchain sysroot or the adapter package. std types will be shown as raw structs.'
+        });
+      } else if (prettyPrinters.source === 'vendored') {
+        warnings.push({
+          code: 'RUST_PRETTY_PRINTERS_VENDORED',
+          message: `The active Rust toolchain has no LLDB pretty-printers; using the copy bundled with the adapter (${prettyPrinters.directory}), which may not match this toolchain's std layout.`
+        });
+      } else {
+        this.dependencies.logger?.info(`[RustDebugAdapter] Rust pretty-printers active from ${prettyPrinters.directory}`);
+      }
+
       // Check Cargo installation
       const cargoInstalled = await checkCargoInstallation();
       if (!cargoInstalled) {
@@ -784,8 +834,13 @@ export class RustDebugAdapter extends EventEmitter implements IDebugAdapter {
   
   // ===== Debug Configuration =====
   
-  async transformLaunchConfig(config: GenericLaunchConfig): Promise<LanguageSpecificLaunchConfig> {
+  async transformLaunchConfig(
+    config: GenericLaunchConfig,
+    options: LaunchPreparationOptions = {}
+  ): Promise<LanguageSpecificLaunchConfig> {
     const rustConfig = config as RustLaunchConfig;
+    const cwd = rustConfig.cwd || process.cwd();
+    const prettyPrinters = await resolveRustPrettyPrinters(await getRustSysroot(cwd));
     
     // Base configuration for CodeLLDB
     const launchConfig: RustLaunchConfig = {
@@ -794,7 +849,7 @@ export class RustDebugAdapter extends EventEmitter implements IDebugAdapter {
       name: rustConfig.name || 'Debug Rust',
       program: '',  // Will be resolved below
       args: rustConfig.args || [],
-      cwd: rustConfig.cwd || process.cwd(),
+      cwd,
       env: rustConfig.env || {},
       stopOnEntry: rustConfig.stopOnEntry || false,
       
@@ -807,8 +862,14 @@ export class RustDebugAdapter extends EventEmitter implements IDebugAdapter {
       // Source mapping for debugging std library (optional)
       sourceMap: rustConfig.sourceMap || {},
       
-      // LLDB commands (optional)
-      initCommands: rustConfig.initCommands || [],
+      // LLDB commands (optional). Loaded first: the std pretty-printers of the project's toolchain
+      // (as rust-lldb does), then the bundled scripts, whose `rust-async` category is enabled last
+      // and so takes priority over the catch-all Rust formatters
+      initCommands: [
+        ...(prettyPrinters?.commands ?? []),
+        ...await getBundledScriptImports(),
+        ...(rustConfig.initCommands || [])
+      ],
       preRunCommands: rustConfig.preRunCommands || [],
       postRunCommands: rustConfig.postRunCommands || []
     };
@@ -822,44 +883,40 @@ export class RustDebugAdapter extends EventEmitter implements IDebugAdapter {
         this.dependencies.logger?.info('[Rust Debugger] Resolving source file to binary...');
         
         try {
-          // Find project root
-          const { findCargoProjectRoot, getDefaultBinary, needsRebuild, buildCargoProject } = 
+          const { resolveCargoBinary, buildCargoProject } = 
             await import('./utils/cargo-utils.js');
           
-          const projectRoot = await findCargoProjectRoot(programPath);
+          // The source file determines the package, so cargo.package does not apply
+          const buildOptions = { ...this.getCargoBuildOptions(rustConfig.cargo), package: undefined };
+          const resolution = await resolveCargoBinary(programPath, buildOptions);
+          const projectRoot = resolution.packageRoot;
+          const { target } = resolution;
           this.dependencies.logger?.info(`[Rust Debugger] Found Cargo project at: ${projectRoot}`);
+          if (resolution.workspaceRoot !== projectRoot) {
+            this.dependencies.logger?.info(`[Rust Debugger] Workspace root: ${resolution.workspaceRoot}`);
+          }
+          
+          if (target) {
+            this.dependencies.logger?.info(`[Rust Debugger] Source file is the ${target.kind} target "${target.name}"`);
+          }
           
-          // Determine binary path
-          const binaryName = await getDefaultBinary(projectRoot);
-          const buildMode = rustConfig.cargo?.release ? 'release' : 'debug';
-          const extension = process.platform === 'win32' ? '.exe' : '';
-          const binaryPath = path.join(
+          // Let cargo decide freshness: its fingerprints cover build scripts,
+          // path dependencies, Cargo.lock and features, and the build is a
+          // no-op when everything is up to date
+          const buildResult = await buildCargoProject(
             projectRoot,
-            'target',
-            buildMode,
-            `${binaryName}${extension}`
+            this.dependencies.logger,
+            buildOptions,
+            target,
+            options
           );
           
-          // Check if build is needed
-          if (await needsRebuild(projectRoot, binaryName, buildMode === 'release')) {
-            this.dependencies.logger?.info('[Rust Debugger] Binary is out of date, rebuilding...');
-            
-            const buildResult = await buildCargoProject(
-              projectRoot,
-              this.dependencies.logger,
-              buildMode
-            );
-            
-            if (!buildResult.success) {
-              throw new Error(`Cargo build failed: ${buildResult.error}`);
-            }
-            
-            launchConfig.program = buildResult.binaryPath!;
-          } else {
-            this.dependencies.logger?.info('[Rust Debugger] Using existing binary (up to date)');
-            launchConfig.program = binaryPath;
+          if (!buildResult.success) {
+            throw this.createBuildError(buildResult);
           }
           
+          launchConfig.program = buildResult.binaryPath!;
+          
         } catch (error) {
           this.dependencies.logger?.error(`[Rust Debugger] Failed to resolve binary: ${error}`);
           throw error;
@@ -869,28 +926,32 @@ export class RustDebugAdapter extends EventEmitter implements IDebugAdapter {
         launchConfig.program = path.resolve(rustConfig.cwd || process.cwd(), programPath);
       }
     } else if (rustConfig.cargo) {
-      // Build program path from Cargo configuration
-      const targetDir = path.join(
-        rustConfig.cwd || process.cwd(), 
-        'target',
-        rustConfig.cargo.release ? 'release' : 'debug'
-      );
+      // Build the selected Cargo target and launch the executable cargo reports
+      const { buildCargoProject, locateCargoExecutable } = await import('./utils/cargo-utils.js');
+      const projectRoot = rustConfig.cwd || process.cwd();
+      const buildOptions = this.getCargoBuildOptions(rustConfig.cargo);
+      const target = rustConfig.cargo.bin ? { kind: 'bin' as const, name: rustConfig.cargo.bin }
+        : rustConfig.cargo.example ? { kind: 'example' as const, name: rustConfig.cargo.example }
+        : rustConfig.cargo.test ? { kind: 'test' as const, name: rustConfig.cargo.test }
+        : undefined;
       
-      let binaryName: string;
-      if (rustConfig.cargo.bin) {
-        binaryName = rustConfig.cargo.bin;
-      } else if (rustConfig.cargo.example) {
-        binaryName = rustConfig.cargo.example;
-      } else if (rustConfig.cargo.test) {
-        binaryName = rustConfig.cargo.test;
+      if (rustConfig.cargo.build === false) {
+        const executable = await locateCargoExecutable(projectRoot, buildOptions, target);
+        if (!executable) {
+          throw new AdapterError(
+            `No built executable found for ${target ? `${target.kind} "${target.name}"` : 'the default binary'}. ` +
+            'Build the project first or set cargo.build to true.',
+            AdapterErrorCode.SCRIPT_NOT_FOUND
+          );
+        }
+        launchConfig.program = executable;
       } else {
-        // Try to find default binary
-        binaryName = 'main';  // Will need to be resolved from Cargo.toml
-        this.dependencies.logger?.warn('[RustDebugAdapter] No binary specified, defaulting to "main"');
+        const buildResult = await buildCargoProject(projectRoot, this.dependencies.logger, buildOptions, target, options);
+        if (!buildResult.success) {
+          throw this.createBuildError(buildResult);
+        }
+        launchConfig.program = buildResult.binaryPath!;
       }
-      
-      const extension = process.platform === 'win32' ? '.exe' : '';
-      launchConfig.program = path.join(targetDir, `${binaryName}${extension}`);
     } else {
       throw new AdapterError(
         'No program specified. Provide either "program" or "cargo" configuration.',
@@ -916,6 +977,27 @@ export class RustDebugAdapter extends EventEmitter implements IDebugAdapter {
     return launchConfig;
   }
   
+  /**
+   * Build flags from the launch config's `cargo` section
+   */
+  private getCargoBuildOptions(cargo: RustLaunchConfig['cargo'] = {}): CargoBuildOptions {
+    const { release, profile, features, allFeatures, noDefaultFeatures, target } = cargo;
+    return { release, profile, features, allFeatures, noDefaultFeatures, target, package: cargo.package };
+  }
+  
+  /**
+   * Error for a failed auto-build, carrying the compiler's diagnostics so the
+   * caller can point at the failing line instead of reading raw cargo output
+   */
+  private createBuildError(buildResult: CargoBuildResult): BuildFailedError {
+    if (buildResult.cancelled) {
+      return new BuildFailedError('Cargo build cancelled', []);
+    }
+    const diagnostics = buildResult.diagnostics ?? [];
+    const summary = summarizeDiagnostics(diagnostics) ?? buildResult.error ?? 'unknown error';
+    return new BuildFailedError(`Cargo build failed: ${summary}`, diagnostics, buildResult.error);
+  }
+  
   getDefaultLaunchConfig(): Partial<GenericLaunchConfig> {
     return {
       stopOnEntry: false,
@@ -1088,6 +1170,7 @@ You can also specify the Rust executable path explicitly in your debug configura
       DebugFeature.LOG_POINTS,
       DebugFeature.DISASSEMBLE_REQUEST,
       DebugFeature.STEP_IN_TARGETS_REQUEST,
+      DebugFeature.STEPPING_GRANULARITY,
       DebugFeature.LOADED_SOURCES_REQUEST,
       DebugFeature.TERMINATE_REQUEST
     ];
@@ -1095,6 +1178,55 @@ You can also specify the Rust executable path explicitly in your debug configura
     return supportedFeatures.includes(feature);
   }
   
+  /**
+   * Get the installed CodeLLDB version (cached after the first lookup)
+   */
+  async getCodeLLDBVersion(): Promise<string | null> {
+    if (this.codelldbVersion === undefined) {
+      this.codelldbVersion = await getCodeLLDBVersion();
+    }
+    return this.codelldbVersion;
+  }
+
+  /**
+   * Architecture of the debugged program: the cross-compilation target when
+   * one is set, else the machine type in the executable's header
+   */
+  async getTargetArchitecture(launchConfig: LanguageSpecificLaunchConfig): Promise<string | undefined> {
+    const triple = (launchConfig as RustLaunchConfig).cargo?.target;
+    if (triple) {
+      return triple.split('-')[0];
+    }
+    const program = launchConfig.program;
+    return typeof program === 'string' ? (await detectBinaryFormat(program)).architecture : undefined;
+  }
+
+  /**
+   * Check a feature against the installed CodeLLDB version
+   */
+  async checkFeatureSupport(feature: DebugFeature): Promise<{ supported: boolean; reason?: string }> {
+    if (!this.supportsFeature(feature)) {
+      return { supported: false, reason: `${feature} is not supported by CodeLLDB` };
+    }
+
+    const minimumVersion = CODELLDB_FEATURE_VERSIONS[feature];
+    if (!minimumVersion) {
+      return { supported: true };
+    }
+
+    const version = await this.getCodeLLDBVersion();
+    if (!version) {
+      return { supported: false, reason: 'CodeLLDB not found. Run: npm run build:adapter' };
+    }
+    if (compareVersions(version, minimumVersion) < 0) {
+      return {
+        supported: false,
+        reason: `${feature} requires CodeLLDB ${minimumVersion}+ (found ${version})`
+      };
+    }
+    return { supported: true };
+  }
+
   getFeatureRequirements(feature: DebugFeature): FeatureRequirement[] {
     const requirements: FeatureRequirement[] = [];
     
@@ -1155,8 +1287,8 @@ You can also specify the Rust executable path explicitly in your debug configura
       supportsSetExpression: false,
       supportsTerminateRequest: true,
       supportsDataBreakpoints: true,  // LLDB supports watchpoints
-      supportsReadMemoryRequest: false,
-      supportsWriteMemoryRequest: false,
+      supportsReadMemoryRequest: true,
+      supportsWriteMemoryRequest: true,
       supportsDisassembleRequest: true,  // LLDB has disassembly support
       supportsCancelRequest: false,
       supportsBreakpointLocationsRequest: true,
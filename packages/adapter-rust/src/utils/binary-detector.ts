@@ -7,6 +7,8 @@ export interface BinaryInfo {
   hasRSDS: boolean;
   imports: string[];
   debugInfoType?: 'pdb' | 'dwarf' | 'none';
+  /** Machine type from the ELF, PE or Mach-O header, named like the first part of a target triple */
+  architecture?: 'x86_64' | 'x86' | 'aarch64' | 'arm';
 }
 
 const MAX_SCAN_BYTES = 1024 * 1024; // 1MB should be enough for headers/import tables
@@ -45,6 +47,48 @@ function detectDebugInfo(buffer: Buffer, hasPDB: boolean, hasRSDS: boolean): Bin
   return 'none';
 }
 
+const ELF_MACHINES: Record<number, BinaryInfo['architecture']> = {
+  0x03: 'x86',
+  0x28: 'arm',
+  0x3e: 'x86_64',
+  0xb7: 'aarch64'
+};
+const PE_MACHINES: Record<number, BinaryInfo['architecture']> = {
+  0x014c: 'x86',
+  0x01c0: 'arm',
+  0x01c4: 'arm',
+  0x8664: 'x86_64',
+  0xaa64: 'aarch64'
+};
+const MACHO_CPU_TYPES: Record<number, BinaryInfo['architecture']> = {
+  0x00000007: 'x86',
+  0x0000000c: 'arm',
+  0x01000007: 'x86_64',
+  0x0100000c: 'aarch64'
+};
+
+function detectArchitecture(buffer: Buffer): BinaryInfo['architecture'] {
+  if (buffer.length >= 20 && buffer.readUInt32BE(0) === 0x7f454c46) {
+    // ELF: e_machine, in the byte order given by EI_DATA
+    const machine = buffer[5] === 2 ? buffer.readUInt16BE(18) : buffer.readUInt16LE(18);
+    return ELF_MACHINES[machine];
+  }
+  if (buffer.length >= 0x40 && buffer.toString('ascii', 0, 2) === 'MZ') {
+    const peOffset = buffer.readUInt32LE(0x3c);
+    if (peOffset + 6 <= buffer.length && buffer.readUInt32BE(peOffset) === 0x50450000) {
+      return PE_MACHINES[buffer.readUInt16LE(peOffset + 4)];
+    }
+    return undefined;
+  }
+  if (buffer.length >= 8) {
+    const magic = buffer.readUInt32LE(0);
+    if (magic === 0xfeedfacf || magic === 0xfeedface) {
+      return MACHO_CPU_TYPES[buffer.readUInt32LE(4)];
+    }
+  }
+  return undefined;
+}
+
 function classifyFormat(imports: string[], debugInfoType: BinaryInfo['debugInfoType']): BinaryInfo['format'] {
   const loweredImports = imports.map(i => i.toLowerCase());
   const hasMSVCImport = loweredImports.some(i => MSVC_IMPORTS.includes(i));
@@ -102,6 +146,7 @@ export async function detectBinaryFormat(exePath: string): Promise<BinaryInfo> {
     info.imports = collectImports(buffer);
     info.debugInfoType = detectDebugInfo(buffer, info.hasPDB, info.hasRSDS);
     info.format = classifyFormat(info.imports, info.debugInfoType);
+    info.architecture = detectArchitecture(buffer);
 
     return info;
   } catch {
@@ -0,0 +1,151 @@
+/**
+ * Structured compiler diagnostics from cargo's `compiler-message` JSON
+ *
+ * rustc runs from the workspace root, so span file names of workspace members
+ * are relative to it; they are resolved to absolute paths here.
+ */
+
+import * as path from 'path';
+import type { CompilerDiagnostic, CompilerSuggestion } from '@debugmcp/shared';
+
+interface RustcSpan {
+  file_name: string;
+  line_start: number;
+  line_end: number;
+  column_start: number;
+  column_end: number;
+  is_primary: boolean;
+  label?: string | null;
+  suggested_replacement?: string | null;
+  suggestion_applicability?: string | null;
+}
+
+interface RustcMessage {
+  message: string;
+  code?: { code: string } | null;
+  level: string;
+  spans: RustcSpan[];
+  children: RustcMessage[];
+  rendered?: string | null;
+}
+
+/**
+ * Diagnostic levels reported to callers; `failure-note` ("For more
+ * information about this error...") and the like are dropped
+ */
+const REPORTED_LEVELS = ['error', 'warning'];
+
+/**
+ * Parse the `compiler-message` lines of cargo JSON output. Errors come before
+ * warnings; duplicates (the same message at the same place) are dropped.
+ */
+export function parseCargoDiagnostics(output: string, workspaceRoot: string): CompilerDiagnostic[] {
+  const diagnostics: CompilerDiagnostic[] = [];
+  const seen = new Set<string>();
+
+  for (const message of readCompilerMessages(output)) {
+    if (!REPORTED_LEVELS.includes(message.level)) {
+      continue;
+    }
+    const primary = message.spans.find(span => span.is_primary) ?? message.spans[0];
+    const diagnostic: CompilerDiagnostic = {
+      level: message.level,
+      code: message.code?.code ?? undefined,
+      message: message.message,
+      suggestions: collectSuggestions(message, workspaceRoot)
+    };
+    if (primary) {
+      diagnostic.file = resolveSpanFile(primary.file_name, workspaceRoot);
+      diagnostic.line = primary.line_start;
+      diagnostic.column = primary.column_start;
+      diagnostic.endLine = primary.line_end;
+      diagnostic.endColumn = primary.column_end;
+      if (primary.label) {
+        diagnostic.label = primary.label;
+      }
+    }
+
+    const key = `${diagnostic.level}|${diagnostic.code}|${diagnostic.message}|${diagnostic.file}:${diagnostic.line}:${diagnostic.column}`;
+    if (!seen.has(key)) {
+      seen.add(key);
+      diagnostics.push(diagnostic);
+    }
+  }
+
+  return diagnostics.sort((a, b) => Number(b.level === 'error') - Number(a.level === 'error'));
+}
+
+/**
+ * rustc's own rendering of the reported diagnostics, for plain-text errors
+ */
+export function renderCargoDiagnostics(output: string): string {
+  return readCompilerMessages(output)
+    .filter(message => REPORTED_LEVELS.includes(message.level) && message.rendered)
+    .map(message => message.rendered!.trimEnd())
+    .join('\n\n');
+}
+
+/**
+ * One-line summary of a failed build, e.g.
+ * `2 errors, first: E0308 mismatched types at /ws/app/src/main.rs:18:18`
+ */
+export function summarizeDiagnostics(diagnostics: CompilerDiagnostic[]): string | undefined {
+  const errors = diagnostics.filter(d => d.level === 'error');
+  const first = errors[0];
+  if (!first) {
+    return undefined;
+  }
+  const code = first.code ? `${first.code} ` : '';
+  const location = first.file ? ` at ${first.file}:${first.line}:${first.column}` : '';
+  const count = errors.length === 1 ? '1 error' : `${errors.length} errors, first`;
+  return `${count}: ${code}${first.message}${location}`;
+}
+
+function collectSuggestions(message: RustcMessage, workspaceRoot: string): CompilerSuggestion[] {
+  const suggestions: CompilerSuggestion[] = [];
+  for (const child of [message, ...message.children]) {
+    for (const span of child.spans) {
+      if (span.suggested_replacement === null || span.suggested_replacement === undefined) {
+        continue;
+      }
+      suggestions.push({
+        message: child.message,
+        file: resolveSpanFile(span.file_name, workspaceRoot),
+        line: span.line_start,
+        column: span.column_start,
+        endLine: span.line_end,
+        endColumn: span.column_end,
+        replacement: span.suggested_replacement,
+        applicability: span.suggestion_applicability ?? undefined
+      });
+    }
+  }
+  return suggestions;
+}
+
+function resolveSpanFile(fileName: string, workspaceRoot: string): string {
+  return path.isAbsolute(fileName) ? fileName : path.join(workspaceRoot, fileName);
+}
+
+function readCompilerMessages(output: string): RustcMessage[] {
+  const messages: RustcMessage[] = [];
+  for (const line of output.split(/\r?\n/)) {
+    if (!line.startsWith('{')) {
+      continue;
+    }
+    let parsed: { reason?: string; message?: RustcMessage };
+    try {
+      parsed = JSON.parse(line);
+    } catch {
+      continue;
+    }
+    if (parsed.reason === 'compiler-message' && parsed.message && typeof parsed.message.message === 'string') {
+      messages.push({
+        ...parsed.message,
+        spans: Array.isArray(parsed.message.spans) ? parsed.message.spans : [],
+        children: Array.isArray(parsed.message.children) ? parsed.message.children : []
+      });
+    }
+  }
+  return messages;
+}
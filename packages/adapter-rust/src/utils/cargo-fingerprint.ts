@@ -0,0 +1,199 @@
+/**
+ * Cargo freshness reporting
+ *
+ * Cargo decides whether a unit must be recompiled from its fingerprint, which
+ * covers every input mtime-based checks miss: build scripts, path
+ * dependencies, workspace siblings, Cargo.lock, features, profile and rustc
+ * changes. Stable cargo has no build dry run, so freshness is taken from the
+ * build itself (a no-op when nothing changed): `compiler-artifact` messages
+ * carry a `fresh` flag, and the fingerprint log explains why a unit is dirty.
+ *
+ * Only `fresh` decides whether a unit is stale. The log is cargo's internal
+ * tracing output, whose span fields and `DirtyReason` wording change between
+ * releases, so the reasons read from it are best effort: lines that do not
+ * look as expected are skipped and the unit is reported as "rebuilt by cargo".
+ */
+
+import type { CargoArtifact } from './cargo-utils.js';
+
+/**
+ * Environment that makes cargo log fingerprint decisions to stderr.
+ * RUST_LIB_BACKTRACE=0 keeps backtraces out of the "fingerprint error" entries.
+ */
+export const CARGO_FINGERPRINT_LOG_ENV: Record<string, string> = {
+  CARGO_LOG: 'cargo::core::compiler::fingerprint=info',
+  RUST_LIB_BACKTRACE: '0'
+};
+
+/**
+ * A unit cargo recompiled, and why
+ */
+export interface CargoStaleUnit {
+  package: string;
+  target: string;
+  kind: string[];
+  reason: string;
+}
+
+const FINGERPRINT_MODULE = 'cargo::core::compiler::fingerprint';
+// Any tracing line: an optional uptime or timestamp, then the level
+const LOG_LINE = /^\s*(?:\[?\S*\d\S*\s+)?(?:TRACE|DEBUG|INFO|WARN|ERROR)\s/;
+// Span fields, in whatever order and quoting the cargo version uses
+const PACKAGE_FIELD = /\bpackage_id=("[^"]*"|[^\s}]+)/;
+const TARGET_FIELD = /\btarget=("[^"]*"|[^\s}]+)/;
+const CARGO_STATUS_LINE = /^\s+(?:Compiling|Checking|Finished|Fresh|Running|Blocking|Updating|Locking|Downloading|Downloaded|Adding|Removing)\s/;
+const DEPENDENCY_REBUILT = 'a dependency was rebuilt';
+
+/**
+ * Read the fingerprint log: `<package>:<target>` -> reason the unit is dirty.
+ * A specific reason (changed file, features, ...) wins over "a dependency was
+ * rebuilt" when cargo logs several units for the same target.
+ */
+export function parseFingerprintLog(stderr: string): Map<string, string> {
+  const reasons = new Map<string, string>();
+  for (const line of stderr.split(/\r?\n/)) {
+    const entry = parseFingerprintEntry(line);
+    if (!entry) {
+      continue;
+    }
+    const { pkg, target, message } = entry;
+    let reason: string | undefined;
+    if (/^dirty:\s/.test(message)) {
+      reason = describeDirtyReason(message.replace(/^dirty:\s+/, ''));
+    } else if (/^err:\s/.test(message)) {
+      // No fingerprint to compare against: never built with this
+      // configuration (first build, or features/profile/target changed)
+      reason = 'not built with the current features, profile or target yet';
+    }
+    if (!reason) {
+      continue;
+    }
+    const key = `${pkg}:${target}`;
+    const previous = reasons.get(key);
+    if (!previous || previous === DEPENDENCY_REBUILT) {
+      reasons.set(key, reason);
+    }
+  }
+  return reasons;
+}
+
+/**
+ * Split a fingerprint log line into package, target and message; undefined
+ * for other lines or when the span fields cannot be found
+ */
+function parseFingerprintEntry(line: string): { pkg: string; target: string; message: string } | undefined {
+  const moduleIndex = line.indexOf(FINGERPRINT_MODULE);
+  if (moduleIndex === -1) {
+    return undefined;
+  }
+  const spans = line.slice(0, moduleIndex);
+  const packageField = PACKAGE_FIELD.exec(spans)?.[1];
+  const targetField = TARGET_FIELD.exec(spans)?.[1];
+  if (!packageField || !targetField) {
+    return undefined;
+  }
+  // `package_id=app v0.1.0 (/ws/app)` or a package id spec
+  const pkg = getPackageName(packageField.replace(/^"|"$/g, ''));
+  const target = targetField.replace(/^"|"$/g, '');
+  const message = line.slice(moduleIndex + FINGERPRINT_MODULE.length).replace(/^[:\]\s]+/, '').trim();
+  return { pkg, target, message };
+}
+
+/**
+ * Turn a cargo `DirtyReason` into a short explanation. Known variants of its
+ * Debug output are summarized; anything else is kept as cargo wrote it.
+ */
+export function describeDirtyReason(raw: string): string {
+  const text = raw.trim();
+  const outdated = /^FsStatusOutdated\((.*)\)$/.exec(text);
+  if (outdated) {
+    return describeDirtyReason(outdated[1]);
+  }
+
+  const changed = /ChangedFile\b.*\bstale: "([^"]+)"/.exec(text);
+  if (changed) {
+    return `changed: ${changed[1]}`;
+  }
+  const missing = /MissingFile\("?([^")]+)"?\)/.exec(text);
+  if (missing) {
+    return `missing: ${missing[1]}`;
+  }
+  if (/^StaleDepFingerprint\b/.test(text)) {
+    return DEPENDENCY_REBUILT;
+  }
+  const staleDependency = /^StaleDependency \{ name: "?([^",]+)"?/.exec(text);
+  if (staleDependency) {
+    return `dependency ${staleDependency[1]} is newer`;
+  }
+  const envVar = /^EnvVarChanged \{ name: "([^"]+)"/.exec(text);
+  if (envVar) {
+    return `environment variable ${envVar[1]} changed`;
+  }
+
+  // Other variants read well as words: FeaturesChanged, RustcChanged,
+  // ProfileConfigurationChanged, TargetConfigurationChanged, ...
+  const variant = /^([A-Z][A-Za-z]*)(?:$|\s*[({])/.exec(text)?.[1];
+  if (variant) {
+    return variant.replace(/([a-z])([A-Z])/g, '$1 $2').toLowerCase();
+  }
+  // Not a Debug-formatted variant (e.g. a reason cargo already words itself)
+  return text.length > 200 ? `${text.slice(0, 197)}...` : text;
+}
+
+/**
+ * Units cargo did not consider fresh, with the reason from the fingerprint log
+ */
+export function getStaleUnits(artifacts: CargoArtifact[], stderr: string): CargoStaleUnit[] {
+  const reasons = parseFingerprintLog(stderr);
+  return artifacts
+    .filter(artifact => !artifact.fresh)
+    .map(artifact => {
+      const pkg = getPackageName(artifact.packageId);
+      return {
+        package: pkg,
+        target: artifact.target.name,
+        kind: artifact.target.kind,
+        reason: reasons.get(`${pkg}:${artifact.target.name}`) ?? 'rebuilt by cargo'
+      };
+    });
+}
+
+/**
+ * Remove fingerprint log entries from cargo's stderr, leaving the rendered
+ * diagnostics and status lines
+ */
+export function stripFingerprintLog(stderr: string): string {
+  const kept: string[] = [];
+  let inEntry = false;
+  for (const line of stderr.split(/\r?\n/)) {
+    if (LOG_LINE.test(line) || line.includes(FINGERPRINT_MODULE)) {
+      inEntry = true;
+      continue;
+    }
+    // "fingerprint error" entries continue with a blank line and an error chain
+    if (inEntry && (line === '' || line === 'Caused by:' || (/^ {4}\S/.test(line) && !CARGO_STATUS_LINE.test(line)))) {
+      continue;
+    }
+    inEntry = false;
+    kept.push(line);
+  }
+  return kept.join('\n');
+}
+
+/**
+ * Package name from a package id: `path+file:///ws/app#0.1.0`,
+ * `path+file:///ws/crates/core#my-core@0.1.0` or `registry+...#serde@1.0.0`
+ */
+export function getPackageName(packageId: string): string {
+  const hash = packageId.lastIndexOf('#');
+  if (hash === -1) {
+    // Pre-1.77 format: "name version (source)"
+    return packageId.split(' ')[0];
+  }
+  const fragment = packageId.slice(hash + 1);
+  if (fragment.includes('@')) {
+    return fragment.slice(0, fragment.indexOf('@'));
+  }
+  const url = packageId.slice(0, hash).replace(/\/+$/, '');
+  return url.slice(url.lastIndexOf('/') + 1);
+}
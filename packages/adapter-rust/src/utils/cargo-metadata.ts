@@ -0,0 +1,347 @@
+/**
+ * Cargo manifest model built from `cargo metadata`
+ *
+ * `cargo metadata` resolves everything that is hard to get right by reading
+ * Cargo.toml directly: workspace inheritance (`version.workspace = true`),
+ * auto-discovered targets, and workspace membership. Profiles are not part of
+ * its output, so `[profile.*]` tables are read from the workspace root manifest.
+ */
+
+import * as fs from 'fs/promises';
+import * as path from 'path';
+import { spawn } from 'child_process';
+
+/**
+ * A build target of a package (mirrors the `cargo metadata` target fields)
+ */
+export interface CargoTarget {
+  name: string;
+  /** e.g. ['bin'], ['lib'], ['example'], ['test'], ['bench'], ['custom-build'] */
+  kind: string[];
+  src_path: string;
+  crate_types?: string[];
+  /** Features that must be enabled for the target to be built */
+  required_features?: string[];
+  edition?: string;
+}
+
+/**
+ * A workspace member package
+ */
+export interface CargoPackage {
+  id: string;
+  name: string;
+  version: string;
+  manifestPath: string;
+  /** Directory containing the package's Cargo.toml */
+  rootDir: string;
+  edition?: string;
+  targets: CargoTarget[];
+  /** Feature name -> features/dependencies it enables */
+  features: Record<string, string[]>;
+  /** `package.default-run`, used by `cargo run` when there are several binaries */
+  defaultRun?: string;
+}
+
+/**
+ * Settings from a `[profile.<name>]` table
+ */
+export interface CargoProfile {
+  name: string;
+  inherits?: string;
+  optLevel?: number | string;
+  debug?: boolean | number | string;
+  /** Every key of the table as written */
+  settings: Record<string, string | number | boolean>;
+}
+
+/**
+ * Manifest model of a Cargo project and the workspace it belongs to
+ */
+export interface CargoMetadata {
+  workspaceRoot: string;
+  /** Build output directory, honoring CARGO_TARGET_DIR and `build.target-dir` */
+  targetDirectory: string;
+  /** Workspace member packages */
+  packages: CargoPackage[];
+  /** Package at the workspace root; undefined for a virtual workspace */
+  rootPackage?: CargoPackage;
+  /** Profiles declared in the workspace root manifest */
+  profiles: Record<string, CargoProfile>;
+}
+
+/**
+ * Target kinds that produce a runnable executable
+ */
+export const EXECUTABLE_TARGET_KINDS = ['bin', 'example', 'test', 'bench'] as const;
+
+export type ExecutableTargetKind = typeof EXECUTABLE_TARGET_KINDS[number];
+
+/**
+ * Run `cargo metadata` in a project directory and build the manifest model.
+ * Returns null when cargo is unavailable or the project has no valid manifest.
+ */
+export async function loadCargoMetadata(projectPath: string): Promise<CargoMetadata | null> {
+  let output: string | null;
+  try {
+    output = await runCargoMetadata(projectPath);
+  } catch {
+    return null;
+  }
+  if (!output) {
+    return null;
+  }
+
+  let raw: unknown;
+  try {
+    raw = JSON.parse(output);
+  } catch {
+    return null;
+  }
+
+  const metadata = parseCargoMetadata(raw, projectPath);
+  if (!metadata) {
+    return null;
+  }
+  metadata.profiles = await readCargoProfiles(path.join(metadata.workspaceRoot, 'Cargo.toml'));
+  return metadata;
+}
+
+function runCargoMetadata(projectPath: string): Promise<string | null> {
+  return new Promise((resolve) => {
+    const metadataProcess = spawn('cargo', ['metadata', '--format-version', '1', '--no-deps'], {
+      cwd: projectPath,
+      shell: true
+    });
+
+    let output = '';
+
+    metadataProcess.stdout?.on('data', (data) => {
+      output += data.toString();
+    });
+
+    metadataProcess.on('error', () => resolve(null));
+    // 'close' waits for stdout to end; on 'exit' the JSON may still be truncated
+    metadataProcess.on('close', (code) => {
+      resolve(code === 0 && output ? output : null);
+    });
+  });
+}
+
+/**
+ * Build the manifest model from parsed `cargo metadata --format-version 1` JSON.
+ * Profiles are left empty; see readCargoProfiles.
+ */
+export function parseCargoMetadata(raw: unknown, projectPath: string): CargoMetadata | null {
+  if (!raw || typeof raw !== 'object' || !Array.isArray((raw as { packages?: unknown }).packages)) {
+    return null;
+  }
+  const data = raw as {
+    packages: Array<Record<string, unknown>>;
+    workspace_members?: string[];
+    workspace_root?: string;
+    target_directory?: string;
+  };
+
+  const workspaceRoot = typeof data.workspace_root === 'string' ? data.workspace_root : path.resolve(projectPath);
+  const members = Array.isArray(data.workspace_members) ? new Set(data.workspace_members) : undefined;
+
+  const packages: CargoPackage[] = data.packages
+    .filter(pkg => !members || members.has(String(pkg.id)))
+    .map(pkg => {
+      const manifestPath = String(pkg.manifest_path ?? '');
+      const targets = Array.isArray(pkg.targets) ? (pkg.targets as Array<Record<string, unknown>>) : [];
+      return {
+        id: String(pkg.id ?? ''),
+        name: typeof pkg.name === 'string' ? pkg.name : '',
+        version: typeof pkg.version === 'string' ? pkg.version : '',
+        manifestPath,
+        rootDir: path.dirname(manifestPath),
+        edition: typeof pkg.edition === 'string' ? pkg.edition : undefined,
+        targets: targets.map(target => ({
+          name: String(target.name ?? ''),
+          kind: Array.isArray(target.kind) ? (target.kind as string[]) : [],
+          src_path: String(target.src_path ?? ''),
+          crate_types: Array.isArray(target.crate_types) ? (target.crate_types as string[]) : undefined,
+          required_features: Array.isArray(target['required-features'])
+            ? (target['required-features'] as string[])
+            : undefined,
+          edition: typeof target.edition === 'string' ? target.edition : undefined
+        })),
+        features: pkg.features && typeof pkg.features === 'object'
+          ? (pkg.features as Record<string, string[]>)
+          : {},
+        defaultRun: typeof pkg.default_run === 'string' ? pkg.default_run : undefined
+      };
+    });
+
+  const rootManifest = path.join(workspaceRoot, 'Cargo.toml');
+  return {
+    workspaceRoot,
+    targetDirectory: typeof data.target_directory === 'string'
+      ? data.target_directory
+      : path.join(workspaceRoot, 'target'),
+    packages,
+    rootPackage: packages.find(pkg => path.resolve(pkg.manifestPath) === path.resolve(rootManifest)),
+    profiles: {}
+  };
+}
+
+/**
+ * Find the package whose manifest lives in the given directory
+ */
+export function findPackageByDir(metadata: CargoMetadata, dir: string): CargoPackage | undefined {
+  const resolved = path.resolve(dir);
+  return metadata.packages.find(pkg => path.resolve(pkg.rootDir) === resolved);
+}
+
+/**
+ * Find the workspace member that owns a source file: the package with the
+ * deepest root directory containing the file.
+ */
+export function findPackageForFile(metadata: CargoMetadata, filePath: string): CargoPackage | undefined {
+  const resolved = path.resolve(filePath);
+  let owner: CargoPackage | undefined;
+  for (const pkg of metadata.packages) {
+    const rootDir = path.resolve(pkg.rootDir);
+    if (resolved.startsWith(rootDir + path.sep) && (!owner || rootDir.length > path.resolve(owner.rootDir).length)) {
+      owner = pkg;
+    }
+  }
+  return owner;
+}
+
+/**
+ * Executable targets (bins, examples, integration tests, benches) whose crate
+ * root is the given source file
+ */
+export function findExecutableTargetsForFile(pkg: CargoPackage, filePath: string): CargoTarget[] {
+  const resolved = path.resolve(filePath);
+  return pkg.targets.filter(target =>
+    getExecutableKind(target) !== undefined && path.resolve(target.src_path) === resolved
+  );
+}
+
+/**
+ * The executable kind of a target, or undefined for libraries, build scripts
+ * and library-type examples
+ */
+export function getExecutableKind(target: CargoTarget): ExecutableTargetKind | undefined {
+  const kind = EXECUTABLE_TARGET_KINDS.find(k => target.kind.includes(k));
+  if (kind === 'example' && target.crate_types && !target.crate_types.includes('bin')) {
+    return undefined;
+  }
+  return kind;
+}
+
+/**
+ * Read the `[profile.<name>]` tables of a manifest. Only plain key = value
+ * pairs are read; per-package overrides (`[profile.dev.package.foo]`) and
+ * `build-override` tables are skipped.
+ */
+export async function readCargoProfiles(manifestPath: string): Promise<Record<string, CargoProfile>> {
+  let content: string;
+  try {
+    content = await fs.readFile(manifestPath, 'utf-8');
+  } catch {
+    return {};
+  }
+  return parseCargoProfiles(content);
+}
+
+export function parseCargoProfiles(content: string): Record<string, CargoProfile> {
+  const profiles: Record<string, CargoProfile> = {};
+  let current: CargoProfile | undefined;
+
+  for (const rawLine of content.split(/\r?\n/)) {
+    const line = stripTomlComment(rawLine).trim();
+    if (line.length === 0) {
+      continue;
+    }
+
+    if (line.startsWith('[')) {
+      const header = /^\[\s*profile\s*\.\s*("?)([A-Za-z0-9_-]+)\1\s*\]$/.exec(line);
+      if (header) {
+        const name = header[2];
+        current = profiles[name] ?? (profiles[name] = { name, settings: {} });
+      } else {
+        current = undefined;
+      }
+      continue;
+    }
+
+    if (!current) {
+      continue;
+    }
+    const entry = /^([A-Za-z0-9_-]+)\s*=\s*(.+)$/.exec(line);
+    if (!entry) {
+      continue;
+    }
+    const value = parseTomlScalar(entry[2]);
+    if (value === undefined) {
+      continue;
+    }
+    current.settings[entry[1]] = value;
+    if (entry[1] === 'inherits' && typeof value === 'string') {
+      current.inherits = value;
+    } else if (entry[1] === 'opt-level') {
+      current.optLevel = typeof value === 'boolean' ? String(value) : value;
+    } else if (entry[1] === 'debug') {
+      current.debug = value;
+    }
+  }
+
+  return profiles;
+}
+
+/**
+ * Directory under the target directory where a profile's artifacts go:
+ * `dev` and `test` build into `debug`, `bench` into `release`, custom
+ * profiles into a directory named after the profile.
+ */
+export function getProfileDirName(profile: string): string {
+  switch (profile) {
+    case 'dev':
+    case 'test':
+      return 'debug';
+    case 'bench':
+      return 'release';
+    default:
+      return profile;
+  }
+}
+
+function stripTomlComment(line: string): string {
+  let quote: string | undefined;
+  for (let i = 0; i < line.length; i++) {
+    const ch = line[i];
+    if (quote) {
+      if (ch === '\\' && quote === '"') {
+        i++;
+      } else if (ch === quote) {
+        quote = undefined;
+      }
+    } else if (ch === '"' || ch === "'") {
+      quote = ch;
+    } else if (ch === '#') {
+      return line.slice(0, i);
+    }
+  }
+  return line;
+}
+
+function parseTomlScalar(text: string): string | number | boolean | undefined {
+  const value = text.trim();
+  if (value === 'true' || value === 'false') {
+    return value === 'true';
+  }
+  const quoted = /^"((?:[^"\\]|\\.)*)"$|^'([^']*)'$/.exec(value);
+  if (quoted) {
+    return quoted[1] !== undefined ? quoted[1].replace(/\\(["\\])/g, '$1') : quoted[2];
+  }
+  if (/^[+-]?\d[\d_]*$/.test(value)) {
+    return parseInt(value.replace(/_/g, ''), 10);
+  }
+  // Arrays and inline tables are not needed for profile settings
+  return undefined;
+}
@@ -0,0 +1,106 @@
+/**
+ * Cargo build progress
+ *
+ * Finished units are counted from cargo's JSON messages: every unit ends with
+ * a `compiler-artifact` message, or `build-script-executed` for build script
+ * runs, fresh units included. The total comes from cargo's progress bar
+ * (`Building [=====>    ] 12/28: tokio`), which cargo only draws on a
+ * terminal unless told to with CARGO_TERM_PROGRESS_WHEN.
+ */
+
+import type { LaunchProgress } from '@debugmcp/shared';
+import { getPackageName } from './cargo-fingerprint.js';
+
+/**
+ * Environment that makes cargo draw its progress bar on a pipe
+ * ("always" requires a width)
+ */
+export const CARGO_PROGRESS_ENV: Record<string, string> = {
+  CARGO_TERM_PROGRESS_WHEN: 'always',
+  CARGO_TERM_PROGRESS_WIDTH: '80'
+};
+
+const PROGRESS_BAR = /^ *Building \[[=> ]*\] +(\d+)\/(\d+)/;
+const PROGRESS_BAR_REDRAW = / *Building \[[=> ]*\] +\d+\/\d+[^\r\n]*\r?/g;
+
+/**
+ * Turns cargo output into progress reports: one per finished unit, with the
+ * total once cargo has drawn its progress bar
+ */
+export class CargoProgressTracker {
+  private done = 0;
+  private total?: number;
+  private stdoutRest = '';
+  private stderrRest = '';
+
+  constructor(private readonly onProgress?: (progress: LaunchProgress) => void) {}
+
+  /**
+   * Report the start of the build
+   */
+  start(message: string): void {
+    this.onProgress?.({ progress: 0, message });
+  }
+
+  /**
+   * Feed a chunk of cargo's JSON stdout
+   */
+  handleStdout(chunk: string): void {
+    const lines = (this.stdoutRest + chunk).split('\n');
+    this.stdoutRest = lines.pop() ?? '';
+    for (const line of lines) {
+      const message = describeFinishedUnit(line);
+      if (message) {
+        this.done++;
+        this.onProgress?.({
+          progress: this.done,
+          total: this.total === undefined ? undefined : Math.max(this.total, this.done),
+          message
+        });
+      }
+    }
+  }
+
+  /**
+   * Feed a chunk of cargo's stderr; only the progress bar is read
+   */
+  handleStderr(chunk: string): void {
+    // Redraws end with \r rather than a newline
+    const segments = (this.stderrRest + chunk).split(/[\r\n]/);
+    this.stderrRest = segments.pop() ?? '';
+    for (const segment of segments) {
+      const bar = PROGRESS_BAR.exec(segment);
+      if (bar) {
+        this.total = Number(bar[2]);
+      }
+    }
+  }
+}
+
+/**
+ * Remove progress bar redraws from cargo's stderr
+ */
+export function stripProgressBar(stderr: string): string {
+  return stderr.replace(PROGRESS_BAR_REDRAW, '');
+}
+
+function describeFinishedUnit(line: string): string | undefined {
+  if (!line.startsWith('{')) {
+    return undefined;
+  }
+  let message: { reason?: string; package_id?: string; target?: { name?: string; kind?: string[] }; fresh?: boolean };
+  try {
+    message = JSON.parse(line);
+  } catch {
+    return undefined;
+  }
+  const pkg = getPackageName(String(message.package_id ?? ''));
+  if (message.reason === 'compiler-artifact') {
+    const kind = message.target?.kind?.join(',') ?? 'lib';
+    return `${message.fresh ? 'Fresh' : 'Compiled'} ${pkg} (${kind} "${message.target?.name ?? pkg}")`;
+  }
+  if (message.reason === 'build-script-executed') {
+    return `Ran build script of ${pkg}`;
+  }
+  return undefined;
+}
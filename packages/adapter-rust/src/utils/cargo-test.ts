@@ -0,0 +1,257 @@
+/**
+ * Locate and prepare individual `#[test]` functions for debugging
+ *
+ * The test harness is built with `cargo test --no-run`, each harness
+ * executable is asked for its test list, and the requested test is matched
+ * against the fully qualified names the harness reports.
+ */
+
+import * as fs from 'fs/promises';
+import type { Dirent } from 'fs';
+import * as path from 'path';
+import { spawn } from 'child_process';
+import { BuildFailedError, type LaunchPreparationOptions } from '@debugmcp/shared';
+import {
+  findCargoProjectRoot,
+  runCargoJsonBuild,
+  type CargoArtifact,
+  type CargoBuildOptions,
+  type CargoBuildResult,
+  type CargoBuildTarget
+} from './cargo-utils.js';
+import { summarizeDiagnostics } from './cargo-diagnostics.js';
+
+/**
+ * Options for resolveRustTest
+ */
+export interface RustTestOptions {
+  /** Profile, features, target triple and package of the test build */
+  build?: CargoBuildOptions;
+  /** Restrict the build to one target, e.g. { kind: 'test', name: 'api' } */
+  target?: CargoBuildTarget;
+  /** Progress reporting and cancellation of the test build */
+  hooks?: LaunchPreparationOptions;
+  logger?: { info?: (msg: string) => void; error?: (msg: string) => void };
+}
+
+/**
+ * A test function ready to be launched under the debugger
+ */
+export interface RustTestLaunch {
+  /** Fully qualified test name as reported by the harness */
+  testName: string;
+  /** Harness executable containing the test */
+  executable: string;
+  /** Target the harness was built from */
+  target: { name: string; kind: string[]; src_path: string };
+  /** Arguments that run only this test, in-process and with output shown */
+  args: string[];
+  /** Directory cargo runs tests from (the package root) */
+  cwd: string;
+  /** Location of the first statement of the test body, when found */
+  location?: { file: string; line: number };
+}
+
+/**
+ * Build the test harnesses and find the executable that contains a test.
+ * `testName` may be fully qualified (`tests::parses_input`) or just the
+ * function name when that is unambiguous.
+ */
+export async function resolveRustTest(
+  projectPath: string,
+  testName: string,
+  options: RustTestOptions = {}
+): Promise<RustTestLaunch> {
+  const projectRoot = projectPath.endsWith('.rs')
+    ? await findCargoProjectRoot(projectPath)
+    : path.resolve(projectPath);
+
+  const build = await buildCargoTests(projectRoot, options);
+  if (!build.success) {
+    if (build.cancelled) {
+      throw new BuildFailedError('Cargo test build cancelled', []);
+    }
+    const diagnostics = build.diagnostics ?? [];
+    const summary = summarizeDiagnostics(diagnostics) ?? build.error ?? 'unknown error';
+    throw new BuildFailedError(`Cargo test build failed: ${summary}`, diagnostics, build.error);
+  }
+
+  const harnesses = (build.artifacts ?? []).filter(a => a.test && a.executable);
+  const matches: Array<{ artifact: CargoArtifact; name: string }> = [];
+  for (const artifact of harnesses) {
+    const names = await listHarnessTests(artifact.executable!, projectRoot);
+    for (const name of names) {
+      if (name === testName || name.endsWith(`::${testName}`)) {
+        matches.push({ artifact, name });
+      }
+    }
+  }
+
+  if (matches.length === 0) {
+    throw new Error(`No test named "${testName}" found in ${harnesses.length} test executable(s) of ${projectRoot}`);
+  }
+  // An exact name wins over suffix matches
+  const exact = matches.filter(m => m.name === testName);
+  const selected = exact.length === 1 ? exact : matches;
+  if (selected.length > 1) {
+    const list = selected.map(m => `${m.name} (${m.artifact.target.kind.join(',')} "${m.artifact.target.name}")`).join(', ');
+    throw new Error(`Test name "${testName}" is ambiguous: ${list}. Use the fully qualified name or restrict the build to one test target.`);
+  }
+
+  const { artifact, name } = selected[0];
+  options.logger?.info?.(`[Rust Debugger] Test ${name} is in ${artifact.executable}`);
+
+  return {
+    testName: name,
+    executable: artifact.executable!,
+    target: artifact.target,
+    args: ['--exact', name, '--nocapture', '--test-threads=1'],
+    cwd: projectRoot,
+    location: await findTestFunction(artifact.target.src_path, name)
+  };
+}
+
+/**
+ * Run `cargo test --no-run --message-format=json` through the same build path
+ * as buildCargoProject and collect the artifacts
+ */
+export async function buildCargoTests(
+  projectRoot: string,
+  options: RustTestOptions = {}
+): Promise<CargoBuildResult> {
+  options.logger?.info?.(`[Rust Debugger] Building tests at ${projectRoot}...`);
+  return runCargoJsonBuild(['test', '--no-run'], projectRoot, options.logger, options.build, options.target, options.hooks);
+}
+
+/**
+ * List the tests compiled into a harness executable (`--list --format=terse`)
+ */
+export async function listHarnessTests(executable: string, cwd: string): Promise<string[]> {
+  return new Promise((resolve) => {
+    const listProcess = spawn(executable, ['--list', '--format=terse'], { cwd });
+
+    let output = '';
+
+    listProcess.stdout?.on('data', (data) => {
+      output += data.toString();
+    });
+
+    listProcess.on('error', () => resolve([]));
+    // 'close' so the whole list is read before parsing
+    listProcess.on('close', () => {
+      resolve(parseTestList(output));
+    });
+  });
+}
+
+/**
+ * Parse `--list --format=terse` output (`path::to::name: test`), skipping benches
+ */
+export function parseTestList(output: string): string[] {
+  return output
+    .split(/\r?\n/)
+    .map(line => /^(.+): test$/.exec(line.trim())?.[1])
+    .filter((name): name is string => name !== undefined);
+}
+
+/**
+ * Find the first statement of a test function below a crate root. Files are
+ * preferred when their path matches the test's module path.
+ */
+export async function findTestFunction(
+  crateRoot: string,
+  testName: string
+): Promise<{ file: string; line: number } | undefined> {
+  const segments = testName.split('::');
+  const fnName = segments[segments.length - 1];
+  const modules = segments.slice(0, -1);
+  const files = crateRoot.endsWith('.rs')
+    ? [crateRoot, ...(await collectRustFiles(path.dirname(crateRoot))).filter(f => f !== crateRoot)]
+    : await collectRustFiles(crateRoot);
+
+  let best: { file: string; line: number; score: number } | undefined;
+  for (const file of files) {
+    let content: string;
+    try {
+      content = await fs.readFile(file, 'utf-8');
+    } catch {
+      continue;
+    }
+    const line = findTestBodyLine(content, fnName);
+    if (line === undefined) {
+      continue;
+    }
+    const fileModules = path.relative(path.dirname(crateRoot), file).replace(/\.rs$/, '').split(path.sep);
+    const score = modules.filter(m => fileModules.includes(m)).length;
+    if (!best || score > best.score) {
+      best = { file, line, score };
+    }
+  }
+  return best ? { file: best.file, line: best.line } : undefined;
+}
+
+/**
+ * 1-based line of the first statement in `fn <name>` when the function carries
+ * a test attribute (`#[test]`, `#[tokio::test]`, ...). Falls back to the line
+ * of the opening brace when the body is empty.
+ */
+export function findTestBodyLine(content: string, fnName: string): number | undefined {
+  const lines = content.split(/\r?\n/);
+  const fnPattern = new RegExp(`\\bfn\\s+${fnName}\\s*[(<]`);
+
+  for (let i = 0; i < lines.length; i++) {
+    if (!fnPattern.test(lines[i])) {
+      continue;
+    }
+    // Look back over attributes and doc comments for a test attribute
+    let isTest = false;
+    for (let j = i - 1; j >= 0; j--) {
+      const previous = lines[j].trim();
+      if (/^#\[(\w+::)*test\b/.test(previous)) {
+        isTest = true;
+        break;
+      }
+      if (!(previous.startsWith('#[') || previous.startsWith('//') || previous === '')) {
+        break;
+      }
+    }
+    if (!isTest) {
+      continue;
+    }
+
+    let braceLine = i;
+    while (braceLine < lines.length && !lines[braceLine].includes('{')) {
+      braceLine++;
+    }
+    for (let k = braceLine + 1; k < lines.length; k++) {
+      const text = lines[k].trim();
+      if (text === '}') {
+        break;
+      }
+      if (text !== '' && !text.startsWith('//')) {
+        return k + 1;
+      }
+    }
+    return Math.min(braceLine, lines.length - 1) + 1;
+  }
+  return undefined;
+}
+
+async function collectRustFiles(dir: string): Promise<string[]> {
+  const files: string[] = [];
+  let entries: Dirent[];
+  try {
+    entries = await fs.readdir(dir, { withFileTypes: true });
+  } catch {
+    return files;
+  }
+  for (const entry of entries) {
+    const fullPath = path.join(dir, entry.name);
+    if (entry.isDirectory() && entry.name !== 'target') {
+      files.push(...(await collectRustFiles(fullPath)));
+    } else if (entry.name.endsWith('.rs')) {
+      files.push(fullPath);
+    }
+  }
+  return files;
+}
@@ -4,15 +4,41 @@
 
 import * as fs from 'fs/promises';
 import * as path from 'path';
-import { spawn } from 'child_process';
+import { spawn, type ChildProcess } from 'child_process';
+import {
+  loadCargoMetadata,
+  findPackageByDir,
+  findPackageForFile,
+  findExecutableTargetsForFile,
+  getExecutableKind,
+  getProfileDirName,
+  type CargoMetadata,
+  type CargoPackage,
+  type CargoTarget,
+  type ExecutableTargetKind
+} from './cargo-metadata.js';
+import {
+  CARGO_FINGERPRINT_LOG_ENV,
+  getPackageName,
+  getStaleUnits,
+  stripFingerprintLog,
+  type CargoStaleUnit
+} from './cargo-fingerprint.js';
+import { parseCargoDiagnostics, renderCargoDiagnostics } from './cargo-diagnostics.js';
+import { CARGO_PROGRESS_ENV, CargoProgressTracker, stripProgressBar } from './cargo-progress.js';
+import type { CompilerDiagnostic, LaunchPreparationOptions } from '@debugmcp/shared';
+
+export type { CargoTarget } from './cargo-metadata.js';
+export type { CargoStaleUnit } from './cargo-fingerprint.js';
 
 /**
- * Cargo target information
+ * Pick the package a project path refers to: the package whose manifest is in
+ * that directory, else the workspace root package, else the only member.
  */
-export interface CargoTarget {
-  name: string;
-  kind: string[];
-  src_path: string;
+function selectPackage(metadata: CargoMetadata, projectPath: string): CargoPackage | undefined {
+  return findPackageByDir(metadata, projectPath)
+    ?? metadata.rootPackage
+    ?? (metadata.packages.length === 1 ? metadata.packages[0] : undefined);
 }
 
 /**
@@ -23,76 +49,32 @@ export async function resolveCargoProject(projectPath: string): Promise<{
   version: string;
   targets: CargoTarget[];
 } | null> {
-  try {
-    const cargoTomlPath = path.join(projectPath, 'Cargo.toml');
-    const cargoTomlContent = await fs.readFile(cargoTomlPath, 'utf-8');
-    
-    // Parse basic info from Cargo.toml (simplified parsing)
-    const nameMatch = cargoTomlContent.match(/name\s*=\s*"([^"]+)"/);
-    const versionMatch = cargoTomlContent.match(/version\s*=\s*"([^"]+)"/);
-    
-    if (!nameMatch) {
-      return null;
-    }
-    
-    // Get targets using cargo metadata
-    const targets = await getCargoTargets(projectPath);
-    
-    return {
-      name: nameMatch[1],
-      version: versionMatch ? versionMatch[1] : '0.1.0',
-      targets
-    };
-  } catch {
+  const metadata = await loadCargoMetadata(projectPath);
+  const pkg = metadata ? selectPackage(metadata, projectPath) : undefined;
+  if (!pkg || !pkg.name) {
     return null;
   }
+
+  return {
+    name: pkg.name,
+    version: pkg.version,
+    targets: pkg.targets
+  };
 }
 
 /**
- * Get Cargo targets (binaries, libraries, tests, etc.)
+ * Get Cargo targets (binaries, libraries, tests, etc.) of every package under a path
  */
 export async function getCargoTargets(projectPath: string): Promise<CargoTarget[]> {
-  return new Promise((resolve) => {
-    const metadataProcess = spawn('cargo', ['metadata', '--format-version', '1'], {
-      cwd: projectPath,
-      shell: true
-    });
-    
-    let output = '';
-    
-    metadataProcess.stdout?.on('data', (data) => {
-      output += data.toString();
-    });
-    
-    metadataProcess.on('error', () => resolve([]));
-    metadataProcess.on('exit', (code) => {
-      if (code === 0 && output) {
-        try {
-          const metadata = JSON.parse(output);
-          const targets: CargoTarget[] = [];
-          
-          // Extract targets from packages
-          for (const pkg of metadata.packages) {
-            if (pkg.manifest_path.startsWith(projectPath)) {
-              for (const target of pkg.targets) {
-                targets.push({
-                  name: target.name,
-                  kind: target.kind,
-                  src_path: target.src_path
-                });
-              }
-            }
-          }
-          
-          resolve(targets);
-        } catch {
-          resolve([]);
-        }
-      } else {
-        resolve([]);
-      }
-    });
-  });
+  const metadata = await loadCargoMetadata(projectPath);
+  if (!metadata) {
+    return [];
+  }
+
+  const root = path.resolve(projectPath);
+  return metadata.packages
+    .filter(pkg => path.resolve(pkg.manifestPath).startsWith(root))
+    .flatMap(pkg => pkg.targets);
 }
 
 /**
@@ -150,78 +132,6 @@ export async function runCargoTest(
   });
 }
 
-/**
- * Check if a Cargo project needs rebuilding
- */
-export async function needsRebuild(
-  projectPath: string,
-  binaryName: string,
-  release: boolean = false
-): Promise<boolean> {
-  const targetDir = path.join(
-    projectPath,
-    'target',
-    release ? 'release' : 'debug'
-  );
-  
-  const extension = process.platform === 'win32' ? '.exe' : '';
-  const binaryPath = path.join(targetDir, `${binaryName}${extension}`);
-  
-  try {
-    // Check if binary exists
-    const binaryStats = await fs.stat(binaryPath);
-    
-    // Check source file modification times
-    const srcDir = path.join(projectPath, 'src');
-    const srcFiles = await getAllRustFiles(srcDir);
-    
-    for (const srcFile of srcFiles) {
-      const srcStats = await fs.stat(srcFile);
-      if (srcStats.mtime > binaryStats.mtime) {
-        return true;
-      }
-    }
-    
-    // Check Cargo.toml modification time
-    const cargoTomlPath = path.join(projectPath, 'Cargo.toml');
-    const cargoStats = await fs.stat(cargoTomlPath);
-    if (cargoStats.mtime > binaryStats.mtime) {
-      return true;
-    }
-    
-    return false;
-  } catch {
-    // Binary doesn't exist or error accessing files
-    return true;
-  }
-}
-
-/**
- * Get all Rust source files recursively
- */
-async function getAllRustFiles(dir: string): Promise<string[]> {
-  const files: string[] = [];
-  
-  try {
-    const entries = await fs.readdir(dir, { withFileTypes: true });
-    
-    for (const entry of entries) {
-      const fullPath = path.join(dir, entry.name);
-      
-      if (entry.isDirectory()) {
-        const subFiles = await getAllRustFiles(fullPath);
-        files.push(...subFiles);
-      } else if (entry.name.endsWith('.rs')) {
-        files.push(fullPath);
-      }
-    }
-  } catch {
-    // Ignore errors reading directories
-  }
-  
-  return files;
-}
-
 /**
  * Run Cargo build with specified arguments
  */
@@ -264,18 +174,32 @@ export async function runCargoBuild(
 }
 
 /**
- * Get the default binary name from Cargo.toml
+ * Get the default binary name: `default-run`, then the first binary target,
+ * then the package name
  */
 export async function getDefaultBinary(projectPath: string): Promise<string> {
-  const cargoProject = await resolveCargoProject(projectPath);
-  if (cargoProject) {
-    // Find the first binary target
-    const binTargets = cargoProject.targets.filter(t => t.kind.includes('bin'));
+  const metadata = await loadCargoMetadata(projectPath);
+  return resolveDefaultBinary(projectPath, metadata);
+}
+
+async function resolveDefaultBinary(
+  projectPath: string,
+  metadata: CargoMetadata | null,
+  packageName?: string
+): Promise<string> {
+  const pkg = metadata
+    ? (packageName ? metadata.packages.find(p => p.name === packageName) : undefined) ?? selectPackage(metadata, projectPath)
+    : undefined;
+  if (pkg && pkg.name) {
+    const binTargets = pkg.targets.filter(t => t.kind.includes('bin'));
+    if (pkg.defaultRun && binTargets.some(t => t.name === pkg.defaultRun)) {
+      return pkg.defaultRun;
+    }
     if (binTargets.length > 0) {
       return binTargets[0].name;
     }
     // Fallback to package name
-    return cargoProject.name;
+    return pkg.name;
   }
   
   // Last resort: look for main.rs
@@ -314,69 +238,607 @@ export async function findCargoProjectRoot(filePath: string): Promise<string> {
 }
 
 /**
- * Build the Cargo project with progress reporting
+ * A single Cargo target to build, selected with `--bin`/`--example`/`--test`/`--bench`
+ */
+export interface CargoBuildTarget {
+  kind: ExecutableTargetKind;
+  name: string;
+}
+
+/**
+ * Binary that debugging a Rust source file resolves to
+ */
+export interface CargoBinaryResolution {
+  /** Directory containing the owning package's Cargo.toml */
+  packageRoot: string;
+  packageName?: string;
+  workspaceRoot: string;
+  /** Build output directory reported by cargo (CARGO_TARGET_DIR and build.target-dir aware) */
+  targetDirectory: string;
+  /** Target whose crate root is the source file; undefined when the package default binary is used */
+  target?: CargoBuildTarget;
+  binaryName: string;
+  /**
+   * Expected executable path. Undefined for tests and benches that have not
+   * been built yet, since their file names carry a hash.
+   */
+  binaryPath?: string;
+}
+
+/**
+ * Get the build output directory of a project, falling back to `<project>/target`
+ * when cargo metadata is unavailable
+ */
+export async function getCargoTargetDirectory(projectPath: string): Promise<string> {
+  const metadata = await loadCargoMetadata(projectPath);
+  return metadata?.targetDirectory ?? path.join(projectPath, 'target');
+}
+
+/**
+ * Cargo command line flags that select a single target
+ */
+export function getCargoTargetArgs(target: CargoBuildTarget): string[] {
+  return [`--${target.kind}`, target.name];
+}
+
+/**
+ * Profile, features, cross-compilation target and package of a build
+ */
+export interface CargoBuildOptions {
+  /** Build with the `release` profile; ignored when `profile` is set */
+  release?: boolean;
+  /** Named profile, e.g. `debugging` for `[profile.debugging]` */
+  profile?: string;
+  features?: string[];
+  allFeatures?: boolean;
+  noDefaultFeatures?: boolean;
+  /** Target triple to cross-compile for, e.g. `aarch64-unknown-linux-gnu` */
+  target?: string;
+  /** Workspace member to build */
+  package?: string;
+}
+
+/**
+ * Profile a build uses: the named profile, else `release` or `dev`
+ */
+export function getCargoProfile(options: CargoBuildOptions = {}): string {
+  return options.profile ?? (options.release ? 'release' : 'dev');
+}
+
+/**
+ * Cargo command line flags for build options
+ */
+export function getCargoBuildArgs(options: CargoBuildOptions = {}): string[] {
+  const args: string[] = [];
+  if (options.profile) {
+    // cargo rejects --release together with --profile
+    args.push('--profile', options.profile);
+  } else if (options.release) {
+    args.push('--release');
+  }
+  if (options.features && options.features.length > 0) {
+    args.push('--features', options.features.join(','));
+  }
+  if (options.allFeatures) {
+    args.push('--all-features');
+  }
+  if (options.noDefaultFeatures) {
+    args.push('--no-default-features');
+  }
+  if (options.target) {
+    args.push('--target', options.target);
+  }
+  if (options.package) {
+    args.push('--package', options.package);
+  }
+  return args;
+}
+
+/**
+ * Directory a build's artifacts go to: `<target-dir>/<profile-dir>`, or
+ * `<target-dir>/<triple>/<profile-dir>` when cross-compiling
+ */
+export function getCargoProfileDir(targetDirectory: string, options: CargoBuildOptions = {}): string {
+  const profileDir = getProfileDirName(getCargoProfile(options));
+  return options.target
+    ? path.join(targetDirectory, options.target, profileDir)
+    : path.join(targetDirectory, profileDir);
+}
+
+/**
+ * Resolve the binary to debug for a Rust source file. The file is mapped to
+ * the bin, example, integration test or bench whose crate root it is; other
+ * files fall back to the package's default binary. Handles workspace members,
+ * whose artifacts live in the workspace's target directory rather than next
+ * to their own Cargo.toml.
+ *
+ * Throws when the file is the crate root of more than one target.
+ */
+export async function resolveCargoBinary(
+  filePath: string,
+  options: CargoBuildOptions = {}
+): Promise<CargoBinaryResolution> {
+  const manifestDir = await findCargoProjectRoot(filePath);
+  const metadata = await loadCargoMetadata(manifestDir);
+  const pkg = metadata
+    ? findPackageForFile(metadata, filePath) ?? selectPackage(metadata, manifestDir)
+    : undefined;
+
+  const matches = pkg ? findExecutableTargetsForFile(pkg, filePath) : [];
+  if (matches.length > 1) {
+    const list = matches.map(t => `${getExecutableKind(t)} "${t.name}"`).join(', ');
+    throw new Error(
+      `${filePath} is the source of multiple Cargo targets: ${list}. ` +
+      'Pass the built executable as the program to debug one of them.'
+    );
+  }
+
+  const packageRoot = pkg?.rootDir ?? manifestDir;
+  const targetDirectory = metadata?.targetDirectory ?? path.join(packageRoot, 'target');
+  const profileDir = getCargoProfileDir(targetDirectory, options);
+  const target = matches.length === 1
+    ? { kind: getExecutableKind(matches[0])!, name: matches[0].name }
+    : undefined;
+  const binaryName = target?.name ?? await resolveDefaultBinary(packageRoot, metadata);
+
+  return {
+    packageRoot,
+    packageName: pkg?.name,
+    workspaceRoot: metadata?.workspaceRoot ?? packageRoot,
+    targetDirectory,
+    target,
+    binaryName,
+    binaryPath: await getArtifactPath(profileDir, target ?? { kind: 'bin', name: binaryName })
+  };
+}
+
+/**
+ * Locate a target's executable in a profile output directory. Binaries sit at
+ * the top level and examples under `examples/`; tests and benches are only
+ * found in `deps/` as `<crate>-<hash>`, so the newest match is returned.
+ */
+async function getArtifactPath(profileDir: string, target: CargoBuildTarget): Promise<string | undefined> {
+  const extension = process.platform === 'win32' ? '.exe' : '';
+  switch (target.kind) {
+    case 'bin':
+      return path.join(profileDir, `${target.name}${extension}`);
+    case 'example':
+      return path.join(profileDir, 'examples', `${target.name}${extension}`);
+    default:
+      return findHashedArtifact(path.join(profileDir, 'deps'), target.name.replace(/-/g, '_'), extension);
+  }
+}
+
+async function findHashedArtifact(depsDir: string, crateName: string, extension: string): Promise<string | undefined> {
+  let entries: string[];
+  try {
+    entries = await fs.readdir(depsDir);
+  } catch {
+    return undefined;
+  }
+
+  const escapedExt = extension.replace('.', '\\.');
+  const pattern = new RegExp(`^${crateName}-[0-9a-f]{16}${escapedExt}$`);
+  let newest: { file: string; mtime: number } | undefined;
+  for (const entry of entries.filter(e => pattern.test(e))) {
+    const file = path.join(depsDir, entry);
+    try {
+      const stats = await fs.stat(file);
+      if (stats.isFile() && (!newest || stats.mtimeMs > newest.mtime)) {
+        newest = { file, mtime: stats.mtimeMs };
+      }
+    } catch {
+      // Ignore entries removed while scanning
+    }
+  }
+  return newest?.file;
+}
+
+/**
+ * A target as reported by list_rust_targets
+ */
+export interface RustTargetInfo {
+  package: string;
+  name: string;
+  /** bin, example, test, bench or lib */
+  kind: string;
+  srcPath: string;
+  requiredFeatures: string[];
+  /** Path of the debug-profile artifact, or null when it has not been built */
+  debugBuild: string | null;
+  /**
+   * Whether cargo's fingerprint check found the debug build out of date (it
+   * is rebuilt by the check); null when not built
+   */
+  stale: boolean | null;
+}
+
+const LIBRARY_KINDS = ['lib', 'rlib', 'dylib', 'cdylib', 'staticlib', 'proc-macro'];
+
+/**
+ * List the bin, example, test, bench and lib targets of the packages under a
+ * directory, or of the package owning a file, with their debug build status.
+ * Built targets are checked with a debug build of each, which is a no-op when
+ * cargo finds them fresh.
+ */
+export async function listRustTargets(targetPath: string): Promise<{
+  workspaceRoot: string;
+  targetDirectory: string;
+  targets: RustTargetInfo[];
+}> {
+  const isFile = targetPath.endsWith('.rs') || targetPath.endsWith('Cargo.toml');
+  const projectRoot = isFile ? await findCargoProjectRoot(targetPath) : path.resolve(targetPath);
+  const metadata = await loadCargoMetadata(projectRoot);
+  if (!metadata) {
+    throw new Error(`cargo metadata failed for ${projectRoot}; is it inside a Cargo project?`);
+  }
+
+  let packages: CargoPackage[];
+  if (isFile) {
+    const owner = findPackageForFile(metadata, targetPath) ?? selectPackage(metadata, projectRoot);
+    packages = owner ? [owner] : [];
+  } else {
+    packages = metadata.packages.filter(pkg => {
+      const rootDir = path.resolve(pkg.rootDir);
+      return rootDir === projectRoot || rootDir.startsWith(projectRoot + path.sep);
+    });
+    if (packages.length === 0) {
+      // A directory inside a package, e.g. src/
+      const owner = findPackageForFile(metadata, path.join(projectRoot, 'Cargo.toml'));
+      packages = owner ? [owner] : [];
+    }
+  }
+
+  const profileDir = path.join(metadata.targetDirectory, getProfileDirName('dev'));
+  const targets: RustTargetInfo[] = [];
+  for (const pkg of packages) {
+    for (const target of pkg.targets) {
+      const executableKind = getExecutableKind(target);
+      const isLibrary = !executableKind && target.kind.some(k => LIBRARY_KINDS.includes(k));
+      if (!executableKind && !isLibrary) {
+        continue;
+      }
+
+      const debugBuild = executableKind
+        ? await getArtifactPath(profileDir, { kind: executableKind, name: target.name })
+        : await findHashedArtifact(path.join(profileDir, 'deps'), `lib${target.name.replace(/-/g, '_')}`, '.rlib');
+      const exists = debugBuild !== undefined && await fs.access(debugBuild).then(() => true, () => false);
+
+      targets.push({
+        package: pkg.name,
+        name: target.name,
+        kind: executableKind ?? 'lib',
+        srcPath: target.src_path,
+        requiredFeatures: target.required_features ?? [],
+        debugBuild: exists ? debugBuild! : null,
+        stale: exists ? await isDebugBuildStale(metadata.workspaceRoot, pkg, target, executableKind) : null
+      });
+    }
+  }
+
+  return {
+    workspaceRoot: metadata.workspaceRoot,
+    targetDirectory: metadata.targetDirectory,
+    targets
+  };
+}
+
+/**
+ * Run cargo's freshness check on one target's debug build: a build of just
+ * that target (with its required features) reports `fresh` for it when
+ * nothing it depends on changed. A failed build counts as stale.
+ */
+async function isDebugBuildStale(
+  workspaceRoot: string,
+  pkg: CargoPackage,
+  target: CargoTarget,
+  executableKind: ExecutableTargetKind | undefined
+): Promise<boolean> {
+  const options: CargoBuildOptions = { package: pkg.name, features: target.required_features };
+  const build = executableKind
+    ? await runCargoJsonBuild(['build'], workspaceRoot, undefined, options, { kind: executableKind, name: target.name })
+    : await runCargoJsonBuild(['build', '--lib'], workspaceRoot, undefined, options);
+  const artifact = build.artifacts?.find(a =>
+    getPackageName(a.packageId) === pkg.name &&
+    a.target.name === target.name &&
+    a.target.kind.some(kind => (executableKind ? kind === executableKind : LIBRARY_KINDS.includes(kind)))
+  );
+  return !artifact?.fresh;
+}
+
+/**
+ * An artifact reported by a `compiler-artifact` message of
+ * `cargo build --message-format=json`
+ */
+export interface CargoArtifact {
+  packageId: string;
+  target: CargoTarget;
+  /** True when built as a test harness (tests, benches, `cargo test`) */
+  test: boolean;
+  filenames: string[];
+  /** Path of the runnable executable, if the artifact is one */
+  executable?: string;
+  /** True when cargo reused an up-to-date artifact */
+  fresh: boolean;
+}
+
+/**
+ * Result of buildCargoProject
+ */
+export interface CargoBuildResult {
+  success: boolean;
+  /** Executable to launch */
+  binaryPath?: string;
+  /** Every artifact cargo reported, including fresh ones */
+  artifacts?: CargoArtifact[];
+  /** True when cargo found every unit up to date and compiled nothing */
+  fresh?: boolean;
+  /** Units cargo recompiled, with the reason from its fingerprint check */
+  staleUnits?: CargoStaleUnit[];
+  /** Compiler errors and warnings of a failed build */
+  diagnostics?: CompilerDiagnostic[];
+  /** True when the build was stopped through the abort signal */
+  cancelled?: boolean;
+  error?: string;
+}
+
+/**
+ * Extract `compiler-artifact` messages from cargo's JSON message stream.
+ * Lines that are not JSON (or other message kinds) are ignored.
+ */
+export function parseCargoArtifacts(output: string): CargoArtifact[] {
+  const artifacts: CargoArtifact[] = [];
+  for (const line of output.split(/\r?\n/)) {
+    if (!line.startsWith('{')) {
+      continue;
+    }
+    let message: Record<string, unknown>;
+    try {
+      message = JSON.parse(line);
+    } catch {
+      continue;
+    }
+    if (message.reason !== 'compiler-artifact' || !message.target || typeof message.target !== 'object') {
+      continue;
+    }
+    const target = message.target as Record<string, unknown>;
+    const profile = (message.profile ?? {}) as { test?: boolean };
+    artifacts.push({
+      packageId: String(message.package_id ?? ''),
+      target: {
+        name: String(target.name ?? ''),
+        kind: Array.isArray(target.kind) ? (target.kind as string[]) : [],
+        src_path: String(target.src_path ?? '')
+      },
+      test: profile.test === true,
+      filenames: Array.isArray(message.filenames) ? (message.filenames as string[]) : [],
+      executable: typeof message.executable === 'string' ? message.executable : undefined,
+      fresh: message.fresh === true
+    });
+  }
+  return artifacts;
+}
+
+/**
+ * Pick the executable to launch from build artifacts: the requested target,
+ * else the named default binary, else the only executable produced.
+ */
+export function selectArtifactExecutable(
+  artifacts: CargoArtifact[],
+  target?: CargoBuildTarget,
+  defaultBinary?: string
+): string | undefined {
+  const executables = artifacts.filter(a => a.executable);
+  if (target) {
+    return executables.find(a => a.target.name === target.name && a.target.kind.includes(target.kind))?.executable;
+  }
+  if (defaultBinary) {
+    const match = executables.find(a => a.target.name === defaultBinary && a.target.kind.includes('bin'));
+    if (match) {
+      return match.executable;
+    }
+  }
+  return executables.length === 1 ? executables[0].executable : undefined;
+}
+
+/**
+ * Locate an already built executable without invoking a build: binaries at
+ * the top of the profile directory, examples under `examples/`, and the
+ * newest `deps/<crate>-<hash>` for tests and benches.
+ */
+export async function locateCargoExecutable(
+  projectRoot: string,
+  options: CargoBuildOptions = {},
+  target?: CargoBuildTarget
+): Promise<string | undefined> {
+  const metadata = await loadCargoMetadata(projectRoot);
+  const profileDir = getCargoProfileDir(metadata?.targetDirectory ?? path.join(projectRoot, 'target'), options);
+  return getArtifactPath(
+    profileDir,
+    target ?? { kind: 'bin', name: await resolveDefaultBinary(projectRoot, metadata, options.package) }
+  );
+}
+
+/**
+ * Stop a cargo build started by spawnCargo, including the rustc and build
+ * script processes it runs
+ */
+function killCargoProcess(cargoProcess: ChildProcess): void {
+  if (!cargoProcess.pid) {
+    return;
+  }
+  if (process.platform === 'win32') {
+    spawn('taskkill', ['/PID', String(cargoProcess.pid), '/T', '/F'], { stdio: 'ignore', windowsHide: true })
+      .on('error', () => cargoProcess.kill());
+    return;
+  }
+  try {
+    // Negative pid: the process group spawnCargo created
+    process.kill(-cargoProcess.pid, 'SIGTERM');
+  } catch {
+    cargoProcess.kill('SIGTERM');
+  }
+}
+
+/**
+ * Spawn cargo in its own process group (so killCargoProcess reaches the
+ * compilers it starts) with its progress bar enabled. No shell: feature,
+ * profile, target and package names come from clients and are passed to
+ * cargo as they are.
+ */
+function spawnCargo(args: string[], cwd: string, env: Record<string, string> = {}): ChildProcess {
+  return spawn('cargo', args, {
+    cwd,
+    detached: process.platform !== 'win32',
+    env: { ...process.env, ...CARGO_PROGRESS_ENV, ...env }
+  });
+}
+
+/**
+ * Build the Cargo project with progress reporting. The executable is taken
+ * from cargo's `compiler-artifact` messages, so hashed test binaries, cross
+ * targets and custom profiles resolve to the file cargo actually produced.
+ * Each finished unit is reported through `hooks.onProgress`; aborting
+ * `hooks.signal` kills cargo.
  */
 export async function buildCargoProject(
   projectRoot: string,
   logger?: { info?: (msg: string) => void; error?: (msg: string) => void },
-  buildMode: 'debug' | 'release' = 'debug'
-): Promise<{ success: boolean; binaryPath?: string; error?: string }> {
+  options: CargoBuildOptions = {},
+  target?: CargoBuildTarget,
+  hooks: LaunchPreparationOptions = {}
+): Promise<CargoBuildResult> {
+  if (hooks.signal?.aborted) {
+    return { success: false, cancelled: true, error: 'Build cancelled' };
+  }
   logger?.info?.(`[Rust Debugger] Building project at ${projectRoot}...`);
   
-  const args = ['build'];
-  if (buildMode === 'release') {
-    args.push('--release');
+  const build = await runCargoJsonBuild(['build'], projectRoot, logger, options, target, hooks);
+  if (!build.success) {
+    return build;
+  }
+  const artifacts = build.artifacts ?? [];
+  const staleUnits = build.staleUnits ?? [];
+  try {
+    let binaryPath = selectArtifactExecutable(artifacts, target);
+    if (!binaryPath && !target) {
+      const metadata = await loadCargoMetadata(projectRoot);
+      const binaryName = await resolveDefaultBinary(projectRoot, metadata, options.package);
+      binaryPath = selectArtifactExecutable(artifacts, undefined, binaryName);
+      if (!binaryPath && artifacts.length === 0) {
+        // No artifact messages (e.g. an old cargo): fall back to the conventional path
+        binaryPath = await getArtifactPath(
+          getCargoProfileDir(metadata?.targetDirectory ?? path.join(projectRoot, 'target'), options),
+          { kind: 'bin', name: binaryName }
+        );
+      }
+    }
+    if (!binaryPath) {
+      const what = target ? `${target.kind} "${target.name}"` : 'the default binary';
+      throw new Error(`Build succeeded but cargo reported no executable for ${what}`);
+    }
+    
+    logger?.info?.(build.fresh
+      ? `[Rust Debugger] Up to date: ${binaryPath}`
+      : `[Rust Debugger] Build successful: ${binaryPath}`);
+    return { ...build, binaryPath };
+  } catch (error) {
+    const errorMsg = error instanceof Error ? error.message : String(error);
+    logger?.error?.(`[Rust Debugger] Failed to determine binary path: ${errorMsg}`);
+    return { success: false, artifacts, staleUnits, error: errorMsg };
+  }
+}
+
+/**
+ * Run a cargo command (`['build']`, `['test', '--no-run']`) with
+ * `--message-format=json` and the build options, reporting progress and
+ * honouring cancellation like buildCargoProject. Returns the artifacts and
+ * stale units of a successful build, or the parsed diagnostics of a failed
+ * one; picking an executable is left to the caller.
+ */
+export async function runCargoJsonBuild(
+  command: string[],
+  projectRoot: string,
+  logger?: { info?: (msg: string) => void; error?: (msg: string) => void },
+  options: CargoBuildOptions = {},
+  target?: CargoBuildTarget,
+  hooks: LaunchPreparationOptions = {}
+): Promise<CargoBuildResult> {
+  if (hooks.signal?.aborted) {
+    return { success: false, cancelled: true, error: 'Build cancelled' };
+  }
+  
+  // Plain JSON keeps rustc's diagnostics as compiler-message objects
+  const args = [...command, '--message-format=json', ...getCargoBuildArgs(options)];
+  if (target) {
+    args.push(...getCargoTargetArgs(target));
   }
   
   return new Promise((resolve) => {
-    const buildProcess = spawn('cargo', args, {
-      cwd: projectRoot,
-      shell: true
-    });
+    // cargo's fingerprint check is the freshness check: the build is a no-op
+    // when nothing changed, and the log says why each stale unit is rebuilt
+    const buildProcess = spawnCargo(args, projectRoot, CARGO_FINGERPRINT_LOG_ENV);
+    const progress = new CargoProgressTracker(hooks.onProgress);
+    progress.start(`cargo ${args.join(' ')}`);
     
     let stdout = '';
     let stderr = '';
+    let cancelled = false;
+    const onAbort = () => {
+      cancelled = true;
+      logger?.info?.('[Rust Debugger] Build cancelled, stopping cargo');
+      killCargoProcess(buildProcess);
+    };
+    hooks.signal?.addEventListener('abort', onAbort, { once: true });
     
     buildProcess.stdout?.on('data', (data) => {
       const msg = data.toString();
       stdout += msg;
-      // Show compilation progress
-      if (msg.includes('Compiling')) {
-        logger?.info?.(`[Rust Build] ${msg.trim()}`);
-      }
+      progress.handleStdout(msg);
     });
     
     buildProcess.stderr?.on('data', (data) => {
-      stderr += data.toString();
+      const msg = data.toString();
+      stderr += msg;
+      progress.handleStderr(msg);
+      // Show compilation progress (rendered by cargo on stderr, between progress bar redraws)
+      for (const line of msg.split(/[\r\n]+/)) {
+        if (/^\s+Compiling /.test(line)) {
+          logger?.info?.(`[Rust Build] ${line.trim()}`);
+        }
+      }
     });
     
     buildProcess.on('error', (error) => {
+      hooks.signal?.removeEventListener('abort', onAbort);
       const errorMsg = `Build process error: ${error.message}`;
       logger?.error?.(`[Rust Debugger] ${errorMsg}`);
       resolve({ success: false, error: errorMsg });
     });
     
-    buildProcess.on('exit', async (code) => {
+    // 'close', not 'exit': stdout may still hold the last compiler-artifact
+    // line (the final binary) when the process exits
+    buildProcess.on('close', async (code) => {
+      hooks.signal?.removeEventListener('abort', onAbort);
+      const artifacts = parseCargoArtifacts(stdout);
+      if (cancelled) {
+        resolve({ success: false, cancelled: true, artifacts, error: 'Build cancelled' });
+        return;
+      }
+      const cargoStderr = stripProgressBar(stderr);
+      const staleUnits = getStaleUnits(artifacts, cargoStderr);
+      const cargoOutput = stripFingerprintLog(cargoStderr).trim();
       if (code === 0) {
-        try {
-          const binaryName = await getDefaultBinary(projectRoot);
-          const binaryPath = path.join(
-            projectRoot,
-            'target',
-            buildMode,
-            process.platform === 'win32' ? `${binaryName}.exe` : binaryName
-          );
-          
-          logger?.info?.(`[Rust Debugger] Build successful: ${binaryPath}`);
-          resolve({ success: true, binaryPath });
-        } catch (error) {
-          const errorMsg = error instanceof Error ? error.message : String(error);
-          logger?.error?.(`[Rust Debugger] Failed to determine binary path: ${errorMsg}`);
-          resolve({ success: false, error: errorMsg });
+        for (const unit of staleUnits) {
+          logger?.info?.(`[Rust Debugger] Rebuilt ${unit.package} (${unit.kind.join(',')} "${unit.target}"): ${unit.reason}`);
         }
+        resolve({ success: true, artifacts, fresh: staleUnits.length === 0, staleUnits });
       } else {
-        logger?.error?.(`[Rust Debugger] Build failed with code ${code}:\n${stderr}`);
-        resolve({ success: false, error: stderr || stdout });
+        const metadata = await loadCargoMetadata(projectRoot);
+        const diagnostics = parseCargoDiagnostics(stdout, metadata?.workspaceRoot ?? projectRoot);
+        const output = [renderCargoDiagnostics(stdout), cargoOutput].filter(Boolean).join('\n\n');
+        logger?.error?.(`[Rust Debugger] Build failed with code ${code}:\n${output}`);
+        resolve({ success: false, artifacts, staleUnits, diagnostics, error: output || stdout });
       }
     });
   });
@@ -0,0 +1,106 @@
+/**
+ * Resolvers for the LLDB scripts loaded into Rust sessions: the command
+ * scripts shipped with the adapter and rustc's std pretty-printers
+ */
+
+import * as fs from 'fs/promises';
+import * as path from 'path';
+import { fileURLToPath } from 'url';
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = path.dirname(__filename);
+
+/**
+ * Scripts loaded into every Rust session. Each registers its LLDB commands
+ * from `__lldb_init_module`.
+ */
+export const BUNDLED_LLDB_SCRIPTS = ['async_futures.py', 'tokio_tasks.py'];
+
+/**
+ * Files of rustc's LLDB pretty-printers: the module registering the providers,
+ * and the `type synthetic/summary add` commands that bind them to std types
+ */
+const PRETTY_PRINTER_MODULE = 'lldb_lookup.py';
+const PRETTY_PRINTER_COMMANDS = 'lldb_commands';
+
+export interface RustPrettyPrinters {
+  /** Whether the scripts come from the active toolchain or the copy vendored under lldb/rust */
+  source: 'toolchain' | 'vendored';
+  directory: string;
+  /** LLDB commands that load them, as rust-lldb does */
+  commands: string[];
+}
+
+/**
+ * Resolve the path of a script under the package's lldb/ directory
+ */
+export async function resolveLldbScript(scriptName: string): Promise<string | null> {
+  const candidatePaths = [
+    // Package root (production install from dist/utils, or src/utils in the source tree)
+    path.resolve(__dirname, '..', '..', 'lldb', scriptName),
+    // Bundled CLI (dist/lldb next to the bundle)
+    path.resolve(__dirname, 'lldb', scriptName),
+    // Monorepo source tree fallback
+    path.resolve(process.cwd(), 'packages', 'adapter-rust', 'lldb', scriptName)
+  ];
+
+  for (const candidate of candidatePaths) {
+    try {
+      await fs.access(candidate, fs.constants.F_OK);
+      return candidate;
+    } catch {
+      // Try next candidate
+    }
+  }
+
+  return null;
+}
+
+/**
+ * `command script import` commands for the bundled scripts that can be found
+ */
+export async function getBundledScriptImports(): Promise<string[]> {
+  const commands: string[] = [];
+  for (const scriptName of BUNDLED_LLDB_SCRIPTS) {
+    const scriptPath = await resolveLldbScript(scriptName);
+    if (scriptPath) {
+      // LLDB accepts forward slashes on Windows and they need no escaping
+      commands.push(`command script import "${scriptPath.replace(/\\/g, '/')}"`);
+    }
+  }
+  return commands;
+}
+
+/**
+ * Locate rustc's LLDB pretty-printers in the toolchain sysroot
+ * (`lib/rustlib/etc`), falling back to the vendored copy
+ */
+export async function resolveRustPrettyPrinters(sysroot: string | null): Promise<RustPrettyPrinters | null> {
+  const candidates: Array<{ source: RustPrettyPrinters['source']; directory: string | null }> = [
+    { source: 'toolchain', directory: sysroot ? path.join(sysroot, 'lib', 'rustlib', 'etc') : null },
+    { source: 'vendored', directory: await resolveLldbScript('rust') }
+  ];
+
+  for (const { source, directory } of candidates) {
+    if (!directory) {
+      continue;
+    }
+    try {
+      await fs.access(path.join(directory, PRETTY_PRINTER_MODULE), fs.constants.F_OK);
+      await fs.access(path.join(directory, PRETTY_PRINTER_COMMANDS), fs.constants.F_OK);
+    } catch {
+      continue;
+    }
+    const scriptsDir = directory.replace(/\\/g, '/');
+    return {
+      source,
+      directory,
+      commands: [
+        `command script import "${scriptsDir}/${PRETTY_PRINTER_MODULE}"`,
+        `command source -s 0 "${scriptsDir}/${PRETTY_PRINTER_COMMANDS}"`
+      ]
+    };
+  }
+
+  return null;
+}
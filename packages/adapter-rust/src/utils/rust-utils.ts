@@ -7,6 +7,7 @@ import * as path from 'path';
 import * as fs from 'fs/promises';
 import os from 'os';
 import which from 'which';
+import { getCargoTargetDirectory } from './cargo-utils.js';
 
 /**
  * Check if Cargo is installed and available
@@ -153,8 +154,7 @@ export async function getRustBinaryPath(
   release: boolean = false
 ): Promise<string | null> {
   const targetDir = path.join(
-    projectPath,
-    'target',
+    await getCargoTargetDirectory(projectPath),
     release ? 'release' : 'debug'
   );
   
@@ -203,6 +203,32 @@ export async function getRustHostTriple(): Promise<string | null> {
   });
 }
 
+/**
+ * Sysroot of the active toolchain. Run from the project directory so
+ * rust-toolchain.toml and rustup overrides select the same toolchain cargo uses.
+ */
+export async function getRustSysroot(cwd?: string): Promise<string | null> {
+  return new Promise((resolve) => {
+    const rustcProcess = spawn('rustc', ['--print', 'sysroot'], {
+      cwd,
+      shell: true
+    });
+
+    let output = '';
+
+    rustcProcess.stdout?.on('data', (data) => {
+      output += data.toString();
+    });
+
+    rustcProcess.on('error', () => resolve(null));
+    // 'close' waits for stdout to drain; 'exit' can fire before the last chunk
+    rustcProcess.on('close', (code) => {
+      const sysroot = output.trim();
+      resolve(code === 0 && sysroot ? sysroot : null);
+    });
+  });
+}
+
 /**
  * Attempt to locate dlltool.exe in PATH, DLLTOOL env override, or rustup toolchains.
  */
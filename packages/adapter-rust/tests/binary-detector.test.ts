@@ -82,6 +82,32 @@ describe('detectBinaryFormat', () => {
     expect(info.format).toBe('gnu');
   });
 
+  it('reads the architecture from ELF, PE and Mach-O headers', async () => {
+    const dir = await createTempDir();
+    tempDirs.push(dir);
+
+    const elf = Buffer.alloc(64);
+    elf.write('\x7fELF', 0, 'latin1');
+    elf[5] = 1;
+    elf.writeUInt16LE(0xb7, 18);
+
+    const pe = Buffer.alloc(0x100);
+    pe.write('MZ', 0, 'ascii');
+    pe.writeUInt32LE(0x80, 0x3c);
+    pe.write('PE\0\0', 0x80, 'latin1');
+    pe.writeUInt16LE(0x8664, 0x84);
+
+    const macho = Buffer.alloc(32);
+    macho.writeUInt32LE(0xfeedfacf, 0);
+    macho.writeUInt32LE(0x0100000c, 4);
+
+    expect((await detectBinaryFormat(await writeBinary(dir, 'app', elf))).architecture).toBe('aarch64');
+    expect((await detectBinaryFormat(await writeBinary(dir, 'app.exe', pe))).architecture).toBe('x86_64');
+    expect((await detectBinaryFormat(await writeBinary(dir, 'app-macos', macho))).architecture).toBe('aarch64');
+    expect((await detectBinaryFormat(await writeBinary(dir, 'short.exe', Buffer.from('MZUNKNOWN', 'ascii')))).architecture)
+      .toBeUndefined();
+  });
+
   it('handles unknown binaries gracefully', async () => {
     const dir = await createTempDir();
     tempDirs.push(dir);
@@ -0,0 +1,133 @@
+import { describe, it, expect } from 'vitest';
+import * as path from 'path';
+import {
+  parseCargoDiagnostics,
+  renderCargoDiagnostics,
+  summarizeDiagnostics
+} from '../src/utils/cargo-diagnostics.js';
+
+const workspaceRoot = path.join(path.sep, 'ws');
+const mainRs = path.join(workspaceRoot, 'app', 'src', 'main.rs');
+
+const span = (line: number, column: number, columnEnd: number, extra: Record<string, unknown> = {}) => ({
+  file_name: 'app/src/main.rs',
+  line_start: line,
+  line_end: line,
+  column_start: column,
+  column_end: columnEnd,
+  is_primary: true,
+  label: null,
+  suggested_replacement: null,
+  suggestion_applicability: null,
+  ...extra
+});
+
+const compilerMessage = (message: Record<string, unknown>): string =>
+  JSON.stringify({
+    reason: 'compiler-message',
+    package_id: 'path+file:///ws/app#0.1.0',
+    manifest_path: '/ws/app/Cargo.toml',
+    target: { name: 'app', kind: ['bin'], src_path: '/ws/app/src/main.rs' },
+    message: { children: [], rendered: null, ...message }
+  });
+
+// Shapes captured from cargo 1.95 (`cargo build --message-format=json` in a workspace member)
+const output = [
+  compilerMessage({
+    level: 'warning',
+    code: { code: 'dead_code' },
+    message: 'function `unused_fn` is never used',
+    spans: [span(6, 4, 13)],
+    rendered: 'warning: function `unused_fn` is never used\n'
+  }),
+  compilerMessage({
+    level: 'error',
+    code: { code: 'E0425' },
+    message: 'cannot find type `HashMap` in this scope',
+    spans: [span(2, 12, 19, { label: 'not found in this scope' })],
+    children: [
+      {
+        level: 'help',
+        message: 'consider importing this struct',
+        spans: [span(1, 1, 1, { suggested_replacement: 'use std::collections::HashMap;\n\n', suggestion_applicability: 'MaybeIncorrect' })],
+        children: []
+      }
+    ],
+    rendered: 'error[E0425]: cannot find type `HashMap` in this scope\n'
+  }),
+  compilerMessage({
+    level: 'error',
+    code: { code: 'E0308' },
+    message: 'mismatched types',
+    spans: [
+      span(3, 12, 15, { is_primary: false, label: 'expected due to this' }),
+      span(3, 18, 24, { label: 'expected `u32`, found `&str`' })
+    ],
+    rendered: 'error[E0308]: mismatched types\n'
+  }),
+  compilerMessage({
+    level: 'failure-note',
+    code: null,
+    message: 'For more information about an error, try `rustc --explain E0308`.',
+    spans: []
+  }),
+  JSON.stringify({ reason: 'build-finished', success: false })
+].join('\n');
+
+describe('parseCargoDiagnostics', () => {
+  it('reports errors first with primary spans and suggestions', () => {
+    const diagnostics = parseCargoDiagnostics(output, workspaceRoot);
+
+    expect(diagnostics.map(d => d.code)).toEqual(['E0425', 'E0308', 'dead_code']);
+    expect(diagnostics[1]).toEqual({
+      level: 'error',
+      code: 'E0308',
+      message: 'mismatched types',
+      file: mainRs,
+      line: 3,
+      column: 18,
+      endLine: 3,
+      endColumn: 24,
+      label: 'expected `u32`, found `&str`',
+      suggestions: []
+    });
+    expect(diagnostics[0].suggestions).toEqual([
+      {
+        message: 'consider importing this struct',
+        file: mainRs,
+        line: 1,
+        column: 1,
+        endLine: 1,
+        endColumn: 1,
+        replacement: 'use std::collections::HashMap;\n\n',
+        applicability: 'MaybeIncorrect'
+      }
+    ]);
+  });
+
+  it('ignores non-JSON lines and other message kinds', () => {
+    expect(parseCargoDiagnostics('   Compiling app\nnot json\n{"reason":"compiler-artifact"}', workspaceRoot)).toEqual([]);
+  });
+});
+
+describe('renderCargoDiagnostics', () => {
+  it('joins rustc renderings of errors and warnings', () => {
+    expect(renderCargoDiagnostics(output)).toBe([
+      'warning: function `unused_fn` is never used',
+      'error[E0425]: cannot find type `HashMap` in this scope',
+      'error[E0308]: mismatched types'
+    ].join('\n\n'));
+  });
+});
+
+describe('summarizeDiagnostics', () => {
+  it('names the first error and its location', () => {
+    expect(summarizeDiagnostics(parseCargoDiagnostics(output, workspaceRoot))).toBe(
+      `2 errors, first: E0425 cannot find type \`HashMap\` in this scope at ${mainRs}:2:12`
+    );
+  });
+
+  it('returns undefined without errors', () => {
+    expect(summarizeDiagnostics([])).toBeUndefined();
+  });
+});
@@ -0,0 +1,134 @@
+import { describe, it, expect } from 'vitest';
+import {
+  describeDirtyReason,
+  getStaleUnits,
+  parseFingerprintLog,
+  stripFingerprintLog
+} from '../src/utils/cargo-fingerprint.js';
+import type { CargoArtifact } from '../src/utils/cargo-utils.js';
+
+const prefix = (pkg: string, target: string): string =>
+  `   0.031259132s  INFO prepare_target{force=false package_id=${pkg} v0.1.0 (/ws/${pkg}) target="${target}"}: ` +
+  'cargo::core::compiler::fingerprint: ';
+
+// Captured from cargo 1.95 after editing a path dependency and build.rs
+const log = [
+  prefix('app', 'app') + 'stale: changed "/ws/dep/src/lib.rs"',
+  prefix('app', 'app') + 'fingerprint dirty for app v0.1.0 (/ws/app)/Build/TargetInner { name: "app", doc: true }',
+  prefix('app', 'app') + '    dirty: FsStatusOutdated(StaleDepFingerprint { unit: UnitIndex(1) })',
+  prefix('app', 'build-script-build') + 'fingerprint dirty for app v0.1.0 (/ws/app)/RunCustomBuild/TargetInner { .. }',
+  prefix('app', 'build-script-build') + '    dirty: FsStatusOutdated(StaleDepFingerprint { unit: UnitIndex(1) })',
+  prefix('app', 'build-script-build') + '    dirty: FsStatusOutdated(StaleItem(ChangedFile { reference: ' +
+    '"/ws/target/debug/.fingerprint/app-78/dep-build-script-build-script-build", reference_mtime: FileTime { seconds: 1, nanos: 0 }, ' +
+    'stale: "/ws/app/build.rs", stale_mtime: FileTime { seconds: 2, nanos: 0 } }))',
+  prefix('dep', 'dep') + 'fingerprint dirty for dep v0.1.0 (/ws/dep)/Build/TargetInner { name_inferred: true }',
+  prefix('dep', 'dep') + '    dirty: TargetConfigurationChanged',
+  prefix('tool', 'tool') + 'fingerprint error for tool v0.1.0 (/ws/tool)/Build/TargetInner { name: "tool" }',
+  prefix('tool', 'tool') + '    err: failed to read `/ws/target/debug/.fingerprint/tool-96/bin-tool`',
+  'Caused by:',
+  '    No such file or directory (os error 2)',
+  '   Compiling dep v0.1.0 (/ws/dep)',
+  'warning: unused variable: `x`',
+  '    Finished `dev` profile [unoptimized + debuginfo] target(s) in 0.13s'
+].join('\n');
+
+describe('parseFingerprintLog', () => {
+  it('keeps the most specific reason per unit', () => {
+    const reasons = parseFingerprintLog(log);
+    expect(reasons.get('app:app')).toBe('a dependency was rebuilt');
+    expect(reasons.get('app:build-script-build')).toBe('changed: /ws/app/build.rs');
+    expect(reasons.get('dep:dep')).toBe('target configuration changed');
+    expect(reasons.get('tool:tool')).toBe('not built with the current features, profile or target yet');
+  });
+
+  it('reads entries whatever the timestamp, span field order and package id format', () => {
+    const variants = [
+      '2026-01-05T10:00:00.123Z  INFO prepare_target{target="dep" package_id=dep v0.1.0 (/ws/dep) force=false}: ' +
+        'cargo::core::compiler::fingerprint:     dirty: TargetConfigurationChanged',
+      'INFO compile{unit=3}:prepare_target{package_id=path+file:///ws/dep#0.1.0 target=dep}: ' +
+        'cargo::core::compiler::fingerprint: dirty: TargetConfigurationChanged'
+    ];
+    for (const line of variants) {
+      expect(parseFingerprintLog(line).get('dep:dep')).toBe('target configuration changed');
+    }
+  });
+
+  it('skips lines it cannot attribute to a unit', () => {
+    const reasons = parseFingerprintLog([
+      '[2026-01-05T10:00:00Z INFO  cargo::core::compiler::fingerprint] dirty: RustcChanged',
+      '   0.1s  INFO prepare_target{force=false}: cargo::core::compiler::fingerprint: dirty: RustcChanged',
+      '   0.1s  INFO prepare_target{package_id=dep v0.1.0 target="dep"}: cargo::core::compiler::fingerprint: fresh'
+    ].join('\n'));
+    expect(reasons.size).toBe(0);
+  });
+});
+
+describe('describeDirtyReason', () => {
+  it('explains common dirty reasons', () => {
+    expect(describeDirtyReason('FsStatusOutdated(StaleItem(MissingFile("/ws/app/src/gen.rs")))')).toBe('missing: /ws/app/src/gen.rs');
+    expect(describeDirtyReason('EnvVarChanged { name: "DATABASE_URL", old_value: None, new_value: Some("x") }'))
+      .toBe('environment variable DATABASE_URL changed');
+    expect(describeDirtyReason('FeaturesChanged { old: "[]", new: "[\\"extra\\"]" }')).toBe('features changed');
+    expect(describeDirtyReason('RustcChanged')).toBe('rustc changed');
+  });
+
+  it('keeps reasons it does not recognize as cargo wrote them', () => {
+    expect(describeDirtyReason('Forced')).toBe('forced');
+    expect(describeDirtyReason('the file `src/lib.rs` has changed')).toBe('the file `src/lib.rs` has changed');
+    expect(describeDirtyReason('LocalLengthsChanged { .. }')).toBe('local lengths changed');
+  });
+});
+
+describe('getStaleUnits', () => {
+  it('lists artifacts cargo did not find fresh', () => {
+    const artifact = (packageId: string, name: string, fresh: boolean): CargoArtifact => ({
+      packageId,
+      target: { name, kind: ['lib'], src_path: `/ws/${name}/src/lib.rs` },
+      test: false,
+      filenames: [],
+      fresh
+    });
+
+    const units = getStaleUnits([
+      artifact('path+file:///ws/dep#0.1.0', 'dep', false),
+      artifact('registry+https://github.com/rust-lang/crates.io-index#serde@1.0.219', 'serde', true),
+      artifact('path+file:///ws/crates/core#my-core@0.1.0', 'my_core', false)
+    ], log);
+
+    expect(units).toEqual([
+      { package: 'dep', target: 'dep', kind: ['lib'], reason: 'target configuration changed' },
+      { package: 'my-core', target: 'my_core', kind: ['lib'], reason: 'rebuilt by cargo' }
+    ]);
+  });
+
+  it('takes staleness from the fresh flag alone when the log is missing or unreadable', () => {
+    const dep: CargoArtifact = {
+      packageId: 'path+file:///ws/dep#0.1.0',
+      target: { name: 'dep', kind: ['lib'], src_path: '/ws/dep/src/lib.rs' },
+      test: false,
+      filenames: [],
+      fresh: false
+    };
+
+    expect(getStaleUnits([dep], '')).toEqual([{ package: 'dep', target: 'dep', kind: ['lib'], reason: 'rebuilt by cargo' }]);
+    expect(getStaleUnits([{ ...dep, fresh: true }], log)).toEqual([]);
+  });
+});
+
+describe('stripFingerprintLog', () => {
+  it('removes fingerprint entries in other log formats', () => {
+    expect(stripFingerprintLog([
+      '2026-01-05T10:00:00.123Z  INFO prepare_target{package_id=dep}: cargo::core::compiler::fingerprint: dirty: RustcChanged',
+      '[2026-01-05T10:00:00Z INFO  cargo::core::compiler::fingerprint] fingerprint dirty for dep',
+      'error[E0308]: mismatched types'
+    ].join('\n'))).toBe('error[E0308]: mismatched types');
+  });
+
+  it('leaves status lines and diagnostics', () => {
+    expect(stripFingerprintLog(log)).toBe([
+      '   Compiling dep v0.1.0 (/ws/dep)',
+      'warning: unused variable: `x`',
+      '    Finished `dev` profile [unoptimized + debuginfo] target(s) in 0.13s'
+    ].join('\n'));
+  });
+});
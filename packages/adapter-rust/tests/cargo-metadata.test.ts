@@ -0,0 +1,126 @@
+import { describe, it, expect } from 'vitest';
+import * as path from 'path';
+import {
+  parseCargoMetadata,
+  parseCargoProfiles,
+  getProfileDirName
+} from '../src/utils/cargo-metadata.js';
+
+const workspaceRoot = path.join(path.sep, 'work', 'ws');
+
+const workspaceMetadata = {
+  packages: [
+    {
+      id: 'app 0.2.0 (path+file:///work/ws/app)',
+      name: 'app',
+      version: '0.2.0',
+      edition: '2021',
+      manifest_path: path.join(workspaceRoot, 'app', 'Cargo.toml'),
+      default_run: 'app',
+      features: { default: ['fast'], fast: [] },
+      targets: [
+        {
+          name: 'app',
+          kind: ['bin'],
+          crate_types: ['bin'],
+          src_path: path.join(workspaceRoot, 'app', 'src', 'main.rs')
+        },
+        {
+          name: 'tool',
+          kind: ['bin'],
+          crate_types: ['bin'],
+          'required-features': ['fast'],
+          src_path: path.join(workspaceRoot, 'app', 'src', 'bin', 'tool.rs')
+        },
+        {
+          name: 'demo',
+          kind: ['example'],
+          crate_types: ['bin'],
+          src_path: path.join(workspaceRoot, 'app', 'examples', 'demo.rs')
+        }
+      ]
+    },
+    {
+      id: 'core 0.2.0 (path+file:///work/ws/core)',
+      name: 'core',
+      version: '0.2.0',
+      manifest_path: path.join(workspaceRoot, 'core', 'Cargo.toml'),
+      targets: [
+        { name: 'core', kind: ['lib'], crate_types: ['lib'], src_path: path.join(workspaceRoot, 'core', 'src', 'lib.rs') }
+      ]
+    }
+  ],
+  workspace_members: [
+    'app 0.2.0 (path+file:///work/ws/app)',
+    'core 0.2.0 (path+file:///work/ws/core)'
+  ],
+  workspace_root: workspaceRoot,
+  target_directory: path.join(workspaceRoot, 'target')
+};
+
+describe('parseCargoMetadata', () => {
+  it('models workspace members, targets and features', () => {
+    const metadata = parseCargoMetadata(workspaceMetadata, path.join(workspaceRoot, 'app'));
+
+    expect(metadata).not.toBeNull();
+    expect(metadata?.workspaceRoot).toBe(workspaceRoot);
+    expect(metadata?.targetDirectory).toBe(path.join(workspaceRoot, 'target'));
+    expect(metadata?.packages.map(p => p.name)).toEqual(['app', 'core']);
+    // Virtual workspace: no package at the root
+    expect(metadata?.rootPackage).toBeUndefined();
+
+    const app = metadata!.packages[0];
+    expect(app.rootDir).toBe(path.join(workspaceRoot, 'app'));
+    expect(app.defaultRun).toBe('app');
+    expect(app.features).toEqual({ default: ['fast'], fast: [] });
+    expect(app.targets.find(t => t.name === 'tool')?.required_features).toEqual(['fast']);
+    expect(app.targets.find(t => t.name === 'demo')?.kind).toEqual(['example']);
+  });
+
+  it('defaults the target directory to <workspace root>/target', () => {
+    const metadata = parseCargoMetadata({ packages: [] }, '/work/single');
+    expect(metadata?.targetDirectory).toBe(path.join(path.resolve('/work/single'), 'target'));
+  });
+
+  it('rejects output without a packages list', () => {
+    expect(parseCargoMetadata({ version: 1 }, '/work')).toBeNull();
+    expect(parseCargoMetadata(null, '/work')).toBeNull();
+  });
+});
+
+describe('parseCargoProfiles', () => {
+  it('reads profile tables and skips nested overrides', () => {
+    const profiles = parseCargoProfiles([
+      '[workspace]',
+      'members = ["app", "core"]',
+      '',
+      '[profile.release]',
+      'debug = true # keep symbols',
+      'opt-level = 3',
+      '',
+      '[profile.release.package.core]',
+      'opt-level = 0',
+      '',
+      '[profile.profiling]',
+      'inherits = "release"',
+      'lto = "thin"',
+      'strip = false'
+    ].join('\n'));
+
+    expect(Object.keys(profiles)).toEqual(['release', 'profiling']);
+    expect(profiles.release.debug).toBe(true);
+    expect(profiles.release.optLevel).toBe(3);
+    expect(profiles.profiling.inherits).toBe('release');
+    expect(profiles.profiling.settings).toEqual({ inherits: 'release', lto: 'thin', strip: false });
+  });
+});
+
+describe('getProfileDirName', () => {
+  it('maps profiles to output directories', () => {
+    expect(getProfileDirName('dev')).toBe('debug');
+    expect(getProfileDirName('test')).toBe('debug');
+    expect(getProfileDirName('release')).toBe('release');
+    expect(getProfileDirName('bench')).toBe('release');
+    expect(getProfileDirName('profiling')).toBe('profiling');
+  });
+});
@@ -0,0 +1,83 @@
+import { describe, it, expect, vi } from 'vitest';
+import { CargoProgressTracker, stripProgressBar } from '../src/utils/cargo-progress.js';
+
+const artifact = (packageId: string, name: string, kind: string[], fresh = false): string =>
+  JSON.stringify({
+    reason: 'compiler-artifact',
+    package_id: packageId,
+    target: { name, kind, src_path: `/src/${name}.rs` },
+    filenames: [],
+    fresh
+  }) + '\n';
+
+const LIBC = 'registry+https://github.com/rust-lang/crates.io-index#libc@0.2.190';
+const APP = 'path+file:///ws/app#0.1.0';
+
+// Redraws as cargo 1.95 writes them with CARGO_TERM_PROGRESS_WHEN=always
+const bar = (done: number, total: number, units: string): string =>
+  `    Building [${'='.repeat(done)}>${' '.repeat(total - done)}] ${done}/${total}: ${units}   \r`;
+
+describe('CargoProgressTracker', () => {
+  it('reports each finished unit with the total from the progress bar', () => {
+    const onProgress = vi.fn();
+    const tracker = new CargoProgressTracker(onProgress);
+
+    tracker.start('cargo build --message-format=json');
+    tracker.handleStdout(artifact(LIBC, 'build_script_build', ['custom-build'], true));
+    tracker.handleStderr(`   Compiling libc v0.2.190\n${bar(1, 3, 'libc(build)')}`);
+    tracker.handleStdout('{"reason":"build-script-executed","package_id":"' + LIBC + '"}\n');
+    tracker.handleStdout(artifact(APP, 'app', ['bin']));
+
+    expect(onProgress.mock.calls.map(([progress]) => progress)).toEqual([
+      { progress: 0, message: 'cargo build --message-format=json' },
+      { progress: 1, total: undefined, message: 'Fresh libc (custom-build "build_script_build")' },
+      { progress: 2, total: 3, message: 'Ran build script of libc' },
+      { progress: 3, total: 3, message: 'Compiled app (bin "app")' }
+    ]);
+  });
+
+  it('buffers messages and redraws split across chunks', () => {
+    const onProgress = vi.fn();
+    const tracker = new CargoProgressTracker(onProgress);
+    const line = artifact(APP, 'app', ['bin']);
+    const redraw = bar(4, 28, 'tokio, app(bin)');
+
+    tracker.handleStderr(redraw.slice(0, 30));
+    tracker.handleStderr(redraw.slice(30));
+    tracker.handleStdout(line.slice(0, 20));
+    expect(onProgress).not.toHaveBeenCalled();
+    tracker.handleStdout(line.slice(20) + '{"reason":"build-finished","success":true}\n');
+
+    expect(onProgress).toHaveBeenCalledTimes(1);
+    expect(onProgress).toHaveBeenCalledWith({ progress: 1, total: 28, message: 'Compiled app (bin "app")' });
+  });
+
+  it('never reports a total below the finished count', () => {
+    const onProgress = vi.fn();
+    const tracker = new CargoProgressTracker(onProgress);
+
+    tracker.handleStderr(bar(0, 1, 'app(bin)'));
+    tracker.handleStdout(artifact(APP, 'app', ['bin']) + artifact(APP, 'app', ['bin']));
+
+    expect(onProgress).toHaveBeenLastCalledWith({ progress: 2, total: 2, message: 'Compiled app (bin "app")' });
+  });
+});
+
+describe('stripProgressBar', () => {
+  it('removes redraws and keeps cargo messages', () => {
+    const stderr = [
+      '   Compiling app v0.1.0 (/ws/app)\n',
+      bar(1, 2, 'app(bin)'),
+      'error[E0425]: cannot find value `x` in this scope\n',
+      bar(1, 2, 'app(bin)'),
+      'error: could not compile `app` (bin "app") due to 1 previous error\n'
+    ].join('');
+
+    expect(stripProgressBar(stderr)).toBe([
+      '   Compiling app v0.1.0 (/ws/app)',
+      'error[E0425]: cannot find value `x` in this scope',
+      'error: could not compile `app` (bin "app") due to 1 previous error',
+      ''
+    ].join('\n'));
+  });
+});
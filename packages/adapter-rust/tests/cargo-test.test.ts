@@ -0,0 +1,192 @@
+import { describe, it, expect, beforeEach, afterEach, vi, type Mock } from 'vitest';
+import * as fs from 'fs/promises';
+import * as fssync from 'fs';
+import * as path from 'path';
+import * as os from 'os';
+import { EventEmitter } from 'events';
+import { BuildFailedError } from '@debugmcp/shared';
+import {
+  findTestBodyLine,
+  parseTestList,
+  resolveRustTest
+} from '../src/utils/cargo-test.js';
+
+const spawnMock: Mock = vi.fn();
+
+vi.mock('child_process', async () => {
+  const actual = await vi.importActual<typeof import('child_process')>('child_process');
+  return {
+    ...actual,
+    spawn: (...args: Parameters<typeof actual.spawn>) => spawnMock(...args)
+  };
+});
+
+const createMockProcess = (stdout: string, exitCode = 0): EventEmitter & { stdout: EventEmitter; stderr: EventEmitter } => {
+  const proc = new EventEmitter() as EventEmitter & { stdout: EventEmitter; stderr: EventEmitter };
+  proc.stdout = new EventEmitter();
+  proc.stderr = new EventEmitter();
+  queueMicrotask(() => {
+    proc.stdout.emit('data', stdout);
+    proc.emit('exit', exitCode);
+    proc.emit('close', exitCode);
+  });
+  return proc;
+};
+
+const artifact = (name: string, kind: string, srcPath: string, executable: string): string =>
+  JSON.stringify({
+    reason: 'compiler-artifact',
+    package_id: 'calc 0.1.0',
+    target: { name, kind: [kind], src_path: srcPath },
+    profile: { test: true },
+    filenames: [executable],
+    executable,
+    fresh: false
+  });
+
+const LIB_SOURCE = [
+  'pub fn add(a: i32, b: i32) -> i32 { a + b }',
+  '',
+  '#[cfg(test)]',
+  'mod tests {',
+  '    use super::*;',
+  '',
+  '    /// Adds small numbers',
+  '    #[test]',
+  '    #[should_panic]',
+  '    fn it_adds() {',
+  '        // arrange',
+  '        let r = add(2, 2);',
+  '        assert_eq!(r, 5);',
+  '    }',
+  '}'
+].join('\n');
+
+describe('parseTestList', () => {
+  it('keeps tests and skips benches and summary lines', () => {
+    const output = 'tests::it_adds: test\nparse::bench_big: bench\nit_works: test\n\n';
+    expect(parseTestList(output)).toEqual(['tests::it_adds', 'it_works']);
+  });
+});
+
+describe('findTestBodyLine', () => {
+  it('returns the first statement of a test function', () => {
+    expect(findTestBodyLine(LIB_SOURCE, 'it_adds')).toBe(12);
+  });
+
+  it('recognizes runtime test attributes and ignores plain functions', () => {
+    const source = '#[tokio::test]\nasync fn fetches() {\n    let v = get().await;\n}\n\nfn helper() {\n    work();\n}\n';
+    expect(findTestBodyLine(source, 'fetches')).toBe(3);
+    expect(findTestBodyLine(source, 'helper')).toBeUndefined();
+  });
+});
+
+describe('resolveRustTest', () => {
+  let project: string;
+
+  beforeEach(async () => {
+    spawnMock.mockReset();
+    project = await fs.mkdtemp(path.join(os.tmpdir(), 'cargo-test-'));
+    await fs.mkdir(path.join(project, 'src'), { recursive: true });
+    await fs.mkdir(path.join(project, 'tests'), { recursive: true });
+    await fs.writeFile(path.join(project, 'Cargo.toml'), '[package]\nname = "calc"\n');
+    await fs.writeFile(path.join(project, 'src', 'lib.rs'), LIB_SOURCE);
+    await fs.writeFile(path.join(project, 'tests', 'api.rs'), '#[test]\nfn it_adds() {\n    assert_eq!(calc::add(1, 1), 2);\n}\n');
+  });
+
+  afterEach(() => {
+    fssync.rmSync(project, { recursive: true, force: true });
+  });
+
+  const mockHarnesses = (): void => {
+    spawnMock.mockImplementation((command: string) => {
+      if (command === 'cargo') {
+        return createMockProcess([
+          artifact('calc', 'lib', path.join(project, 'src', 'lib.rs'), '/t/deps/calc-1111111111111111'),
+          artifact('api', 'test', path.join(project, 'tests', 'api.rs'), '/t/deps/api-2222222222222222')
+        ].join('\n') + '\n');
+      }
+      return createMockProcess(command.includes('calc-') ? 'tests::it_adds: test\n' : 'it_adds: test\n');
+    });
+  };
+
+  it('finds the harness, launch arguments and body line of a qualified test', async () => {
+    mockHarnesses();
+
+    const launch = await resolveRustTest(project, 'tests::it_adds');
+
+    expect(spawnMock.mock.calls[0][1]).toEqual(['test', '--no-run', '--message-format=json']);
+    expect(launch.executable).toBe('/t/deps/calc-1111111111111111');
+    expect(launch.args).toEqual(['--exact', 'tests::it_adds', '--nocapture', '--test-threads=1']);
+    expect(launch.cwd).toBe(path.resolve(project));
+    expect(launch.location).toEqual({ file: path.join(project, 'src', 'lib.rs'), line: 12 });
+  });
+
+  it('prefers an exact name over suffix matches in other harnesses', async () => {
+    mockHarnesses();
+
+    const launch = await resolveRustTest(project, 'it_adds');
+    expect(launch.executable).toBe('/t/deps/api-2222222222222222');
+    expect(launch.location).toEqual({ file: path.join(project, 'tests', 'api.rs'), line: 3 });
+  });
+
+  it('reports missing tests and build failures', async () => {
+    mockHarnesses();
+    await expect(resolveRustTest(project, 'does_not_exist')).rejects.toThrow('No test named "does_not_exist"');
+
+    spawnMock.mockImplementation(() => createMockProcess('', 101));
+    await expect(resolveRustTest(project, 'it_adds')).rejects.toThrow('Cargo test build failed');
+  });
+
+  it('builds with the launch options and reports compiler diagnostics', async () => {
+    const compilerMessage = JSON.stringify({
+      reason: 'compiler-message',
+      package_id: 'calc 0.1.0',
+      message: {
+        level: 'error',
+        code: { code: 'E0308' },
+        message: 'mismatched types',
+        spans: [{
+          file_name: 'src/lib.rs',
+          line_start: 13,
+          line_end: 13,
+          column_start: 24,
+          column_end: 25,
+          is_primary: true,
+          label: 'expected `i32`, found `&str`',
+          suggested_replacement: null
+        }],
+        children: [],
+        rendered: 'error[E0308]: mismatched types\n --> src/lib.rs:13:24\n'
+      }
+    });
+    spawnMock.mockImplementation((_command: string, args: string[]) =>
+      createMockProcess(args[0] === 'test' ? compilerMessage + '\n' : '', args[0] === 'test' ? 101 : 1)
+    );
+
+    const error = await resolveRustTest(project, 'it_adds', {
+      build: { release: true, features: ['fast'] },
+      target: { kind: 'test', name: 'api' }
+    }).catch((e: unknown) => e);
+
+    expect(spawnMock.mock.calls[0][1]).toEqual([
+      'test', '--no-run', '--message-format=json', '--release', '--features', 'fast', '--test', 'api'
+    ]);
+    expect(error).toBeInstanceOf(BuildFailedError);
+    expect((error as BuildFailedError).message).toBe(
+      `Cargo test build failed: 1 error: E0308 mismatched types at ${path.join(project, 'src', 'lib.rs')}:13:24`
+    );
+    expect((error as BuildFailedError).diagnostics).toEqual([
+      expect.objectContaining({ code: 'E0308', line: 13, column: 24 })
+    ]);
+  });
+
+  it('stops the test build when cancelled', async () => {
+    const controller = new AbortController();
+    controller.abort();
+
+    await expect(resolveRustTest(project, 'it_adds', { hooks: { signal: controller.signal } }))
+      .rejects.toThrow('Cargo test build cancelled');
+    expect(spawnMock).not.toHaveBeenCalled();
+  });
+});
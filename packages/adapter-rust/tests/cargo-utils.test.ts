@@ -40,6 +40,7 @@ const createMockProcess = (options: {
     stdoutChunks.forEach((chunk) => stdout.emit('data', chunk));
     stderrChunks.forEach((chunk) => stderr.emit('data', chunk));
     proc.emit('exit', exitCode);
+    proc.emit('close', exitCode);
   });
 
   return proc;
@@ -79,6 +80,8 @@ describe('resolveCargoProject', () => {
     const metadata = {
       packages: [
         {
+          name: 'metadata',
+          version: '0.1.0',
           manifest_path: path.join(projectPath, 'Cargo.toml'),
           targets: [
             {
@@ -127,6 +130,36 @@ describe('resolveCargoProject', () => {
     const result = await cargoUtils.resolveCargoProject(projectPath);
     expect(result).toBeNull();
   });
+
+  it('uses the version cargo resolves for workspace-inherited fields', async () => {
+    const projectPath = await createTempProject('inherited');
+    await fs.writeFile(
+      path.join(projectPath, 'Cargo.toml'),
+      '[package]\nname = "inherited"\nversion.workspace = true\n\n[dependencies]\nserde = { version = "1.0" }\n'
+    );
+    const metadata = {
+      packages: [
+        {
+          id: 'inherited 2.3.0',
+          name: 'inherited',
+          version: '2.3.0',
+          manifest_path: path.join(projectPath, 'Cargo.toml'),
+          targets: [{ name: 'inherited', kind: ['bin'], src_path: path.join(projectPath, 'src/main.rs') }]
+        }
+      ],
+      workspace_members: ['inherited 2.3.0'],
+      workspace_root: projectPath
+    };
+    spawnMock.mockImplementation(() =>
+      createMockProcess({
+        stdoutChunks: [JSON.stringify(metadata)]
+      })
+    );
+
+    const result = await cargoUtils.resolveCargoProject(projectPath);
+    expect(result?.name).toBe('inherited');
+    expect(result?.version).toBe('2.3.0');
+  });
 });
 
 describe('getCargoTargets', () => {
@@ -228,41 +261,6 @@ describe('runCargoTest', () => {
   });
 });
 
-describe('needsRebuild', () => {
-  it('returns true when binary is older than sources', async () => {
-    const project = await createTempProject('needs-rebuild');
-    const targetDir = path.join(project, 'target', 'debug');
-    await fs.mkdir(targetDir, { recursive: true });
-    const binaryPath = path.join(targetDir, withBinaryExtension('needs-rebuild'));
-    await fs.writeFile(binaryPath, '');
-    await new Promise((resolve) => setTimeout(resolve, 10));
-    await fs.writeFile(path.join(project, 'src', 'main.rs'), '// updated');
-
-    const result = await cargoUtils.needsRebuild(project, 'needs-rebuild');
-    expect(result).toBe(true);
-  });
-
-  it('returns false when binary is newer than sources and Cargo.toml', async () => {
-    const project = await createTempProject('fresh');
-    const targetDir = path.join(project, 'target', 'debug');
-    await fs.mkdir(targetDir, { recursive: true });
-    const binaryPath = path.join(targetDir, withBinaryExtension('fresh'));
-    await fs.writeFile(path.join(project, 'src', 'main.rs'), '// stable');
-    await fs.writeFile(path.join(project, 'Cargo.toml'), 'name = "fresh"\nversion = "0.1.0"\n');
-    await new Promise((resolve) => setTimeout(resolve, 10));
-    await fs.writeFile(binaryPath, '');
-
-    const result = await cargoUtils.needsRebuild(project, 'fresh');
-    expect(result).toBe(false);
-  });
-
-  it('returns true when binary does not exist', async () => {
-    const project = await createTempProject('missing-bin');
-    const result = await cargoUtils.needsRebuild(project, 'missing-bin');
-    expect(result).toBe(true);
-  });
-});
-
 describe('getDefaultBinary', () => {
   it('returns first binary target when available', async () => {
     const project = await createTempProject('default-bin');
@@ -288,11 +286,40 @@ describe('getDefaultBinary', () => {
     expect(result).toBe('default-bin');
   });
 
+  it('prefers default-run over the first binary target', async () => {
+    const project = await createTempProject('default-run');
+    const metadata = {
+      packages: [
+        {
+          name: 'default-run',
+          version: '0.1.0',
+          default_run: 'server',
+          manifest_path: path.join(project, 'Cargo.toml'),
+          targets: [
+            { name: 'client', kind: ['bin'], src_path: 'src/bin/client.rs' },
+            { name: 'server', kind: ['bin'], src_path: 'src/bin/server.rs' }
+          ]
+        }
+      ]
+    };
+
+    spawnMock.mockImplementation(() =>
+      createMockProcess({
+        stdoutChunks: [JSON.stringify(metadata)]
+      })
+    );
+
+    const result = await cargoUtils.getDefaultBinary(project);
+    expect(result).toBe('server');
+  });
+
   it('falls back to package name when no binary targets', async () => {
     const project = await createTempProject('package-only');
     const metadata = {
       packages: [
         {
+          name: 'package-only',
+          version: '0.1.0',
           manifest_path: path.join(project, 'Cargo.toml'),
           targets: [{ name: 'package-only', kind: ['lib'], src_path: 'src/lib.rs' }]
         }
@@ -356,6 +383,298 @@ describe('findCargoProjectRoot', () => {
   });
 });
 
+describe('resolveCargoBinary', () => {
+  it('resolves a workspace member binary under the workspace target directory', async () => {
+    const workspace = await createTempProject('workspace');
+    const member = path.join(workspace, 'crates', 'cli');
+    await fs.mkdir(path.join(member, 'src'), { recursive: true });
+    await fs.writeFile(path.join(member, 'Cargo.toml'), '[package]\nname = "cli"\n');
+    const file = path.join(member, 'src', 'main.rs');
+    await fs.writeFile(file, 'fn main() {}');
+    const targetDirectory = path.join(os.tmpdir(), 'shared-target');
+
+    spawnMock.mockImplementation(() =>
+      createMockProcess({
+        stdoutChunks: [
+          JSON.stringify({
+            packages: [
+              {
+                id: 'cli 0.1.0',
+                name: 'cli',
+                version: '0.1.0',
+                manifest_path: path.join(member, 'Cargo.toml'),
+                targets: [{ name: 'cli', kind: ['bin'], src_path: file }]
+              },
+              {
+                id: 'workspace 0.1.0',
+                name: 'workspace',
+                version: '0.1.0',
+                manifest_path: path.join(workspace, 'Cargo.toml'),
+                targets: [{ name: 'workspace', kind: ['bin'], src_path: path.join(workspace, 'src', 'main.rs') }]
+              }
+            ],
+            workspace_members: ['cli 0.1.0', 'workspace 0.1.0'],
+            workspace_root: workspace,
+            target_directory: targetDirectory
+          })
+        ]
+      })
+    );
+
+    const resolution = await cargoUtils.resolveCargoBinary(file);
+    expect(spawnMock.mock.calls[0][2]).toMatchObject({ cwd: member });
+    expect(resolution.packageRoot).toBe(member);
+    expect(resolution.packageName).toBe('cli');
+    expect(resolution.workspaceRoot).toBe(workspace);
+    expect(resolution.binaryPath).toBe(path.join(targetDirectory, 'debug', withBinaryExtension('cli')));
+  });
+
+  it('falls back to <package>/target when cargo metadata is unavailable', async () => {
+    const project = await createTempProject('no-metadata');
+    spawnMock.mockImplementation(() => createMockProcess({ exitCode: 101 }));
+
+    const resolution = await cargoUtils.resolveCargoBinary(path.join(project, 'src', 'main.rs'), { release: true });
+    expect(resolution.packageRoot).toBe(project);
+    expect(resolution.binaryPath).toBe(
+      path.join(project, 'target', 'release', withBinaryExtension(path.basename(project)))
+    );
+  });
+});
+
+describe('getCargoProfileDir', () => {
+  it('nests cross-compiled artifacts under the target triple', () => {
+    const targetDirectory = path.join('/ws', 'target');
+    expect(cargoUtils.getCargoProfileDir(targetDirectory)).toBe(path.join(targetDirectory, 'debug'));
+    expect(cargoUtils.getCargoProfileDir(targetDirectory, { release: true })).toBe(path.join(targetDirectory, 'release'));
+    expect(cargoUtils.getCargoProfileDir(targetDirectory, { release: true, profile: 'debugging' }))
+      .toBe(path.join(targetDirectory, 'debugging'));
+    expect(cargoUtils.getCargoProfileDir(targetDirectory, { target: 'wasm32-wasip1' }))
+      .toBe(path.join(targetDirectory, 'wasm32-wasip1', 'debug'));
+    expect(cargoUtils.getCargoProfileDir(targetDirectory, { profile: 'bench', target: 'aarch64-apple-darwin' }))
+      .toBe(path.join(targetDirectory, 'aarch64-apple-darwin', 'release'));
+  });
+});
+
+describe('resolveCargoBinary target mapping', () => {
+  const mockTargets = (project: string, targets: Array<Record<string, unknown>>): void => {
+    spawnMock.mockImplementation(() =>
+      createMockProcess({
+        stdoutChunks: [
+          JSON.stringify({
+            packages: [
+              {
+                name: 'multi',
+                version: '0.1.0',
+                manifest_path: path.join(project, 'Cargo.toml'),
+                targets
+              }
+            ],
+            workspace_root: project,
+            target_directory: path.join(project, 'target')
+          })
+        ]
+      })
+    );
+  };
+
+  it('maps bins, examples and integration tests to their own target', async () => {
+    const project = await createTempProject('multi');
+    const tool = path.join(project, 'src', 'bin', 'tool.rs');
+    const demo = path.join(project, 'examples', 'demo.rs');
+    const api = path.join(project, 'tests', 'api-tests.rs');
+    mockTargets(project, [
+      { name: 'multi', kind: ['bin'], src_path: path.join(project, 'src', 'main.rs') },
+      { name: 'tool', kind: ['bin'], src_path: tool },
+      { name: 'demo', kind: ['example'], crate_types: ['bin'], src_path: demo },
+      { name: 'api-tests', kind: ['test'], src_path: api }
+    ]);
+    const depsDir = path.join(project, 'target', 'debug', 'deps');
+    await fs.mkdir(depsDir, { recursive: true });
+    const testExe = path.join(depsDir, withBinaryExtension('api_tests-0123456789abcdef'));
+    await fs.writeFile(testExe, '');
+    await fs.writeFile(path.join(depsDir, 'api_tests-0123456789abcdef.d'), '');
+
+    const toolResolution = await cargoUtils.resolveCargoBinary(tool);
+    expect(toolResolution.target).toEqual({ kind: 'bin', name: 'tool' });
+    expect(toolResolution.binaryPath).toBe(path.join(project, 'target', 'debug', withBinaryExtension('tool')));
+
+    const demoResolution = await cargoUtils.resolveCargoBinary(demo);
+    expect(demoResolution.target).toEqual({ kind: 'example', name: 'demo' });
+    expect(demoResolution.binaryPath).toBe(
+      path.join(project, 'target', 'debug', 'examples', withBinaryExtension('demo'))
+    );
+
+    const testResolution = await cargoUtils.resolveCargoBinary(api);
+    expect(testResolution.target).toEqual({ kind: 'test', name: 'api-tests' });
+    expect(testResolution.binaryPath).toBe(testExe);
+  });
+
+  it('uses the default binary for files that are not a target root', async () => {
+    const project = await createTempProject('module-file');
+    mockTargets(project, [
+      { name: 'module-file', kind: ['bin'], src_path: path.join(project, 'src', 'main.rs') }
+    ]);
+
+    const resolution = await cargoUtils.resolveCargoBinary(path.join(project, 'src', 'parser.rs'));
+    expect(resolution.target).toBeUndefined();
+    expect(resolution.binaryName).toBe('module-file');
+  });
+
+  it('rejects a file that is the root of several targets', async () => {
+    const project = await createTempProject('ambiguous');
+    const shared = path.join(project, 'src', 'main.rs');
+    mockTargets(project, [
+      { name: 'server', kind: ['bin'], src_path: shared },
+      { name: 'server-bench', kind: ['bench'], src_path: shared }
+    ]);
+
+    await expect(cargoUtils.resolveCargoBinary(shared)).rejects.toThrow(
+      /multiple Cargo targets: bin "server", bench "server-bench"/
+    );
+  });
+});
+
+describe('listRustTargets', () => {
+  const mockWorkspace = (
+    root: string,
+    packages: Array<Record<string, unknown>>,
+    builds: Record<string, Array<Record<string, unknown>>> = {}
+  ): void => {
+    spawnMock.mockImplementation((_command: string, args: string[]) => {
+      if (args[0] !== 'metadata') {
+        // Builds are keyed by their target selection (`--lib`, `--bin listing`); others fail
+        const flag = args.findIndex(arg => /^--(lib|bin|example|test|bench)$/.test(arg));
+        const selection = args[flag] === '--lib' ? '--lib' : `${args[flag]} ${args[flag + 1]}`;
+        const artifacts = builds[selection];
+        return createMockProcess({
+          stdoutChunks: (artifacts ?? []).map(a => JSON.stringify(a) + '\n'),
+          exitCode: artifacts ? 0 : 101
+        });
+      }
+      return createMockProcess({
+        stdoutChunks: [
+          JSON.stringify({
+            packages,
+            workspace_root: root,
+            target_directory: path.join(root, 'target')
+          })
+        ]
+      });
+    });
+  };
+
+  it('reports every target with its debug build status', async () => {
+    const project = await createTempProject('listing');
+    const profileDir = path.join(project, 'target', 'debug');
+    await fs.mkdir(path.join(profileDir, 'deps'), { recursive: true });
+    const artifact = (name: string, kind: string, fresh: boolean) => ({
+      reason: 'compiler-artifact',
+      package_id: `path+file://${project}#listing@0.1.0`,
+      target: { name, kind: [kind], src_path: path.join(project, 'src', kind === 'lib' ? 'lib.rs' : 'main.rs') },
+      filenames: [],
+      fresh
+    });
+    mockWorkspace(project, [
+      {
+        name: 'listing',
+        version: '0.1.0',
+        manifest_path: path.join(project, 'Cargo.toml'),
+        targets: [
+          { name: 'listing', kind: ['lib'], crate_types: ['lib'], src_path: path.join(project, 'src', 'lib.rs') },
+          { name: 'listing', kind: ['bin'], crate_types: ['bin'], src_path: path.join(project, 'src', 'main.rs') },
+          {
+            name: 'tool',
+            kind: ['bin'],
+            crate_types: ['bin'],
+            'required-features': ['cli'],
+            src_path: path.join(project, 'src', 'bin', 'tool.rs')
+          },
+          { name: 'build-script-build', kind: ['custom-build'], src_path: path.join(project, 'build.rs') }
+        ]
+      }
+    ], {
+      // A dependency of the library changed; the binary is up to date
+      '--lib': [artifact('listing', 'lib', false)],
+      '--bin listing': [artifact('listing', 'lib', true), artifact('listing', 'bin', true)]
+    });
+
+    const rlib = path.join(profileDir, 'deps', 'liblisting-0123456789abcdef.rlib');
+    await fs.writeFile(rlib, '');
+    const binary = path.join(profileDir, withBinaryExtension('listing'));
+    await fs.writeFile(binary, '');
+
+    const listing = await cargoUtils.listRustTargets(project);
+
+    const builds = spawnMock.mock.calls.map(call => call[1] as string[]).filter(args => args[0] === 'build');
+    expect(builds).toEqual([
+      ['build', '--lib', '--message-format=json', '--package', 'listing'],
+      ['build', '--message-format=json', '--package', 'listing', '--bin', 'listing']
+    ]);
+    expect(spawnMock.mock.calls.find(call => call[1][0] === 'build')?.[2]).toMatchObject({ cwd: project });
+    expect(listing.workspaceRoot).toBe(project);
+    expect(listing.targets.map(t => `${t.kind}:${t.name}`)).toEqual(['lib:listing', 'bin:listing', 'bin:tool']);
+    expect(listing.targets[0]).toMatchObject({ debugBuild: rlib, stale: true });
+    expect(listing.targets[1]).toMatchObject({ debugBuild: binary, stale: false });
+    expect(listing.targets[2]).toMatchObject({ requiredFeatures: ['cli'], debugBuild: null, stale: null });
+  });
+
+  it('checks a built target with its required features and reports failed builds as stale', async () => {
+    const project = await createTempProject('features');
+    await fs.mkdir(path.join(project, 'target', 'debug'), { recursive: true });
+    await fs.writeFile(path.join(project, 'target', 'debug', withBinaryExtension('tool')), '');
+    mockWorkspace(project, [
+      {
+        name: 'features',
+        version: '0.1.0',
+        manifest_path: path.join(project, 'Cargo.toml'),
+        targets: [
+          {
+            name: 'tool',
+            kind: ['bin'],
+            crate_types: ['bin'],
+            'required-features': ['cli'],
+            src_path: path.join(project, 'src', 'main.rs')
+          }
+        ]
+      }
+    ]);
+
+    const listing = await cargoUtils.listRustTargets(project);
+
+    expect(spawnMock.mock.calls.map(call => call[1])).toContainEqual(
+      ['build', '--message-format=json', '--features', 'cli', '--package', 'features', '--bin', 'tool']
+    );
+    expect(listing.targets[0]).toMatchObject({ stale: true });
+  });
+
+  it('lists only the package that owns a file', async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), 'cargo-utils-list-ws-'));
+    tempDirs.push(root);
+    await fs.writeFile(path.join(root, 'Cargo.toml'), '[workspace]\nmembers = ["app", "core"]\n');
+    for (const member of ['app', 'core']) {
+      await fs.mkdir(path.join(root, member, 'src'), { recursive: true });
+      await fs.writeFile(path.join(root, member, 'Cargo.toml'), `[package]\nname = "${member}"\n`);
+    }
+    mockWorkspace(root, ['app', 'core'].map(member => ({
+      name: member,
+      version: '0.1.0',
+      manifest_path: path.join(root, member, 'Cargo.toml'),
+      targets: [{ name: member, kind: ['lib'], src_path: path.join(root, member, 'src', 'lib.rs') }]
+    })));
+
+    const workspace = await cargoUtils.listRustTargets(root);
+    expect(workspace.targets.map(t => t.package)).toEqual(['app', 'core']);
+
+    const member = await cargoUtils.listRustTargets(path.join(root, 'core', 'src', 'lib.rs'));
+    expect(member.targets.map(t => t.package)).toEqual(['core']);
+  });
+
+  it('throws when cargo metadata is unavailable', async () => {
+    const project = await createTempProject('no-metadata');
+    await expect(cargoUtils.listRustTargets(project)).rejects.toThrow(/cargo metadata failed/);
+  });
+});
+
 describe('runCargoBuild', () => {
   it('returns build output and success flag', async () => {
     spawnMock
@@ -421,7 +740,7 @@ describe('buildCargoProject', () => {
       error: vi.fn()
     };
 
-    const result = await cargoUtils.buildCargoProject(metadataProject, logger, 'release');
+    const result = await cargoUtils.buildCargoProject(metadataProject, logger, { release: true });
     expect(result.success).toBe(true);
     const expectedBinary = process.platform === 'win32' ? 'build-success.exe' : 'build-success';
     expect(result.binaryPath).toBe(
@@ -430,6 +749,437 @@ describe('buildCargoProject', () => {
     expect((logger.info as Mock)).toHaveBeenCalled();
   });
 
+  it('places the binary under the target directory reported by cargo', async () => {
+    const project = await createTempProject('custom-target');
+    const targetDirectory = path.join(project, 'out');
+    spawnMock
+      .mockImplementationOnce(() => createMockProcess({ exitCode: 0 }))
+      .mockImplementationOnce(() =>
+        createMockProcess({
+          stdoutChunks: [
+            JSON.stringify({
+              packages: [
+                {
+                  name: 'custom-target',
+                  version: '0.1.0',
+                  manifest_path: path.join(project, 'Cargo.toml'),
+                  targets: [{ name: 'custom-target', kind: ['bin'], src_path: 'src/main.rs' }]
+                }
+              ],
+              workspace_root: project,
+              target_directory: targetDirectory
+            })
+          ]
+        })
+      );
+
+    const result = await cargoUtils.buildCargoProject(project);
+    expect(result.binaryPath).toBe(
+      path.join(targetDirectory, 'debug', withBinaryExtension('custom-target'))
+    );
+  });
+
+  it('builds only the requested target', async () => {
+    const project = await createTempProject('example-build');
+    const executable = path.join(project, 'target', 'debug', 'examples', withBinaryExtension('demo'));
+    spawnMock.mockImplementationOnce(() =>
+      createMockProcess({
+        stdoutChunks: [
+          JSON.stringify({
+            reason: 'compiler-artifact',
+            package_id: 'example-build 0.1.0',
+            target: { name: 'demo', kind: ['example'], src_path: path.join(project, 'examples', 'demo.rs') },
+            profile: { test: false },
+            filenames: [executable],
+            executable,
+            fresh: true
+          }) + '\n',
+          JSON.stringify({ reason: 'build-finished', success: true }) + '\n'
+        ]
+      })
+    );
+
+    const result = await cargoUtils.buildCargoProject(project, undefined, {}, { kind: 'example', name: 'demo' });
+    expect(spawnMock.mock.calls[0][1]).toEqual([
+      'build',
+      '--message-format=json',
+      '--example',
+      'demo'
+    ]);
+    expect(result.binaryPath).toBe(executable);
+    expect(result.artifacts).toHaveLength(1);
+  });
+
+  it('passes profile, feature, target triple and package flags to cargo', async () => {
+    const project = await createTempProject('build-options');
+    spawnMock.mockImplementationOnce(() => createMockProcess({ exitCode: 101 }));
+
+    await cargoUtils.buildCargoProject(
+      project,
+      undefined,
+      {
+        release: true,
+        profile: 'debugging',
+        features: ['tracing', 'serde/std'],
+        noDefaultFeatures: true,
+        target: 'aarch64-unknown-linux-gnu',
+        package: 'server'
+      },
+      { kind: 'bin', name: 'server' }
+    );
+    expect(spawnMock.mock.calls[0][1]).toEqual([
+      'build',
+      '--message-format=json',
+      '--profile',
+      'debugging',
+      '--features',
+      'tracing,serde/std',
+      '--no-default-features',
+      '--target',
+      'aarch64-unknown-linux-gnu',
+      '--package',
+      'server',
+      '--bin',
+      'server'
+    ]);
+  });
+
+  it('falls back to the cross-target profile directory of the selected package', async () => {
+    const workspace = await createTempProject('options-ws');
+    const targetDirectory = path.join(workspace, 'target');
+    spawnMock
+      .mockImplementationOnce(() => createMockProcess({ exitCode: 0 }))
+      .mockImplementationOnce(() =>
+        createMockProcess({
+          stdoutChunks: [
+            JSON.stringify({
+              packages: [
+                {
+                  name: 'client',
+                  version: '0.1.0',
+                  manifest_path: path.join(workspace, 'client', 'Cargo.toml'),
+                  targets: [{ name: 'client', kind: ['bin'], src_path: 'src/main.rs' }]
+                },
+                {
+                  name: 'server',
+                  version: '0.1.0',
+                  manifest_path: path.join(workspace, 'server', 'Cargo.toml'),
+                  targets: [{ name: 'serverd', kind: ['bin'], src_path: 'src/main.rs' }]
+                }
+              ],
+              workspace_root: workspace,
+              target_directory: targetDirectory
+            })
+          ]
+        })
+      );
+
+    const result = await cargoUtils.buildCargoProject(workspace, undefined, {
+      profile: 'debugging',
+      target: 'x86_64-unknown-linux-musl',
+      package: 'server'
+    });
+    expect(result.binaryPath).toBe(
+      path.join(targetDirectory, 'x86_64-unknown-linux-musl', 'debugging', withBinaryExtension('serverd'))
+    );
+  });
+
+  it('takes hashed test executables and cross-target paths from artifact messages', async () => {
+    const project = await createTempProject('artifacts');
+    const libArtifact = {
+      reason: 'compiler-artifact',
+      package_id: 'artifacts 0.1.0',
+      target: { name: 'my-lib', kind: ['lib'], src_path: 'src/lib.rs' },
+      profile: { test: false },
+      filenames: ['/t/x86_64-unknown-linux-musl/debug/libmy_lib.rlib'],
+      executable: null,
+      fresh: false
+    };
+    const testArtifact = {
+      reason: 'compiler-artifact',
+      package_id: 'artifacts 0.1.0',
+      target: { name: 'api-tests', kind: ['test'], src_path: 'tests/api-tests.rs' },
+      profile: { test: true },
+      filenames: ['/t/x86_64-unknown-linux-musl/debug/deps/api_tests-9f86d081884c7d65'],
+      executable: '/t/x86_64-unknown-linux-musl/debug/deps/api_tests-9f86d081884c7d65',
+      fresh: false
+    };
+    spawnMock.mockImplementationOnce(() =>
+      createMockProcess({
+        // Messages split across chunks, as cargo's pipe delivers them
+        stdoutChunks: [
+          JSON.stringify(libArtifact) + '\n' + JSON.stringify(testArtifact).slice(0, 40),
+          JSON.stringify(testArtifact).slice(40) + '\n'
+        ],
+        stderrChunks: ['   Compiling artifacts v0.1.0\n']
+      })
+    );
+    const logger = { info: vi.fn(), error: vi.fn() };
+
+    const result = await cargoUtils.buildCargoProject(project, logger, {}, { kind: 'test', name: 'api-tests' });
+    expect(result.success).toBe(true);
+    expect(result.binaryPath).toBe(testArtifact.executable);
+    expect(result.artifacts?.map(a => a.target.name)).toEqual(['my-lib', 'api-tests']);
+    expect(result.artifacts?.[1].test).toBe(true);
+    expect(logger.info).toHaveBeenCalledWith(expect.stringContaining('Compiling artifacts'));
+  });
+
+  it('reports units cargo rebuilt and why', async () => {
+    const project = await createTempProject('freshness');
+    const artifact = (pkg: string, name: string, kind: string, fresh: boolean) => ({
+      reason: 'compiler-artifact',
+      package_id: `path+file:///ws/${pkg}#0.1.0`,
+      target: { name, kind: [kind], src_path: `/ws/${pkg}/src/${kind === 'lib' ? 'lib' : 'main'}.rs` },
+      profile: { test: false },
+      filenames: [`/ws/target/debug/${name}`],
+      executable: kind === 'bin' ? `/ws/target/debug/${name}` : null,
+      fresh
+    });
+    const log = (pkg: string, target: string, message: string) =>
+      `   0.03s  INFO prepare_target{force=false package_id=${pkg} v0.1.0 (/ws/${pkg}) target="${target}"}: ` +
+      `cargo::core::compiler::fingerprint: ${message}\n`;
+    spawnMock.mockImplementationOnce(() =>
+      createMockProcess({
+        stdoutChunks: [
+          [artifact('util', 'util', 'lib', true), artifact('mathlib', 'mathlib', 'lib', false), artifact('app', 'app', 'bin', false)]
+            .map(a => JSON.stringify(a)).join('\n') + '\n'
+        ],
+        stderrChunks: [
+          log('app', 'app', 'fingerprint dirty for app v0.1.0 (/ws/app)/Build/TargetInner { name: "app" }') +
+          log('app', 'app', '    dirty: FsStatusOutdated(StaleDepFingerprint { unit: UnitIndex(1) })') +
+          log('mathlib', 'mathlib', 'fingerprint dirty for mathlib v0.1.0 (/ws/mathlib)/Build/TargetInner { name: "mathlib" }') +
+          log('mathlib', 'mathlib', '    dirty: FsStatusOutdated(StaleItem(ChangedFile { reference: "/ws/target/debug/.fingerprint/mathlib-1/dep-lib-mathlib", ' +
+            'reference_mtime: FileTime { seconds: 1, nanos: 0 }, stale: "/ws/mathlib/src/lib.rs", stale_mtime: FileTime { seconds: 2, nanos: 0 } }))'),
+          '   Compiling mathlib v0.1.0 (/ws/mathlib)\n   Compiling app v0.1.0 (/ws/app)\n'
+        ]
+      })
+    );
+    const logger = { info: vi.fn(), error: vi.fn() };
+
+    const result = await cargoUtils.buildCargoProject(project, logger, {}, { kind: 'bin', name: 'app' });
+
+    expect(spawnMock.mock.calls[0][2].env).toMatchObject({ CARGO_LOG: 'cargo::core::compiler::fingerprint=info' });
+    expect(result.fresh).toBe(false);
+    expect(result.staleUnits).toEqual([
+      { package: 'mathlib', target: 'mathlib', kind: ['lib'], reason: 'changed: /ws/mathlib/src/lib.rs' },
+      { package: 'app', target: 'app', kind: ['bin'], reason: 'a dependency was rebuilt' }
+    ]);
+    expect(logger.info).toHaveBeenCalledWith(expect.stringContaining('Rebuilt mathlib (lib "mathlib"): changed: /ws/mathlib/src/lib.rs'));
+    expect(logger.info).not.toHaveBeenCalledWith(expect.stringContaining('prepare_target'));
+  });
+
+  it('passes client-supplied names to cargo without a shell', async () => {
+    const project = await createTempProject('no-shell');
+    spawnMock.mockImplementationOnce(() => createMockProcess({ exitCode: 101 }));
+    spawnMock.mockImplementation(() => createMockProcess({}));
+
+    await cargoUtils.buildCargoProject(
+      project,
+      undefined,
+      { features: ['a; touch pwned'], profile: 'my profile' },
+      { kind: 'bin', name: '$(id)' }
+    );
+
+    expect(spawnMock.mock.calls[0][1]).toEqual([
+      'build', '--message-format=json', '--profile', 'my profile', '--features', 'a; touch pwned', '--bin', '$(id)'
+    ]);
+    expect(spawnMock.mock.calls[0][2].shell).toBeUndefined();
+  });
+
+  it('reads artifact messages that arrive after the process exits', async () => {
+    const project = await createTempProject('late-stdout');
+    const executable = path.join(project, 'target', 'debug', withBinaryExtension('late-stdout'));
+    const proc = new EventEmitter() as EventEmitter & { stdout: EventEmitter; stderr: EventEmitter };
+    proc.stdout = new EventEmitter();
+    proc.stderr = new EventEmitter();
+    spawnMock.mockImplementationOnce(() => {
+      queueMicrotask(() => {
+        proc.emit('exit', 0);
+        // stdout is drained after 'exit'; 'close' follows once it has ended
+        proc.stdout.emit('data', JSON.stringify({
+          reason: 'compiler-artifact',
+          package_id: `path+file://${project}#late-stdout@0.1.0`,
+          target: { name: 'late-stdout', kind: ['bin'], src_path: path.join(project, 'src', 'main.rs') },
+          filenames: [executable],
+          executable,
+          fresh: true
+        }) + '\n');
+        proc.emit('close', 0);
+      });
+      return proc;
+    });
+
+    const result = await cargoUtils.buildCargoProject(project, undefined, {}, { kind: 'bin', name: 'late-stdout' });
+
+    expect(result.success).toBe(true);
+    expect(result.binaryPath).toBe(executable);
+  });
+
+  it('reports each finished unit as progress', async () => {
+    const project = await createTempProject('progress');
+    const executable = path.join(project, 'target', 'debug', withBinaryExtension('progress'));
+    const proc = new EventEmitter() as EventEmitter & { stdout: EventEmitter; stderr: EventEmitter };
+    proc.stdout = new EventEmitter();
+    proc.stderr = new EventEmitter();
+    spawnMock.mockImplementationOnce(() => {
+      queueMicrotask(() => {
+        proc.stderr.emit('data', '   Compiling libc v0.2.190\n    Building [          ] 0/3: libc(build.rs)   \r');
+        proc.stdout.emit('data', JSON.stringify({
+          reason: 'compiler-artifact',
+          package_id: 'registry+https://github.com/rust-lang/crates.io-index#libc@0.2.190',
+          target: { name: 'build_script_build', kind: ['custom-build'], src_path: '/r/libc/build.rs' },
+          filenames: [],
+          fresh: false
+        }) + '\n{"reason":"build-script-executed","package_id":"registry+https://github.com/rust-lang/crates.io-index#libc@0.2.190"}\n');
+        proc.stderr.emit('data', '    Building [======>   ] 2/3: progress(bin)   \r   Compiling progress v0.1.0 (/p)\n');
+        proc.stdout.emit('data', JSON.stringify({
+          reason: 'compiler-artifact',
+          package_id: `path+file://${project}#progress@0.1.0`,
+          target: { name: 'progress', kind: ['bin'], src_path: path.join(project, 'src', 'main.rs') },
+          filenames: [executable],
+          executable,
+          fresh: false
+        }) + '\n');
+        proc.stderr.emit('data', '    Finished `dev` profile [unoptimized + debuginfo] target(s) in 1.20s\n');
+        proc.emit('exit', 0);
+        proc.emit('close', 0);
+      });
+      return proc;
+    });
+    const logger = { info: vi.fn(), error: vi.fn() };
+    const onProgress = vi.fn();
+
+    const result = await cargoUtils.buildCargoProject(project, logger, {}, undefined, { onProgress });
+
+    expect(spawnMock.mock.calls[0][2].env).toMatchObject({ CARGO_TERM_PROGRESS_WHEN: 'always' });
+    expect(onProgress.mock.calls.map(([progress]) => progress)).toEqual([
+      { progress: 0, message: 'cargo build --message-format=json' },
+      { progress: 1, total: 3, message: 'Compiled libc (custom-build "build_script_build")' },
+      { progress: 2, total: 3, message: 'Ran build script of libc' },
+      { progress: 3, total: 3, message: 'Compiled progress (bin "progress")' }
+    ]);
+    expect(logger.info).toHaveBeenCalledWith('[Rust Build] Compiling progress v0.1.0 (/p)');
+    expect(result.binaryPath).toBe(executable);
+  });
+
+  it.skipIf(process.platform === 'win32')('kills the cargo process group when the build is cancelled', async () => {
+    const project = await createTempProject('cancel');
+    const proc = new EventEmitter() as EventEmitter & { stdout: EventEmitter; stderr: EventEmitter; pid: number; kill: Mock };
+    proc.stdout = new EventEmitter();
+    proc.stderr = new EventEmitter();
+    proc.pid = 4242;
+    proc.kill = vi.fn();
+    spawnMock.mockImplementationOnce(() => proc);
+    const killSpy = vi.spyOn(process, 'kill').mockImplementation(() => {
+      queueMicrotask(() => {
+        proc.emit('exit', null);
+        proc.emit('close', null);
+      });
+      return true;
+    });
+    const controller = new AbortController();
+
+    try {
+      const build = cargoUtils.buildCargoProject(project, undefined, {}, undefined, { signal: controller.signal });
+      proc.stderr.emit('data', '   Compiling tokio v1.53.2\n    Building [====>     ] 26/28: tokio   \r');
+      controller.abort();
+      const result = await build;
+
+      expect(spawnMock.mock.calls[0][2]).toMatchObject({ detached: true });
+      expect(killSpy).toHaveBeenCalledWith(-4242, 'SIGTERM');
+      expect(result).toMatchObject({ success: false, cancelled: true, error: 'Build cancelled' });
+    } finally {
+      killSpy.mockRestore();
+    }
+  });
+
+  it('does not start cargo when already cancelled', async () => {
+    const controller = new AbortController();
+    controller.abort();
+
+    const result = await cargoUtils.buildCargoProject('/p', undefined, {}, undefined, { signal: controller.signal });
+
+    expect(result).toEqual({ success: false, cancelled: true, error: 'Build cancelled' });
+    expect(spawnMock).not.toHaveBeenCalled();
+  });
+
+  it('keeps the fingerprint log out of build errors', async () => {
+    const project = await createTempProject('dirty-fail');
+    spawnMock.mockImplementationOnce(() =>
+      createMockProcess({
+        stderrChunks: [
+          '   0.01s  INFO prepare_target{force=false package_id=dirty-fail v0.1.0 (/p) target="dirty-fail"}: ' +
+            'cargo::core::compiler::fingerprint:     err: failed to read `/p/target/debug/.fingerprint/x/bin-dirty-fail`\n' +
+            'Caused by:\n    No such file or directory (os error 2)\n',
+          '   Compiling dirty-fail v0.1.0 (/p)\nerror[E0308]: mismatched types\n --> src/main.rs:4:20\n'
+        ],
+        exitCode: 101
+      })
+    );
+
+    const result = await cargoUtils.buildCargoProject(project);
+    expect(result.success).toBe(false);
+    expect(result.error).toContain('error[E0308]: mismatched types\n --> src/main.rs:4:20');
+    expect(result.error).not.toMatch(/prepare_target|Caused by|No such file/);
+  });
+
+  it('returns compiler diagnostics with workspace-resolved paths', async () => {
+    const workspace = await createTempProject('diag-ws');
+    const member = path.join(workspace, 'app');
+    await fs.mkdir(member, { recursive: true });
+    spawnMock
+      .mockImplementationOnce(() =>
+        createMockProcess({
+          stdoutChunks: [
+            JSON.stringify({
+              reason: 'compiler-message',
+              package_id: `path+file://${member}#0.1.0`,
+              message: {
+                level: 'error',
+                code: { code: 'E0308' },
+                message: 'mismatched types',
+                spans: [{
+                  file_name: 'app/src/main.rs',
+                  line_start: 3,
+                  line_end: 3,
+                  column_start: 18,
+                  column_end: 24,
+                  is_primary: true,
+                  label: 'expected `u32`, found `&str`',
+                  suggested_replacement: null
+                }],
+                children: [],
+                rendered: 'error[E0308]: mismatched types\n --> app/src/main.rs:3:18\n'
+              }
+            }) + '\n',
+            JSON.stringify({ reason: 'build-finished', success: false }) + '\n'
+          ],
+          stderrChunks: ['   Compiling app v0.1.0\nerror: could not compile `app` (bin "app") due to 1 previous error\n'],
+          exitCode: 101
+        })
+      )
+      .mockImplementationOnce(() =>
+        createMockProcess({
+          stdoutChunks: [JSON.stringify({ packages: [], workspace_root: workspace, target_directory: path.join(workspace, 'target') })]
+        })
+      );
+
+    const result = await cargoUtils.buildCargoProject(member);
+    expect(result.success).toBe(false);
+    expect(result.diagnostics).toEqual([
+      expect.objectContaining({
+        level: 'error',
+        code: 'E0308',
+        file: path.join(workspace, 'app', 'src', 'main.rs'),
+        line: 3,
+        column: 18,
+        label: 'expected `u32`, found `&str`'
+      })
+    ]);
+    expect(result.error).toContain('error[E0308]: mismatched types\n --> app/src/main.rs:3:18');
+    expect(result.error).toContain('could not compile `app`');
+  });
+
   it('reports errors when build process exits with failure', async () => {
     const project = await createTempProject('build-fail');
     spawnMock
@@ -0,0 +1,46 @@
+/**
+ * Resolves binaries in examples/rust/workspace with the real cargo toolchain.
+ * Skipped when cargo is not installed.
+ */
+import { describe, it, expect, afterEach } from 'vitest';
+import { spawnSync } from 'child_process';
+import * as path from 'path';
+import * as os from 'os';
+import { fileURLToPath } from 'url';
+import { resolveCargoBinary } from '../src/utils/cargo-utils.js';
+
+const currentDir = path.dirname(fileURLToPath(import.meta.url));
+const workspaceRoot = path.resolve(currentDir, '../../../examples/rust/workspace');
+const cargoAvailable = spawnSync('cargo', ['--version'], { stdio: 'ignore', shell: true }).status === 0;
+const exe = (name: string): string => (process.platform === 'win32' ? `${name}.exe` : name);
+
+describe.skipIf(!cargoAvailable)('workspace example', () => {
+  const originalTargetDir = process.env.CARGO_TARGET_DIR;
+
+  afterEach(() => {
+    if (originalTargetDir === undefined) {
+      delete process.env.CARGO_TARGET_DIR;
+    } else {
+      process.env.CARGO_TARGET_DIR = originalTargetDir;
+    }
+  });
+
+  it('resolves a member source file to the binary in the workspace target directory', async () => {
+    delete process.env.CARGO_TARGET_DIR;
+    const resolution = await resolveCargoBinary(path.join(workspaceRoot, 'app', 'src', 'main.rs'));
+
+    expect(resolution.packageName).toBe('app');
+    expect(resolution.packageRoot).toBe(path.join(workspaceRoot, 'app'));
+    expect(resolution.workspaceRoot).toBe(workspaceRoot);
+    expect(resolution.binaryPath).toBe(path.join(workspaceRoot, 'target', 'debug', exe('app')));
+  }, 30000);
+
+  it('honors CARGO_TARGET_DIR', async () => {
+    const targetDir = path.join(os.tmpdir(), 'mcp-debugger-workspace-target');
+    process.env.CARGO_TARGET_DIR = targetDir;
+    const resolution = await resolveCargoBinary(path.join(workspaceRoot, 'app', 'src', 'main.rs'), { release: true });
+
+    expect(resolution.targetDirectory).toBe(targetDir);
+    expect(resolution.binaryPath).toBe(path.join(targetDir, 'release', exe('app')));
+  }, 30000);
+});
@@ -13,6 +13,20 @@ import {
   AdapterConfig
 } from '@debugmcp/shared';
 import * as path from 'path';
+import * as fs from 'fs/promises';
+import * as os from 'os';
+import { buildCargoProject, locateCargoExecutable } from '../src/utils/cargo-utils.js';
+import { getRustSysroot } from '../src/utils/rust-utils.js';
+
+vi.mock('../src/utils/cargo-utils.js', () => ({
+  buildCargoProject: vi.fn(),
+  locateCargoExecutable: vi.fn()
+}));
+
+vi.mock('../src/utils/rust-utils.js', async (importOriginal) => ({
+  ...await importOriginal<typeof import('../src/utils/rust-utils.js')>(),
+  getRustSysroot: vi.fn().mockResolvedValue(null)
+}));
 
 // Mock dependencies
 const mockDependencies: AdapterDependencies = {
@@ -78,6 +92,23 @@ describe('RustDebugAdapter', () => {
       expect(adapter.supportsFeature(DebugFeature.LOG_POINTS)).toBe(true);
     });
     
+    it('should gate data breakpoints on the CodeLLDB version', async () => {
+      const versionSpy = vi.spyOn(adapter, 'getCodeLLDBVersion').mockResolvedValue('1.6.2');
+      const oldResult = await adapter.checkFeatureSupport(DebugFeature.DATA_BREAKPOINTS);
+      expect(oldResult.supported).toBe(false);
+      expect(oldResult.reason).toContain('1.7.0');
+
+      versionSpy.mockResolvedValue('v1.11.0');
+      await expect(adapter.checkFeatureSupport(DebugFeature.DATA_BREAKPOINTS)).resolves.toEqual({ supported: true });
+      await expect(adapter.checkFeatureSupport(DebugFeature.STEP_BACK)).resolves.toMatchObject({ supported: false });
+    });
+
+    it('should take the target architecture from the cross target, else the executable', async () => {
+      await expect(adapter.getTargetArchitecture({ program: '/missing/app', cargo: { target: 'aarch64-unknown-linux-gnu' } }))
+        .resolves.toBe('aarch64');
+      await expect(adapter.getTargetArchitecture({ program: '/missing/app' })).resolves.toBeUndefined();
+    });
+
     it('should not support reverse debugging', () => {
       expect(adapter.supportsFeature(DebugFeature.STEP_BACK)).toBe(false);
     });
@@ -190,7 +221,47 @@ describe('RustDebugAdapter', () => {
       expect(transformed.sourceLanguages).toEqual(['rust']);
     });
     
+    it('should load the bundled LLDB scripts before user initCommands', async () => {
+      const transformed = await adapter.transformLaunchConfig({
+        program: './target/debug/myapp',
+        cwd: '/project',
+        initCommands: ['settings set target.max-children-count 500']
+      });
+
+      const initCommands = transformed.initCommands as string[];
+      expect(initCommands).toHaveLength(5);
+      // No sysroot: the vendored std pretty-printers
+      expect(initCommands[0]).toMatch(/^command script import ".*\/lldb\/rust\/lldb_lookup\.py"$/);
+      expect(initCommands[1]).toMatch(/^command source -s 0 ".*\/lldb\/rust\/lldb_commands"$/);
+      expect(initCommands[2]).toMatch(/^command script import ".*\/lldb\/async_futures\.py"$/);
+      expect(initCommands[3]).toMatch(/^command script import ".*\/lldb\/tokio_tasks\.py"$/);
+      expect(initCommands[4]).toBe('settings set target.max-children-count 500');
+    });
+
+    it('should load the pretty-printers of the project toolchain', async () => {
+      const sysroot = await fs.mkdtemp(path.join(os.tmpdir(), 'rust-sysroot-'));
+      const etcDir = path.join(sysroot, 'lib', 'rustlib', 'etc');
+      await fs.mkdir(etcDir, { recursive: true });
+      await fs.writeFile(path.join(etcDir, 'lldb_lookup.py'), '');
+      await fs.writeFile(path.join(etcDir, 'lldb_commands'), '');
+      vi.mocked(getRustSysroot).mockResolvedValueOnce(sysroot);
+
+      const transformed = await adapter.transformLaunchConfig({
+        program: './target/debug/myapp',
+        cwd: '/project'
+      });
+
+      const scriptsDir = etcDir.replace(/\\/g, '/');
+      expect(getRustSysroot).toHaveBeenCalledWith('/project');
+      expect((transformed.initCommands as string[]).slice(0, 2)).toEqual([
+        `command script import "${scriptsDir}/lldb_lookup.py"`,
+        `command source -s 0 "${scriptsDir}/lldb_commands"`
+      ]);
+    });
+
     it('should handle Cargo configuration', async () => {
+      const binaryPath = path.join('/project', 'target', 'release', process.platform === 'win32' ? 'my_binary.exe' : 'my_binary');
+      vi.mocked(buildCargoProject).mockResolvedValue({ success: true, binaryPath, artifacts: [] });
       const config = {
         cargo: {
           bin: 'my_binary',
@@ -202,6 +273,10 @@ describe('RustDebugAdapter', () => {
       
       const transformed = await adapter.transformLaunchConfig(config);
       
+      expect(buildCargoProject).toHaveBeenCalledWith('/project', expect.anything(), { release: true }, {
+        kind: 'bin',
+        name: 'my_binary'
+      }, {});
       expect(transformed.program).toContain(path.join('target', 'release', 'my_binary'));
       if (process.platform === 'win32') {
         expect(transformed.program).toContain('.exe');
@@ -211,6 +286,59 @@ describe('RustDebugAdapter', () => {
       expect(transformedWithFields.sourceLanguages).toEqual(['rust']);
     });
     
+    it('should launch the hashed test executable reported by cargo', async () => {
+      const testBinary = path.join('/project', 'target', 'debug', 'deps', 'integration-3f2a9c1d0b7e6f54');
+      vi.mocked(buildCargoProject).mockResolvedValue({ success: true, binaryPath: testBinary, artifacts: [] });
+
+      const transformed = await adapter.transformLaunchConfig({ cargo: { test: 'integration' }, cwd: '/project' });
+
+      expect(buildCargoProject).toHaveBeenCalledWith('/project', expect.anything(), {}, {
+        kind: 'test',
+        name: 'integration'
+      }, {});
+      expect(transformed.program).toBe(testBinary);
+    });
+    
+    it('should pass features, profile, target triple and package to the build', async () => {
+      const binaryPath = path.join('/project', 'target', 'aarch64-unknown-linux-gnu', 'debugging', 'server');
+      vi.mocked(buildCargoProject).mockResolvedValue({ success: true, binaryPath, artifacts: [] });
+
+      const transformed = await adapter.transformLaunchConfig({
+        cargo: {
+          bin: 'server',
+          profile: 'debugging',
+          features: ['tracing', 'metrics'],
+          allFeatures: false,
+          target: 'aarch64-unknown-linux-gnu',
+          package: 'server'
+        },
+        cwd: '/project'
+      });
+
+      expect(buildCargoProject).toHaveBeenCalledWith(
+        '/project',
+        expect.anything(),
+        {
+          profile: 'debugging',
+          features: ['tracing', 'metrics'],
+          target: 'aarch64-unknown-linux-gnu',
+          package: 'server'
+        },
+        { kind: 'bin', name: 'server' },
+        {}
+      );
+      expect(transformed.program).toBe(binaryPath);
+    });
+    
+    it('should locate an existing executable when cargo.build is false', async () => {
+      vi.mocked(locateCargoExecutable).mockResolvedValue(undefined);
+
+      await expect(
+        adapter.transformLaunchConfig({ cargo: { example: 'demo', build: false }, cwd: '/project' })
+      ).rejects.toThrow('No built executable found for example "demo"');
+      expect(buildCargoProject).not.toHaveBeenCalled();
+    });
+    
     it('should throw error if no program specified', async () => {
       const config = {
         args: ['--verbose']
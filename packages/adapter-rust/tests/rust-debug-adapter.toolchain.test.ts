@@ -1,6 +1,6 @@
 import { describe, it, expect, beforeEach, vi, type Mock } from 'vitest';
 import { RustDebugAdapter } from '../src/rust-debug-adapter.js';
-import { AdapterError, DebugFeature, AdapterState } from '@debugmcp/shared';
+import { AdapterError, AdapterErrorCode, BuildFailedError, DebugFeature, AdapterState } from '@debugmcp/shared';
 import type { AdapterConfig, AdapterDependencies } from '@debugmcp/shared';
 import * as fs from 'fs/promises';
 import * as fsSync from 'fs';
@@ -11,6 +11,7 @@ vi.mock('../src/utils/rust-utils.js', () => ({
   checkCargoInstallation: vi.fn(),
   checkRustInstallation: vi.fn(),
   getRustHostTriple: vi.fn(),
+  getRustSysroot: vi.fn(),
   findDlltoolExecutable: vi.fn()
 }));
 
@@ -23,9 +24,7 @@ vi.mock('../src/utils/binary-detector.js', () => ({
 }));
 
 vi.mock('../src/utils/cargo-utils.js', () => ({
-  findCargoProjectRoot: vi.fn(),
-  getDefaultBinary: vi.fn(),
-  needsRebuild: vi.fn(),
+  resolveCargoBinary: vi.fn(),
   buildCargoProject: vi.fn()
 }));
 
@@ -33,13 +32,12 @@ import {
   checkCargoInstallation,
   checkRustInstallation,
   getRustHostTriple,
+  getRustSysroot,
   findDlltoolExecutable
 } from '../src/utils/rust-utils.js';
 import { detectBinaryFormat } from '../src/utils/binary-detector.js';
 import {
-  findCargoProjectRoot,
-  getDefaultBinary,
-  needsRebuild,
+  resolveCargoBinary,
   buildCargoProject
 } from '../src/utils/cargo-utils.js';
 import { resolveCodeLLDBExecutable } from '../src/utils/codelldb-resolver.js';
@@ -94,13 +92,12 @@ describe('RustDebugAdapter toolchain logic', () => {
     vi.clearAllMocks();
     vi.mocked(resolveCodeLLDBExecutable).mockReset();
     vi.mocked(detectBinaryFormat).mockReset();
-    vi.mocked(findCargoProjectRoot).mockReset();
-    vi.mocked(getDefaultBinary).mockReset();
-    vi.mocked(needsRebuild).mockReset();
+    vi.mocked(resolveCargoBinary).mockReset();
     vi.mocked(buildCargoProject).mockReset();
     vi.mocked(checkCargoInstallation).mockReset();
     vi.mocked(checkRustInstallation).mockReset();
     vi.mocked(getRustHostTriple).mockReset();
+    vi.mocked(getRustSysroot).mockReset();
     vi.mocked(findDlltoolExecutable).mockReset();
     delete process.env.MCP_RUST_ALLOW_PREBUILT;
     delete process.env.MCP_RUST_EXECUTABLE_PLACEHOLDER;
@@ -187,6 +184,26 @@ describe('RustDebugAdapter toolchain logic', () => {
         restorePlatform();
       }
     });
+
+    it('reports which pretty-printers are active', async () => {
+      const sysroot = await fs.mkdtemp(path.join(os.tmpdir(), 'rda-sysroot-'));
+      const etcDir = path.join(sysroot, 'lib', 'rustlib', 'etc');
+      await fs.mkdir(etcDir, { recursive: true });
+      await fs.writeFile(path.join(etcDir, 'lldb_lookup.py'), '');
+      await fs.writeFile(path.join(etcDir, 'lldb_commands'), '');
+      vi.mocked(resolveCodeLLDBExecutable).mockResolvedValue('/codelldb');
+
+      vi.mocked(getRustSysroot).mockResolvedValueOnce(sysroot);
+      const fromToolchain = await adapter.validateEnvironment();
+      const toolchainCodes = fromToolchain.warnings.map((warning) => warning.code);
+      expect(toolchainCodes).not.toContain('RUST_PRETTY_PRINTERS_VENDORED');
+      expect(toolchainCodes).not.toContain('RUST_PRETTY_PRINTERS_NOT_FOUND');
+      expect(dependencies.logger?.info).toHaveBeenCalledWith(expect.stringContaining(etcDir));
+
+      vi.mocked(getRustSysroot).mockResolvedValueOnce(null);
+      const vendored = await adapter.validateEnvironment();
+      expect(vendored.warnings.map((warning) => warning.code)).toContain('RUST_PRETTY_PRINTERS_VENDORED');
+    });
   });
 
   describe('buildAdapterCommand environment wiring', () => {
@@ -240,37 +257,59 @@ describe('RustDebugAdapter toolchain logic', () => {
       debugInfoType: 'dwarf'
     };
 
-    it('resolves source program without rebuild when up to date', async () => {
-      vi.mocked(findCargoProjectRoot).mockResolvedValueOnce('/workspace/project');
-      vi.mocked(getDefaultBinary).mockResolvedValueOnce('project-bin');
-      vi.mocked(needsRebuild).mockResolvedValueOnce(false);
+    const binaryPath = path.join(
+      '/workspace/project',
+      'target',
+      'debug',
+      process.platform === 'win32' ? 'project-bin.exe' : 'project-bin'
+    );
+    const resolution = {
+      packageRoot: '/workspace/project',
+      packageName: 'project',
+      workspaceRoot: '/workspace/project',
+      targetDirectory: '/workspace/project/target',
+      binaryName: 'project-bin',
+      binaryPath
+    };
+
+    it('launches the binary cargo reports as up to date', async () => {
+      vi.mocked(resolveCargoBinary).mockResolvedValueOnce(resolution);
+      vi.mocked(buildCargoProject).mockResolvedValueOnce({
+        success: true,
+        binaryPath,
+        fresh: true,
+        staleUnits: []
+      });
       detectBinaryFormat.mockResolvedValueOnce(mockBinaryInfo);
 
       const result = await adapter.transformLaunchConfig({
         program: '/workspace/project/src/main.rs'
       });
 
-      const expectedBinaryPath = path.join(
+      expect(buildCargoProject).toHaveBeenCalledWith(
         '/workspace/project',
-        'target',
-        'debug',
-        process.platform === 'win32' ? 'project-bin.exe' : 'project-bin'
+        dependencies.logger,
+        {},
+        undefined,
+        {}
       );
-      expect(result.program).toBe(expectedBinaryPath);
-      expect(buildCargoProject).not.toHaveBeenCalled();
+      expect(result.program).toBe(binaryPath);
     });
 
-    it('builds the project when sources are stale', async () => {
-      vi.mocked(findCargoProjectRoot).mockResolvedValueOnce('/workspace/project');
-      vi.mocked(getDefaultBinary).mockResolvedValueOnce('project-bin');
-      vi.mocked(needsRebuild).mockResolvedValueOnce(true);
+    it('lets cargo rebuild stale units even when the binary exists', async () => {
+      vi.mocked(resolveCargoBinary).mockResolvedValueOnce(resolution);
       const builtBinaryPath =
         process.platform === 'win32'
           ? '/workspace/project/target/release/project-bin.exe'
           : '/workspace/project/target/release/project-bin';
       vi.mocked(buildCargoProject).mockResolvedValueOnce({
         success: true,
-        binaryPath: builtBinaryPath
+        binaryPath: builtBinaryPath,
+        fresh: false,
+        staleUnits: [
+          { package: 'mathlib', target: 'mathlib', kind: ['lib'], reason: 'changed: /workspace/mathlib/src/lib.rs' },
+          { package: 'project', target: 'project-bin', kind: ['bin'], reason: 'a dependency was rebuilt' }
+        ]
       });
       detectBinaryFormat.mockResolvedValueOnce(mockBinaryInfo);
 
@@ -279,18 +318,47 @@ describe('RustDebugAdapter toolchain logic', () => {
         cargo: { release: true }
       });
 
+      expect(resolveCargoBinary).toHaveBeenCalledWith('/workspace/project/src/main.rs', { release: true });
       expect(buildCargoProject).toHaveBeenCalledWith(
         '/workspace/project',
         dependencies.logger,
-        'release'
+        { release: true },
+        undefined,
+        {}
       );
       expect(result.program).toBe(builtBinaryPath);
     });
 
+    it('builds the source file with the configured features, profile and target', async () => {
+      vi.mocked(resolveCargoBinary).mockResolvedValueOnce(resolution);
+      vi.mocked(buildCargoProject).mockResolvedValueOnce({ success: true, binaryPath, fresh: true, staleUnits: [] });
+      detectBinaryFormat.mockResolvedValueOnce(mockBinaryInfo);
+
+      await adapter.transformLaunchConfig({
+        program: '/workspace/project/src/main.rs',
+        cargo: {
+          profile: 'debugging',
+          features: ['tracing'],
+          noDefaultFeatures: true,
+          target: 'x86_64-unknown-linux-musl',
+          package: 'other'
+        }
+      });
+
+      const options = {
+        profile: 'debugging',
+        features: ['tracing'],
+        noDefaultFeatures: true,
+        target: 'x86_64-unknown-linux-musl'
+      };
+      expect(resolveCargoBinary).toHaveBeenCalledWith('/workspace/project/src/main.rs', options);
+      expect(buildCargoProject).toHaveBeenCalledWith('/workspace/project', dependencies.logger, options, undefined, {});
+      // The source file, not cargo.package, selects the package
+      expect(vi.mocked(buildCargoProject).mock.calls[0][2]?.package).toBeUndefined();
+    });
+
     it('throws when Cargo build fails', async () => {
-      vi.mocked(findCargoProjectRoot).mockResolvedValueOnce('/workspace/project');
-      vi.mocked(getDefaultBinary).mockResolvedValueOnce('project-bin');
-      vi.mocked(needsRebuild).mockResolvedValueOnce(true);
+      vi.mocked(resolveCargoBinary).mockResolvedValueOnce(resolution);
       vi.mocked(buildCargoProject).mockResolvedValueOnce({
         success: false,
         error: 'compile error'
@@ -303,6 +371,54 @@ describe('RustDebugAdapter toolchain logic', () => {
         })
       ).rejects.toThrow('Cargo build failed: compile error');
     });
+
+    it('passes progress reporting and cancellation to the build', async () => {
+      vi.mocked(resolveCargoBinary).mockResolvedValueOnce(resolution);
+      vi.mocked(buildCargoProject).mockResolvedValueOnce({ success: false, cancelled: true, error: 'Build cancelled' });
+      const controller = new AbortController();
+      const onProgress = vi.fn();
+
+      const error = await adapter
+        .transformLaunchConfig({ program: '/workspace/project/src/main.rs' }, { onProgress, signal: controller.signal })
+        .catch((err: unknown) => err);
+
+      expect(vi.mocked(buildCargoProject).mock.calls[0][4]).toEqual({ onProgress, signal: controller.signal });
+      expect(error).toBeInstanceOf(BuildFailedError);
+      expect((error as BuildFailedError).message).toBe('Cargo build cancelled');
+    });
+
+    it('throws a BuildFailedError carrying the compiler diagnostics', async () => {
+      vi.mocked(resolveCargoBinary).mockResolvedValueOnce(resolution);
+      const diagnostic = {
+        level: 'error',
+        code: 'E0308',
+        message: 'mismatched types',
+        file: '/workspace/project/src/main.rs',
+        line: 3,
+        column: 18,
+        endLine: 3,
+        endColumn: 24,
+        label: 'expected `u32`, found `&str`',
+        suggestions: []
+      };
+      vi.mocked(buildCargoProject).mockResolvedValueOnce({
+        success: false,
+        diagnostics: [diagnostic],
+        error: 'error[E0308]: mismatched types'
+      });
+
+      const error = await adapter
+        .transformLaunchConfig({ program: '/workspace/project/src/main.rs' })
+        .catch((err: unknown) => err);
+
+      expect(error).toBeInstanceOf(BuildFailedError);
+      expect((error as BuildFailedError).code).toBe(AdapterErrorCode.BUILD_FAILED);
+      expect((error as BuildFailedError).message).toBe(
+        'Cargo build failed: 1 error: E0308 mismatched types at /workspace/project/src/main.rs:3:18'
+      );
+      expect((error as BuildFailedError).diagnostics).toEqual([diagnostic]);
+      expect((error as BuildFailedError).output).toBe('error[E0308]: mismatched types');
+    });
   });
 
   describe('validateToolchain', () => {
@@ -43,6 +43,7 @@ const createMockProcess = (options: {
     stdoutChunks.forEach(chunk => stdout.emit('data', chunk));
     stderrChunks.forEach(chunk => stderr.emit('data', chunk));
     proc.emit('exit', exitCode);
+    proc.emit('close', exitCode);
   });
 
   return proc;
@@ -146,6 +147,24 @@ describe('rust-utils process checks', () => {
     spawnMock.mockImplementation(() => createMockProcess({ stdoutChunks: [], exitCode: 0 }));
     await expect(rustUtils.getRustHostTriple()).resolves.toBeNull();
   });
+
+  it('retrieves the toolchain sysroot from the project directory', async () => {
+    spawnMock.mockImplementation(() =>
+      createMockProcess({ stdoutChunks: ['/home/me/.rustup/toolchains/stable-x86_64-unknown-linux-gnu\n'], exitCode: 0 })
+    );
+
+    await expect(rustUtils.getRustSysroot('/workspace/project')).resolves.toBe(
+      '/home/me/.rustup/toolchains/stable-x86_64-unknown-linux-gnu'
+    );
+    expect(spawnMock).toHaveBeenLastCalledWith(
+      'rustc',
+      ['--print', 'sysroot'],
+      expect.objectContaining({ cwd: '/workspace/project' })
+    );
+
+    spawnMock.mockImplementation(() => createMockProcess({ exitCode: 1 }));
+    await expect(rustUtils.getRustSysroot()).resolves.toBeNull();
+  });
 });
 
 describe('rust-utils filesystem helpers', () => {
@@ -166,6 +166,18 @@ async function bundleCLI() {
     console.warn('Run: pnpm -w -F @debugmcp/adapter-rust run build:adapter');
   }
 
+  const rustScriptsSrc = path.join(repoRoot, 'packages/adapter-rust/lldb');
+  if (fs.existsSync(rustScriptsSrc)) {
+    fs.cpSync(rustScriptsSrc, path.join(distDir, 'lldb'), {
+      recursive: true,
+      filter: (src) =>
+        fs.statSync(src).isDirectory() ||
+        src.endsWith('.py') ||
+        ['lldb_commands', 'LICENSE-MIT', 'LICENSE-APACHE'].includes(path.basename(src))
+    });
+    console.log('Copied Rust LLDB scripts and vendored pretty-printers.');
+  }
+
   // Mirror dist into the package/ directory used by npm pack artifacts.
   const packageDir = path.join(packageRoot, 'package');
   const packageDistDir = path.join(packageDir, 'dist');
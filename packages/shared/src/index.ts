@@ -29,6 +29,8 @@ export type {
   // Launch configurations
   GenericLaunchConfig,
   LanguageSpecificLaunchConfig,
+  LaunchProgress,
+  LaunchPreparationOptions,
 
   // Features
   FeatureRequirement,
@@ -38,7 +40,11 @@ export type {
   AdapterEvents,
 
   // Migration
-  ConfigMigration
+  ConfigMigration,
+
+  // Build failures
+  CompilerDiagnostic,
+  CompilerSuggestion
 } from './interfaces/debug-adapter.js';
 
 // Debug Adapter interfaces - Values (enums and classes)
@@ -51,7 +57,8 @@ export {
 
   // Error class and enum
   AdapterError,
-  AdapterErrorCode
+  AdapterErrorCode,
+  BuildFailedError
 } from './interfaces/debug-adapter.js';
 
 // Adapter Registry interfaces - Types
@@ -140,13 +147,26 @@ export type {
   // Session types
   SessionConfig,
   Breakpoint,
+  DataBreakpoint,
+  DataBreakpointAccessType,
+  FunctionBreakpoint,
+  InstructionBreakpoint,
+  SteppingGranularity,
+  StepInTarget,
+  BreakpointLocation,
   DebugSession,
   DebugSessionInfo,
 
   // Debug info types
   Variable,
   StackFrame,
-  DebugLocation
+  AsyncBody,
+  AsyncTaskState,
+  AsyncTaskInfo,
+  AsyncRuntimeInfo,
+  DebugLocation,
+  ExceptionStopInfo,
+  DisassembledInstruction
 } from './models/index.js';
 
 // Model values (enums and functions)
@@ -159,7 +179,10 @@ export {
 
   // State mapping functions
   mapLegacyState,
-  mapToLegacyState
+  mapToLegacyState,
+
+  // Stack frames
+  COLLAPSED_FRAME_ID
 } from './models/index.js';
 
 // ===== Factories =====
@@ -181,6 +204,9 @@ export type { RustAdapterPolicyInterface } from './interfaces/adapter-policy-rus
 export { MockAdapterPolicy } from './interfaces/adapter-policy-mock.js';
 export { ZigAdapterPolicy } from './interfaces/adapter-policy-zig.js';
 
+// Target architecture layout helpers
+export { getTargetPointerSize, isTargetBigEndian } from './interfaces/target-architecture.js';
+
 // DAP Client Behavior interfaces for adapter policies
 export type {
   DapClientBehavior,
@@ -7,8 +7,486 @@ import type { DebugProtocol } from '@vscode/debugprotocol';
 import * as path from 'path';
 import type { AdapterPolicy, AdapterSpecificState, CommandHandling } from './adapter-policy.js';
 import { SessionState } from '@debugmcp/shared';
-import type { StackFrame, Variable } from '../models/index.js';
+import { COLLAPSED_FRAME_ID } from '../models/index.js';
+import type {
+  AsyncBody,
+  AsyncRuntimeInfo,
+  AsyncTaskInfo,
+  StackFrame,
+  Variable,
+  BreakpointLocation
+} from '../models/index.js';
 import type { DapClientBehavior, DapClientContext, ReverseRequestResult } from './dap-client-behavior.js';
+import { getTargetPointerSize } from './target-architecture.js';
+
+/** Width and signedness of Rust primitive integers; isize/usize are as wide as the target's pointers */
+const RUST_INTEGER_TYPES: Record<string, { bits: number | 'pointer'; signed: boolean }> = {
+  i8: { bits: 8, signed: true },
+  i16: { bits: 16, signed: true },
+  i32: { bits: 32, signed: true },
+  i64: { bits: 64, signed: true },
+  i128: { bits: 128, signed: true },
+  isize: { bits: 'pointer', signed: true },
+  u8: { bits: 8, signed: false },
+  u16: { bits: 16, signed: false },
+  u32: { bits: 32, signed: false },
+  u64: { bits: 64, signed: false },
+  u128: { bits: 128, signed: false },
+  usize: { bits: 'pointer', signed: false }
+};
+
+const RUST_CHAR_ESCAPES: Record<string, number> = {
+  '\\n': 10,
+  '\\r': 13,
+  '\\t': 9,
+  '\\0': 0,
+  '\\\\': 92,
+  "\\'": 39,
+  '\\"': 34
+};
+
+const RUST_STRING_TYPES = new Set(['&str', '&mut str', 'alloc::string::String', 'String']);
+
+/**
+ * Trailing path segment naming an async body, e.g. `app::fetch::{async_fn#0}`
+ * (rustc before 1.73 named all of them `{generator#0}`, later `{coroutine#0}`)
+ */
+const RUST_ASYNC_BODY = /::\{(async_fn|async_block|async_closure|generator|coroutine)#\d+\}(<.*>)?$/;
+
+/** Executors, task harnesses and poll glue between async bodies */
+const RUST_EXECUTOR_PREFIXES = [
+  'tokio::runtime::',
+  'tokio::task::coop::',
+  'tokio::loom::',
+  'tokio::future::',
+  'futures_executor::',
+  'futures_util::future::',
+  'async_executor::',
+  'async_task::',
+  'async_std::task::',
+  'core::future::',
+  'std::future::'
+];
+
+const RUST_STD_PREFIXES = ['std::', 'core::', 'alloc::', '__rust_', '__libc_start', '_start'];
+
+/** Frame name without the `<` of a qualified path like `<T as Trait>::poll` */
+function rustFrameItem(name: string): string {
+  return name.startsWith('<') ? name.slice(1) : name;
+}
+
+function getRustAsyncBody(name: string): AsyncBody | undefined {
+  const match = RUST_ASYNC_BODY.exec(name);
+  if (!match) {
+    return undefined;
+  }
+  const kind = match[1] === 'async_block' ? 'block' : match[1] === 'async_closure' ? 'closure' : 'fn';
+  return { kind, function: name.slice(0, match.index) };
+}
+
+function isRustExecutorFrame(frame: StackFrame): boolean {
+  const item = rustFrameItem(frame.name || '');
+  return RUST_EXECUTOR_PREFIXES.some(prefix => item.startsWith(prefix));
+}
+
+function isRustInternalFrame(frame: StackFrame): boolean {
+  if (isRustExecutorFrame(frame)) {
+    return true;
+  }
+  const item = rustFrameItem(frame.name || '');
+  if (RUST_STD_PREFIXES.some(prefix => item.startsWith(prefix))) {
+    return true;
+  }
+  // No debug info (libc, the C `main`) or standard library sources
+  const file = (frame.file || '').replace(/\\/g, '/');
+  return !file || file === '<unknown_source>' ||
+    file.includes('/rustc/') || file.includes('/library/std/') || file.includes('/library/core/');
+}
+
+function withRustAsyncBody(frame: StackFrame): StackFrame {
+  const asyncBody = frame.collapsedFrames === undefined ? getRustAsyncBody(frame.name || '') : undefined;
+  return asyncBody ? { ...frame, asyncBody } : frame;
+}
+
+/**
+ * Placeholder for a run of runtime frames, named after what drives the
+ * async body above it. It has no frame in the adapter, hence the sentinel ID.
+ */
+function collapseRustFrames(run: StackFrame[]): StackFrame {
+  const names = run.map(frame => frame.name || '');
+  const label = names.some(name => name.includes('tokio::runtime::task::'))
+    ? 'tokio task'
+    : names.some(name => name.includes('block_on'))
+      ? 'block_on'
+      : 'poll';
+  return {
+    id: COLLAPSED_FRAME_ID,
+    name: `[${label}: ${run.length} frame${run.length === 1 ? '' : 's'}]`,
+    file: run[0].file,
+    line: run[0].line,
+    collapsedFrames: run.length
+  };
+}
+
+/**
+ * Replace a leading qualified path with its self type:
+ * `<Foo as Trait>::method` and `<Foo>::method` become `Foo::method`
+ */
+function unqualifyRustPath(itemPath: string): string {
+  if (!itemPath.startsWith('<')) {
+    return itemPath;
+  }
+  let depth = 0;
+  let asIndex = -1;
+  for (let i = 0; i < itemPath.length; i++) {
+    const ch = itemPath[i];
+    if (ch === '<') {
+      depth++;
+    } else if (ch === '>') {
+      depth--;
+      if (depth === 0) {
+        const selfType = itemPath.slice(1, asIndex === -1 ? i : asIndex).trim();
+        return selfType + itemPath.slice(i + 1);
+      }
+    } else if (depth === 1 && asIndex === -1 && itemPath.startsWith(' as ', i)) {
+      asIndex = i;
+    }
+  }
+  return itemPath;
+}
+
+/**
+ * Type of the state machine behind an async body, e.g. `app::fetch::{async_fn_env#0}`
+ */
+const RUST_FUTURE_ENV = /::\{(async_fn|async_block|async_closure|generator|coroutine)_env#\d+\}(<.*>)?$/;
+
+/** Tokio task state bits (tokio::runtime::task::state) */
+const TOKIO_RUNNING = 1 << 0;
+const TOKIO_COMPLETE = 1 << 1;
+const TOKIO_NOTIFIED = 1 << 2;
+const TOKIO_CANCELLED = 1 << 5;
+
+/** A task as printed by the bundled `tokio-tasks` LLDB command */
+interface TokioTaskOutput {
+  id: number;
+  state: number;
+  spawnLocation?: { file: string; line: number; column?: number } | null;
+  stage?: string | null;
+  futures?: Array<{ type: string; variant?: string }>;
+}
+
+/** Readable name of a future type: the async fn, or the function containing an async block */
+function describeRustFuture(type: string): string {
+  const match = RUST_FUTURE_ENV.exec(type);
+  if (!match) {
+    return type;
+  }
+  const owner = type.slice(0, match.index);
+  return match[1] === 'async_block' ? `async block in ${owner}`
+    : match[1] === 'async_closure' ? `async closure in ${owner}`
+    : owner;
+}
+
+/**
+ * Whether a variable holds an async state machine, or is the future an async
+ * body is awaiting (rustc names it `__awaitee`)
+ */
+function isRustAsyncFuture(variable: Variable): boolean {
+  return variable.name === '__awaitee' ||
+    /\{(async_fn|async_block|async_closure|generator|coroutine)_env#\d+\}/.test(variable.type || '');
+}
+
+function toAsyncTask(task: TokioTaskOutput): AsyncTaskInfo {
+  const state = task.state & TOKIO_COMPLETE ? 'complete'
+    : task.state & TOKIO_RUNNING ? 'running'
+    : task.state & TOKIO_NOTIFIED ? 'notified'
+    : 'idle';
+  const futures = task.futures ?? [];
+  const awaitChain = futures.map(future => describeRustFuture(future.type));
+  return {
+    id: task.id,
+    state,
+    cancelled: (task.state & TOKIO_CANCELLED) !== 0,
+    spawnLocation: task.spawnLocation ?? undefined,
+    future: awaitChain[0],
+    suspendState: futures[0]?.variant,
+    suspendedIn: awaitChain.length > 1 ? awaitChain[awaitChain.length - 1] : undefined,
+    awaitChain
+  };
+}
+
+/** Items shown per collection, and how deep nested std types are expanded, in previews */
+const RUST_PREVIEW_MAX_ITEMS = 3;
+const RUST_PREVIEW_MAX_DEPTH = 3;
+const RUST_PREVIEW_MAX_VALUE_LENGTH = 50;
+
+type RustPreviewKind =
+  | 'vec' | 'vec_deque' | 'slice' | 'map' | 'set' | 'string'
+  | 'option' | 'result' | 'rc' | 'arc' | 'ref_cell' | 'cell' | 'box';
+
+/** std types with a Rust-syntax preview, by the type name LLDB reports */
+const RUST_PREVIEW_TYPES: Array<[RegExp, RustPreviewKind]> = [
+  [/^alloc::vec::Vec</, 'vec'],
+  [/^alloc::collections::vec_deque::VecDeque</, 'vec_deque'],
+  [/^&(mut )?\[.*\]$|^\[.*; \d+\]$/, 'slice'],
+  [/^std::collections::hash::map::HashMap</, 'map'],
+  [/^std::collections::hash::set::HashSet</, 'set'],
+  [/^(alloc::string::String|&(mut )?str)$/, 'string'],
+  [/^core::option::Option</, 'option'],
+  [/^core::result::Result</, 'result'],
+  [/^alloc::rc::Rc</, 'rc'],
+  [/^alloc::sync::Arc</, 'arc'],
+  [/^core::cell::RefCell</, 'ref_cell'],
+  [/^core::cell::Cell</, 'cell'],
+  [/^alloc::boxed::Box</, 'box']
+];
+
+type RustChildren = (variablesReference: number) => Promise<DebugProtocol.Variable[]>;
+
+function rustPreviewKind(type: string): RustPreviewKind | undefined {
+  // References preview as the value they point to
+  for (const candidate of [type, type.replace(/^&(mut )?/, '')]) {
+    const match = RUST_PREVIEW_TYPES.find(([pattern]) => pattern.test(candidate));
+    if (match) {
+      return match[1];
+    }
+  }
+  return undefined;
+}
+
+/** Elements and entries, which the std formatters name `[0]`, `[1]`... */
+function rustIndexedChildren(children: DebugProtocol.Variable[]): DebugProtocol.Variable[] {
+  return children.filter(child => /^\[\d+\]$/.test(child.name));
+}
+
+/** Fields of a value, without CodeLLDB's `[raw]` view and enum discriminants */
+function rustFields(children: DebugProtocol.Variable[]): DebugProtocol.Variable[] {
+  return children.filter(child => child.name !== '[raw]' && child.name !== '$discr$');
+}
+
+/** Value of an unsigned field, looking through newtypes such as `Cap(usize)` */
+async function rustUnsigned(field: DebugProtocol.Variable, getChildren: RustChildren): Promise<number | undefined> {
+  let value = field.value;
+  if (!/^\d+$/.test(value) && field.variablesReference > 0) {
+    value = rustFields(await getChildren(field.variablesReference))[0]?.value ?? '';
+  }
+  return /^\d+$/.test(value) ? Number(value) : undefined;
+}
+
+/**
+ * Capacity of a Vec from its unformatted fields, which CodeLLDB lists under
+ * `[raw]` (or as the children when the std formatters are not loaded):
+ * `buf: RawVec { inner: RawVecInner { cap, .. } }`, or `RawVec { cap, .. }` before Rust 1.83
+ */
+async function rustVecCapacity(children: DebugProtocol.Variable[], getChildren: RustChildren): Promise<number | undefined> {
+  const raw = children.find(child => child.name === '[raw]');
+  let fields = raw ? await getChildren(raw.variablesReference) : children;
+  for (let hops = 0; hops < 3; hops++) {
+    const cap = fields.find(field => field.name === 'cap');
+    if (cap) {
+      return rustUnsigned(cap, getChildren);
+    }
+    const next = fields.find(field => field.name === 'buf' || field.name === 'inner');
+    if (!next || next.variablesReference <= 0) {
+      return undefined;
+    }
+    fields = await getChildren(next.variablesReference);
+  }
+  return undefined;
+}
+
+/** Element count from the std formatters' `size=N` summary */
+function rustSize(value: string): number | undefined {
+  const match = /^size=(\d+)$/.exec(value);
+  return match ? Number(match[1]) : undefined;
+}
+
+function truncateRustValue(value: string): string {
+  return value.length > RUST_PREVIEW_MAX_VALUE_LENGTH
+    ? `${value.slice(0, RUST_PREVIEW_MAX_VALUE_LENGTH)}…`
+    : value;
+}
+
+/** Preview of a nested value: std types recursively, anything else as the adapter shows it */
+async function previewRustChild(
+  variable: DebugProtocol.Variable,
+  getChildren: RustChildren,
+  depth: number
+): Promise<string> {
+  const kind = rustPreviewKind(variable.type ?? '');
+  if (kind && variable.variablesReference > 0 && depth < RUST_PREVIEW_MAX_DEPTH) {
+    const preview = await previewRustValue(variable, kind, getChildren, depth);
+    if (preview !== undefined) {
+      return preview;
+    }
+  }
+  return truncateRustValue(variable.value || (variable.variablesReference > 0 ? '{...}' : ''));
+}
+
+async function previewRustSequence(
+  variable: DebugProtocol.Variable,
+  kind: RustPreviewKind,
+  getChildren: RustChildren,
+  depth: number
+): Promise<string | undefined> {
+  const children = await getChildren(variable.variablesReference);
+  const items = rustIndexedChildren(children);
+  const lenField = children.find(child => child.name === 'len');
+  const length = rustSize(variable.value)
+    ?? variable.indexedVariables
+    ?? (lenField ? await rustUnsigned(lenField, getChildren) : undefined)
+    ?? (items.length > 0 ? items.length : undefined);
+  if (length === undefined) {
+    return undefined;
+  }
+  const capacity = kind === 'vec' ? await rustVecCapacity(children, getChildren) : undefined;
+  if (items.length === 0 && length > 0) {
+    // Only the header fields are known without the std formatters
+    return kind === 'vec' && capacity !== undefined ? `Vec(len=${length}, capacity=${capacity})` : undefined;
+  }
+
+  const shown = await Promise.all(
+    items.slice(0, RUST_PREVIEW_MAX_ITEMS).map(item => previewRustChild(item, getChildren, depth + 1))
+  );
+  if (length > shown.length) {
+    shown.push(`… (${length} items)`);
+  }
+  const open = kind === 'vec' ? 'vec![' : kind === 'vec_deque' ? 'VecDeque[' : '[';
+  const preview = `${open}${shown.join(', ')}]`;
+  return capacity === undefined ? preview : `${preview} (capacity ${capacity})`;
+}
+
+async function previewRustMap(
+  variable: DebugProtocol.Variable,
+  kind: RustPreviewKind,
+  getChildren: RustChildren,
+  depth: number
+): Promise<string | undefined> {
+  const entries = rustIndexedChildren(await getChildren(variable.variablesReference));
+  const length = rustSize(variable.value) ?? entries.length;
+  if (entries.length === 0 && length > 0) {
+    return undefined;
+  }
+
+  const shown = await Promise.all(entries.slice(0, RUST_PREVIEW_MAX_ITEMS).map(async entry => {
+    if (kind === 'set' || entry.variablesReference <= 0) {
+      return previewRustChild(entry, getChildren, depth + 1);
+    }
+    // Map entries are (key, value) tuples
+    const [key, value] = rustFields(await getChildren(entry.variablesReference));
+    if (!key || !value) {
+      return previewRustChild(entry, getChildren, depth + 1);
+    }
+    return `${await previewRustChild(key, getChildren, depth + 1)}: ${await previewRustChild(value, getChildren, depth + 1)}`;
+  }));
+  if (length > shown.length) {
+    shown.push(`… (${length} entries)`);
+  }
+  return `{${shown.join(', ')}}`;
+}
+
+/**
+ * Option and Result as `Some(5)` / `Err("x")`. The std formatters name the
+ * variant in the summary and list its fields as children; without them LLDB
+ * shows the clang-encoded `$variants$` union, where the active `$variant$N`
+ * is the one whose `$discr$` is N, or else the one without a `$discr$`.
+ */
+async function previewRustEnum(
+  variable: DebugProtocol.Variable,
+  getChildren: RustChildren,
+  depth: number
+): Promise<string | undefined> {
+  const children = await getChildren(variable.variablesReference);
+  let variant = /^([A-Z]\w*)\b/.exec(variable.value)?.[1];
+  let fields = rustFields(children);
+
+  if (children[0]?.name === '$variants$') {
+    let active: DebugProtocol.Variable | undefined;
+    let niche: DebugProtocol.Variable | undefined;
+    for (const candidate of await getChildren(children[0].variablesReference)) {
+      const members = await getChildren(candidate.variablesReference);
+      const discr = members.find(member => member.name === '$discr$');
+      const value = members.find(member => member.name === 'value');
+      if (!discr) {
+        niche ??= value;
+      } else if (candidate.name === `$variant$${await rustUnsigned(discr, getChildren)}`) {
+        active = value;
+        break;
+      }
+    }
+    active ??= niche;
+    if (!active) {
+      return undefined;
+    }
+    variant = /(\w+)\$Variant$/.exec(active.type ?? '')?.[1];
+    fields = active.variablesReference > 0 ? rustFields(await getChildren(active.variablesReference)) : [];
+  }
+
+  if (!variant) {
+    return undefined;
+  }
+  if (fields.length === 0) {
+    return variant;
+  }
+  const values = await Promise.all(fields.map(field => previewRustChild(field, getChildren, depth + 1)));
+  if (fields.every(field => /^(__)?\d+$/.test(field.name))) {
+    return `${variant}(${values.join(', ')})`;
+  }
+  return `${variant} { ${fields.map((field, index) => `${field.name}: ${values[index]}`).join(', ')} }`;
+}
+
+/** Rc, Arc, RefCell, Cell and Box, whose std formatters expose the wrapped value as `value` */
+async function previewRustWrapper(
+  variable: DebugProtocol.Variable,
+  kind: RustPreviewKind,
+  getChildren: RustChildren,
+  depth: number
+): Promise<string | undefined> {
+  const fields = rustFields(await getChildren(variable.variablesReference));
+  // LLDB shows a Box as a pointer whose only child is the pointee
+  const inner = fields.find(field => field.name === 'value') ?? (kind === 'box' && fields.length === 1 ? fields[0] : undefined);
+  if (!inner) {
+    return undefined;
+  }
+  const value = await previewRustChild(inner, getChildren, depth + 1);
+
+  if (kind === 'rc' || kind === 'arc') {
+    const strong = /strong=(\d+)/.exec(variable.value)?.[1];
+    const name = kind === 'arc' ? 'Arc' : 'Rc';
+    return `${name}${strong ? `(strong=${strong})` : ''} -> ${value}`;
+  }
+  if (kind === 'ref_cell') {
+    // The summary is `borrow=N` for shared borrows, `borrow_mut=1` while mutably borrowed
+    const borrow = /^borrow(_mut)?=\d+$/.test(variable.value) && variable.value !== 'borrow=0' ? ` [${variable.value}]` : '';
+    return `RefCell(${value})${borrow}`;
+  }
+  return `${kind === 'cell' ? 'Cell' : 'Box'}(${value})`;
+}
+
+async function previewRustValue(
+  variable: DebugProtocol.Variable,
+  kind: RustPreviewKind,
+  getChildren: RustChildren,
+  depth: number
+): Promise<string | undefined> {
+  switch (kind) {
+    case 'vec':
+    case 'vec_deque':
+    case 'slice':
+      return previewRustSequence(variable, kind, getChildren, depth);
+    case 'map':
+    case 'set':
+      return previewRustMap(variable, kind, getChildren, depth);
+    case 'string':
+      // The std formatters summarize strings as quoted text; their children are the bytes
+      return variable.value.startsWith('"') ? variable.value : undefined;
+    case 'option':
+    case 'result':
+      return previewRustEnum(variable, getChildren, depth);
+    default:
+      return previewRustWrapper(variable, kind, getChildren, depth);
+  }
+}
 
 export interface RustAdapterPolicyInterface {
   requiresCompilation: true;
@@ -71,8 +549,8 @@ export const RustAdapterPolicy: AdapterPolicy = {
       localVars = localVars.filter(v => {
         const name = v.name;
         
-        // Skip LLDB internal variables
-        if (name.startsWith('$') || name.startsWith('__')) {
+        // Skip LLDB internal variables; futures are shown by the async formatter
+        if (name.startsWith('$') || (name.startsWith('__') && !isRustAsyncFuture(v))) {
           return false;
         }
         
@@ -88,6 +566,336 @@ export const RustAdapterPolicy: AdapterPolicy = {
     return localVars;
   },
   
+  /**
+   * Identify frames belonging to std/core panic machinery so a panic stop
+   * can be unwound to the `panic!`/`unwrap()` call site in user code
+   */
+  isPanicFrame: (frame: StackFrame): boolean => {
+    const name = frame.name || '';
+    if (
+      name === 'rust_panic' ||
+      name.startsWith('__rust_') ||
+      name.startsWith('rust_begin_unwind') ||
+      name.includes('std::panicking::') ||
+      name.includes('core::panicking::') ||
+      name.includes('std::panic::') ||
+      name.includes('std::sys::backtrace::') ||
+      name.includes('std::sys_common::backtrace::') ||
+      name.startsWith('core::result::unwrap_failed') ||
+      name.startsWith('core::option::expect_failed') ||
+      name.startsWith('core::option::unwrap_failed') ||
+      /^core::(result::Result|option::Option)<.*>::(unwrap|expect)/.test(name)
+    ) {
+      return true;
+    }
+
+    // Frames from the Rust standard library sources (rustc sysroot or remapped /rustc/<hash>/ paths)
+    const file = (frame.file || '').replace(/\\/g, '/');
+    return file.includes('/rustc/') || file.includes('/library/std/') || file.includes('/library/core/');
+  },
+
+  /**
+   * Standard library, async executor and poll glue frames, and frames
+   * without debug info
+   */
+  isInternalFrame: (frame: StackFrame): boolean => isRustInternalFrame(frame),
+
+  /**
+   * Mark async bodies and, unless internals are requested, drop internal
+   * frames. The top frame is always kept: it is where the program stopped.
+   */
+  filterStackFrames: (frames: StackFrame[], includeInternals: boolean): StackFrame[] => {
+    const marked = frames.map(withRustAsyncBody);
+    if (includeInternals) {
+      return marked;
+    }
+    return marked.filter((frame, index) =>
+      index === 0 || frame.collapsedFrames !== undefined || !isRustInternalFrame(frame)
+    );
+  },
+
+  /**
+   * Async stack: the async bodies from the innermost to the one the executor
+   * polls, each awaiting the next. Runs of runtime frames between bodies
+   * become one placeholder, and everything below the outermost body (task
+   * harness, block_on, thread start) another.
+   */
+  collapseAsyncFrames: (frames: StackFrame[]): StackFrame[] => {
+    const marked = frames.map(withRustAsyncBody);
+    const outermost = marked.map(frame => frame.asyncBody !== undefined).lastIndexOf(true);
+    if (outermost === -1) {
+      return marked;
+    }
+
+    const result: StackFrame[] = [];
+    const firstBody = marked.findIndex(frame => frame.asyncBody !== undefined);
+    // Synchronous code called from the innermost body is kept as is
+    result.push(...marked.slice(0, firstBody));
+    let run: StackFrame[] = [];
+    for (const frame of marked.slice(firstBody, outermost + 1)) {
+      if (!frame.asyncBody && isRustInternalFrame(frame)) {
+        run.push(frame);
+        continue;
+      }
+      if (run.length > 0) {
+        result.push(collapseRustFrames(run));
+        run = [];
+      }
+      result.push(frame);
+    }
+    if (outermost + 1 < marked.length) {
+      result.push(collapseRustFrames(marked.slice(outermost + 1)));
+    }
+    return result;
+  },
+
+  /**
+   * LLDB watchpoints use CPU debug registers of the debugged program's
+   * architecture: x86/x86-64 has four, and AArch64 cores commonly expose four
+   * as well. Unknown for other or unreported architectures.
+   */
+  getHardwareWatchpointLimit: (targetArchitecture?: string): number | undefined => {
+    if (!targetArchitecture) {
+      return undefined;
+    }
+    return /^(x86_64|x86|i[3-6]86|aarch64|arm64)$/.test(targetArchitecture) ? 4 : undefined;
+  },
+
+  /**
+   * Turn a Rust item path into an LLDB function name. LLDB matches trailing
+   * path components, so `crate::` is dropped, trait-qualified paths keep
+   * their self type (`<Foo as Trait>::method` -> `Foo::method`), and generic
+   * arguments are removed so every monomorphized instance is matched.
+   */
+  normalizeFunctionBreakpointName: (name: string): string => {
+    let normalized = unqualifyRustPath(name.trim().replace(/\(\)$/, ''));
+    normalized = normalized.replace(/^crate::/, '');
+
+    // Drop generic arguments (including turbofish), handling nesting
+    let result = '';
+    let depth = 0;
+    for (const ch of normalized) {
+      if (ch === '<') {
+        depth++;
+      } else if (ch === '>' && depth > 0) {
+        depth--;
+      } else if (depth === 0) {
+        result += ch;
+      }
+    }
+    return result.replace(/::$/, '').replace(/::::/g, '::');
+  },
+
+  /**
+   * CodeLLDB hit conditions: `N` (same as `>= N`), `== N`/`= N`, `> N`, `>= N`, `< N`, `<= N`, `% N`
+   */
+  validateHitCondition: (hitCondition: string): string | undefined => {
+    const match = /^\s*(==|=|>=|>|<=|<|%)?\s*(\d+)\s*$/.exec(hitCondition);
+    if (!match) {
+      return `Invalid hit condition '${hitCondition}'. Use a count with an optional operator: '== 5', '>= 3', '< 10' or '% 2'`;
+    }
+    if (match[1] === '%' && parseInt(match[2], 10) === 0) {
+      return `Invalid hit condition '${hitCondition}': '% 0' would never stop`;
+    }
+    return undefined;
+  },
+
+  /**
+   * Check values against the Rust primitive type CodeLLDB reports and rewrite
+   * Rust literal syntax (`1_000`, `0o17`, `5u8`, `'x'`) into what LLDB parses
+   */
+  prepareVariableValue: (
+    type: string,
+    value: string,
+    targetArchitecture?: string
+  ): { value: string } | { error: string } => {
+    const text = value.trim();
+    const rustType = type.trim();
+    if (text.length === 0) {
+      return { error: 'Value must not be empty' };
+    }
+
+    const integer = RUST_INTEGER_TYPES[rustType];
+    if (integer) {
+      // A radix prefix needs at least one digit: `0x_` is not a literal
+      const match = /^(-)?(0x_*[0-9a-f][0-9a-f_]*|0o_*[0-7][0-7_]*|0b_*[01][01_]*|\d[\d_]*)([iu](?:8|16|32|64|128|size))?$/i.exec(text);
+      if (!match) {
+        return { error: `'${value}' is not a valid ${rustType} literal` };
+      }
+      if (match[3] && match[3] !== rustType) {
+        return { error: `'${value}' is a ${match[3]} literal, but the variable is ${rustType}` };
+      }
+      const magnitude = BigInt(match[2].replace(/_/g, ''));
+      const parsed = match[1] ? -magnitude : magnitude;
+      const bits = BigInt(integer.bits === 'pointer' ? getTargetPointerSize(targetArchitecture) * 8 : integer.bits);
+      const min = integer.signed ? -(1n << (bits - 1n)) : 0n;
+      const max = integer.signed ? (1n << (bits - 1n)) - 1n : (1n << bits) - 1n;
+      if (parsed < min || parsed > max) {
+        return { error: `${text} is out of range for ${rustType} (${min}..=${max})` };
+      }
+      return { value: parsed.toString() };
+    }
+
+    if (rustType === 'bool') {
+      return text === 'true' || text === 'false'
+        ? { value: text }
+        : { error: `'${value}' is not a valid bool; use true or false` };
+    }
+
+    if (rustType === 'f32' || rustType === 'f64') {
+      const match = /^(-?\d[\d_]*(?:\.[\d_]*)?(?:e[+-]?\d+)?)(?:f32|f64)?$/i.exec(text);
+      if (!match) {
+        return { error: `'${value}' is not a valid ${rustType} literal` };
+      }
+      const parsed = Number(match[1].replace(/_/g, ''));
+      if (!Number.isFinite(parsed) || (rustType === 'f32' && Math.abs(parsed) > 3.4028234663852886e38)) {
+        return { error: `${text} is out of range for ${rustType}` };
+      }
+      return { value: String(parsed) };
+    }
+
+    if (rustType === 'char') {
+      const match = /^'(.+)'$/su.exec(text);
+      let codePoint: number | undefined;
+      if (match) {
+        const body = match[1];
+        const unicode = /^\\u\{([0-9a-f]{1,6})\}$/i.exec(body);
+        if (RUST_CHAR_ESCAPES[body] !== undefined) {
+          codePoint = RUST_CHAR_ESCAPES[body];
+        } else if (unicode) {
+          codePoint = parseInt(unicode[1], 16);
+        } else if (Array.from(body).length === 1) {
+          codePoint = body.codePointAt(0);
+        }
+      }
+      if (codePoint === undefined || codePoint > 0x10ffff || (codePoint >= 0xd800 && codePoint <= 0xdfff)) {
+        return { error: `${text} is not a valid char literal; use a quoted character such as 'a' or '\\u{1F600}'` };
+      }
+      // LLDB stores Rust chars as u32 code points
+      return { value: String(codePoint) };
+    }
+
+    if (RUST_STRING_TYPES.has(rustType)) {
+      return /^"(?:[^"\\]|\\.)*"$/s.test(text)
+        ? { value: text }
+        : { error: `${rustType} values must be a double-quoted string literal, e.g. "hello"` };
+    }
+
+    // Structs, enums, pointers etc.: let CodeLLDB decide
+    return { value: text };
+  },
+
+  /**
+   * CodeLLDB reports a single location per DAP breakpoint, so ask LLDB directly
+   */
+  getBreakpointLocationsCommand: (breakpointId: number): string => {
+    return `breakpoint list ${breakpointId}`;
+  },
+
+  /**
+   * Parse `breakpoint list <id>` output, e.g.
+   *   1.2: where = app`app::sum::h1a2b3c4d5e6f7a8b + 20 at main.rs:27:5, address = 0x0000555555559a34, resolved, hit count = 0
+   */
+  parseBreakpointLocations: (output: string): BreakpointLocation[] => {
+    const locations: BreakpointLocation[] = [];
+    const pattern = /^\s*\d+\.\d+:\s+where = (?:[^`\s]*`)?(.+?)(?: \+ \d+)?(?: at (.+?):(\d+)(?::(\d+))?)?, address = (0x[0-9a-fA-F]+)(?:, (resolved|unresolved))?/;
+    for (const line of output.split(/\r?\n/)) {
+      const match = pattern.exec(line);
+      if (!match) {
+        continue;
+      }
+      const [, fn, file, lineNo, column, address, state] = match;
+      locations.push({
+        // Strip the legacy-mangling hash suffix (::h0123456789abcdef)
+        function: fn.replace(/::h[0-9a-f]{16}$/, ''),
+        file,
+        line: lineNo ? parseInt(lineNo, 10) : undefined,
+        column: column ? parseInt(column, 10) : undefined,
+        address,
+        verified: state !== 'unresolved'
+      });
+    }
+    return locations;
+  },
+
+  /**
+   * LLDB keeps a hit count per breakpoint; `breakpoint list` prints them all
+   */
+  getBreakpointHitCountsCommand: (): string => {
+    return 'breakpoint list';
+  },
+
+  /**
+   * Parse the top-level entries of `breakpoint list`, e.g.
+   *   1: file = '/work/src/main.rs', line = 8, exact_match = 0, locations = 1, resolved = 1, hit count = 5
+   *   2: name = 'app::largest', locations = 2, resolved = 2, hit count = 0
+   */
+  parseBreakpointHitCounts: (output: string): Array<{ file?: string; line?: number; name?: string; hitCount: number }> => {
+    const counts: Array<{ file?: string; line?: number; name?: string; hitCount: number }> = [];
+    const pattern = /^\d+: (?:file = '(.+?)', line = (\d+)|name = '(.+?)')?.*?hit count = (\d+)/;
+    for (const line of output.split(/\r?\n/)) {
+      const match = pattern.exec(line);
+      if (!match) {
+        continue;
+      }
+      const [, file, lineNo, name, hitCount] = match;
+      counts.push({
+        file,
+        line: lineNo ? parseInt(lineNo, 10) : undefined,
+        name,
+        hitCount: parseInt(hitCount, 10)
+      });
+    }
+    return counts;
+  },
+
+  /**
+   * Preview Vec, HashMap, String, Option, Result, Rc/Arc, RefCell, Cell and Box
+   * in Rust syntax instead of as their synthetic children
+   */
+  buildValuePreview: async (
+    value: DebugProtocol.Variable,
+    getChildren: (variablesReference: number) => Promise<DebugProtocol.Variable[]>
+  ): Promise<string | undefined> => {
+    const kind = rustPreviewKind(value.type ?? '');
+    if (!kind || value.variablesReference <= 0) {
+      return undefined;
+    }
+    return previewRustValue(value, kind, getChildren, 0);
+  },
+
+  /**
+   * Provided by lldb/tokio_tasks.py, which the Rust adapter imports into every session
+   */
+  getAsyncTasksCommand: (): string => 'tokio-tasks',
+
+  /**
+   * Parse the JSON line printed by `tokio-tasks`
+   */
+  parseAsyncTasks: (output: string): { runtimes: AsyncRuntimeInfo[] } | { error: string } => {
+    const line = output.split(/\r?\n/).map(text => text.trim()).reverse().find(text => text.startsWith('{'));
+    if (!line) {
+      return /not a valid command/.test(output)
+        ? { error: 'The tokio-tasks LLDB command is not loaded in this session' }
+        : { error: output.trim() || 'No output from tokio-tasks' };
+    }
+    let parsed: { runtimes?: Array<{ flavor: string; tasks: TokioTaskOutput[] }>; error?: string };
+    try {
+      parsed = JSON.parse(line);
+    } catch {
+      return { error: `Unexpected tokio-tasks output: ${line}` };
+    }
+    if (parsed.error || !parsed.runtimes) {
+      return { error: parsed.error ?? 'No runtimes in tokio-tasks output' };
+    }
+    return {
+      runtimes: parsed.runtimes.map(runtime => ({
+        flavor: runtime.flavor,
+        tasks: runtime.tasks.map(toAsyncTask)
+      }))
+    };
+  },
+
   /**
    * Rust/CodeLLDB uses "Local" or "Locals" for local variables scope
    */
@@ -13,7 +13,7 @@
  * @since 2.1.0
  */
 import type { DebugProtocol } from '@vscode/debugprotocol';
-import type { StackFrame, Variable } from '../models/index.js';
+import type { StackFrame, Variable, BreakpointLocation, AsyncRuntimeInfo } from '../models/index.js';
 import type { DapClientBehavior } from './dap-client-behavior.js';
 import type { SessionState } from '@debugmcp/shared';
 import type { LanguageSpecificLaunchConfig } from './debug-adapter.js';
@@ -100,6 +100,105 @@ export interface AdapterPolicy {
    */
   isInternalFrame?(frame: StackFrame): boolean;
 
+  /**
+   * Rebuild the logical async call stack: executor and poll frames are folded
+   * into placeholder frames (with `collapsedFrames` set), leaving the chain of
+   * async bodies awaiting each other. Applied before filterStackFrames.
+   *
+   * @param frames The original stack frames from the debug adapter
+   * @returns The frames with executor frames collapsed
+   */
+  collapseAsyncFrames?(frames: StackFrame[]): StackFrame[];
+
+  /**
+   * Check if a stack frame belongs to the language runtime's panic/exception
+   * raising machinery (e.g. Rust's std::panicking). Used to unwind to the
+   * user-code location when the debuggee stops on an exception breakpoint.
+   *
+   * @param frame The stack frame to check
+   * @returns True if the frame is part of the panic/unwind machinery
+   */
+  isPanicFrame?(frame: StackFrame): boolean;
+
+  /**
+   * Number of hardware watchpoint slots available to data breakpoints.
+   * Used to report how many more variables can be watched.
+   *
+   * @param targetArchitecture Architecture of the debugged program, from
+   *   IDebugAdapter.getTargetArchitecture
+   * @returns The slot count, or undefined if unknown/unlimited
+   */
+  getHardwareWatchpointLimit?(targetArchitecture?: string): number | undefined;
+
+  /**
+   * Normalize a user-supplied function name into what the debugger matches on
+   * (e.g. strip generic arguments so every instance is matched).
+   */
+  normalizeFunctionBreakpointName?(name: string): string;
+
+  /**
+   * Validate a hit-count condition in the adapter's syntax.
+   *
+   * @returns An error message, or undefined if the hit condition is valid
+   */
+  validateHitCondition?(hitCondition: string): string | undefined;
+
+  /**
+   * Check a new value for a variable of the given debugger-reported type and
+   * convert it to the syntax the debug adapter accepts in setVariable.
+   *
+   * @param targetArchitecture Architecture of the debugged program, from
+   *   IDebugAdapter.getTargetArchitecture, for pointer-sized types
+   * @returns The value to send, or an error message
+   */
+  prepareVariableValue?(type: string, value: string, targetArchitecture?: string): { value: string } | { error: string };
+
+  /**
+   * Render the one-line preview of an evaluated value in the language's own
+   * syntax, e.g. `vec![1, 2, 3]` for a Rust Vec. `getChildren` expands a
+   * variablesReference through the debug adapter.
+   *
+   * @returns The preview, or undefined to use the generic array/object preview
+   */
+  buildValuePreview?(
+    value: DebugProtocol.Variable,
+    getChildren: (variablesReference: number) => Promise<DebugProtocol.Variable[]>
+  ): Promise<string | undefined>;
+
+  /**
+   * REPL command that lists the resolved locations of an adapter breakpoint.
+   * The output is parsed with parseBreakpointLocations.
+   */
+  getBreakpointLocationsCommand?(breakpointId: number): string;
+
+  /**
+   * Parse the output of getBreakpointLocationsCommand into locations
+   */
+  parseBreakpointLocations?(output: string): BreakpointLocation[];
+
+  /**
+   * REPL command that reports how often each breakpoint was hit.
+   * The output is parsed with parseBreakpointHitCounts.
+   */
+  getBreakpointHitCountsCommand?(): string;
+
+  /**
+   * Parse the output of getBreakpointHitCountsCommand into per-breakpoint hit counts
+   */
+  parseBreakpointHitCounts?(output: string): Array<{ file?: string; line?: number; name?: string; hitCount: number }>;
+
+  /**
+   * REPL command that lists the tasks of the debuggee's async runtimes.
+   * The output is parsed with parseAsyncTasks.
+   */
+  getAsyncTasksCommand?(): string;
+
+  /**
+   * Parse the output of getAsyncTasksCommand into runtimes and their tasks,
+   * or the reason the tasks could not be listed
+   */
+  parseAsyncTasks?(output: string): { runtimes: AsyncRuntimeInfo[] } | { error: string };
+
   /**
    * Extract local variables from the raw DAP data based on language-specific logic.
    * This allows each language adapter to define what constitutes "local variables".
@@ -115,10 +115,14 @@ export interface IDebugAdapter extends EventEmitter {
   /**
    * Transform generic launch config to language-specific format
    * 
+   * @param options Progress reporting and cancellation for builds run while preparing the launch
    * @returns Promise resolving to language-specific launch configuration
    * @since 2.1.0 - Made async to support build operations (e.g., Rust compilation)
    */
-  transformLaunchConfig(config: GenericLaunchConfig): Promise<LanguageSpecificLaunchConfig>;
+  transformLaunchConfig(
+    config: GenericLaunchConfig,
+    options?: LaunchPreparationOptions
+  ): Promise<LanguageSpecificLaunchConfig>;
   
   /**
    * Get default launch configuration for this language
@@ -191,6 +195,19 @@ export interface IDebugAdapter extends EventEmitter {
    */
   getFeatureRequirements(feature: DebugFeature): FeatureRequirement[];
   
+  /**
+   * Check a feature against the installed debugger, e.g. a minimum adapter
+   * version. Optional; adapters without it are assumed to provide what
+   * supportsFeature reports.
+   */
+  checkFeatureSupport?(feature: DebugFeature): Promise<{ supported: boolean; reason?: string }>;
+  
+  /**
+   * Architecture of the program a launch config debugs, as the first part of
+   * a target triple (`x86_64`, `aarch64`, ...); undefined when unknown
+   */
+  getTargetArchitecture?(launchConfig: LanguageSpecificLaunchConfig): Promise<string | undefined>;
+  
   /**
    * Get full capability declaration
    */
@@ -291,6 +308,26 @@ export interface LanguageSpecificLaunchConfig extends GenericLaunchConfig {
   [key: string]: unknown;
 }
 
+/**
+ * Progress of work done while preparing a launch, such as compiling the program
+ */
+export interface LaunchProgress {
+  /** Work done so far (e.g. compiled units); increases with every report */
+  progress: number;
+  /** Total amount of work, when known */
+  total?: number;
+  message?: string;
+}
+
+/**
+ * Hooks for long-running work in transformLaunchConfig
+ */
+export interface LaunchPreparationOptions {
+  onProgress?: (progress: LaunchProgress) => void;
+  /** Aborted when the client cancels; adapters stop any build they started */
+  signal?: AbortSignal;
+}
+
 
 /**
  * Debug features enumeration (from DAP spec)
@@ -315,7 +352,8 @@ export enum DebugFeature {
   EXCEPTION_INFO_REQUEST = 'exceptionInfoRequest',
   STEP_BACK = 'stepBack',
   REVERSE_DEBUGGING = 'reverseDebugging',
-  STEP_IN_TARGETS_REQUEST = 'stepInTargetsRequest'
+  STEP_IN_TARGETS_REQUEST = 'stepInTargetsRequest',
+  STEPPING_GRANULARITY = 'steppingGranularity'
 }
 
 /**
@@ -400,6 +438,56 @@ export class AdapterError extends Error {
   }
 }
 
+/**
+ * A source edit suggested by the compiler
+ */
+export interface CompilerSuggestion {
+  message: string;
+  file: string;
+  line: number;
+  column: number;
+  endLine: number;
+  endColumn: number;
+  replacement: string;
+  /** e.g. 'MachineApplicable', 'MaybeIncorrect' */
+  applicability?: string;
+}
+
+/**
+ * A compiler error or warning reported by a failed build
+ */
+export interface CompilerDiagnostic {
+  /** 'error', 'warning', ... */
+  level: string;
+  /** Error code such as E0308 */
+  code?: string;
+  message: string;
+  /** Location of the primary span */
+  file?: string;
+  line?: number;
+  column?: number;
+  endLine?: number;
+  endColumn?: number;
+  /** Label of the primary span, e.g. "expected `u32`, found `&str`" */
+  label?: string;
+  suggestions: CompilerSuggestion[];
+}
+
+/**
+ * Building the debug target failed; carries the compiler's diagnostics
+ */
+export class BuildFailedError extends AdapterError {
+  constructor(
+    message: string,
+    public diagnostics: CompilerDiagnostic[],
+    /** Build output, for failures without diagnostics (linker, build script) */
+    public output?: string
+  ) {
+    super(message, AdapterErrorCode.BUILD_FAILED, true);
+    this.name = 'BuildFailedError';
+  }
+}
+
 /**
  * Adapter error codes
  */
@@ -423,6 +511,7 @@ export enum AdapterErrorCode {
   DEBUGGER_ERROR = 'DEBUGGER_ERROR',
   SCRIPT_NOT_FOUND = 'SCRIPT_NOT_FOUND',
   PERMISSION_DENIED = 'PERMISSION_DENIED',
+  BUILD_FAILED = 'BUILD_FAILED',
   
   // Generic errors
   UNKNOWN_ERROR = 'UNKNOWN_ERROR'
@@ -0,0 +1,27 @@
+/**
+ * Layout facts about the debugged program's architecture, as reported by
+ * `IDebugAdapter.getTargetArchitecture` (the first component of a target
+ * triple, e.g. `x86_64`, `i686`, `armv7`, `wasm32`).
+ */
+
+/** Architectures with 4-byte pointers */
+const TARGET_32_BIT = /^(x86|i[3-6]86|arm(eb|v\d+\w*)?|thumb\w*|wasm32|riscv32\w*|mips(el)?|mipsisa32\w*|powerpc|sparc|hexagon|m68k|csky|xtensa)$/;
+
+/** Architectures that store multi-byte values most significant byte first */
+const TARGET_BIG_ENDIAN = /^(powerpc(64)?|mips(64)?|mipsisa(32|64)r6|s390x|sparc(64|v9)?|m68k|aarch64_be|\w+eb)$/;
+
+/**
+ * Pointer (and isize/usize) width in bytes. Unknown architectures are
+ * assumed to be 64-bit.
+ */
+export function getTargetPointerSize(targetArchitecture?: string): 4 | 8 {
+  return targetArchitecture && TARGET_32_BIT.test(targetArchitecture) ? 4 : 8;
+}
+
+/**
+ * Whether the architecture is big-endian. Unknown architectures are assumed
+ * to be little-endian.
+ */
+export function isTargetBigEndian(targetArchitecture?: string): boolean {
+  return targetArchitecture !== undefined && TARGET_BIG_ENDIAN.test(targetArchitecture);
+}
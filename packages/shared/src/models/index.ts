@@ -145,6 +145,10 @@ export interface Breakpoint {
   line: number;
   /** Conditional expression (if any) */
   condition?: string;
+  /** Hit-count condition (e.g. `== 5`, `>= 3`, `% 2`) */
+  hitCondition?: string;
+  /** Logpoint message; `{expr}` is interpolated and the program is not stopped */
+  logMessage?: string;
   /** Whether the breakpoint is verified */
   verified: boolean;
   /** Validation message from DAP adapter */
@@ -153,8 +157,116 @@ export interface Breakpoint {
   conditionVerified?: boolean;
   /** Error message if condition validation failed */
   conditionError?: string;
+  /** Whether the adapter accepted the hit condition; unset while an unverified breakpoint has no message */
+  hitConditionVerified?: boolean;
+  /** Error message if the hit condition was rejected */
+  hitConditionError?: string;
+  /** Times execution reached this breakpoint in the current run, refreshed from the adapter while paused */
+  hitCount?: number;
 }
 
+/**
+ * Access kinds a data breakpoint (watchpoint) can trigger on
+ */
+export type DataBreakpointAccessType = 'read' | 'write' | 'readWrite';
+
+/**
+ * Data breakpoint (watchpoint) definition
+ */
+export interface DataBreakpoint {
+  /** Unique identifier */
+  id: string;
+  /** Adapter-provided data ID from a dataBreakpointInfo request */
+  dataId: string;
+  /** Variable name being watched */
+  name: string;
+  /** Access kind that triggers the breakpoint */
+  accessType: DataBreakpointAccessType;
+  /** Conditional expression (if any) */
+  condition?: string;
+  /** Adapter description of the watched data (e.g. address and size) */
+  description?: string;
+  /** Whether the breakpoint is verified */
+  verified: boolean;
+  /** Validation message from DAP adapter */
+  message?: string;
+}
+
+/**
+ * A concrete code location a breakpoint resolved to
+ */
+export interface BreakpointLocation {
+  /** Fully qualified function name of the resolved instance */
+  function?: string;
+  /** Source file (may be a base name when the adapter only reports that) */
+  file?: string;
+  /** Line number */
+  line?: number;
+  /** Column number */
+  column?: number;
+  /** Instruction address */
+  address?: string;
+  /** Whether this location is resolved in the loaded code */
+  verified: boolean;
+}
+
+/**
+ * Function breakpoint definition
+ */
+export interface FunctionBreakpoint {
+  /** Unique identifier */
+  id: string;
+  /** Function name as requested (e.g. a Rust item path) */
+  name: string;
+  /** Conditional expression (if any) */
+  condition?: string;
+  /** Whether the breakpoint is verified */
+  verified: boolean;
+  /** Validation message from DAP adapter */
+  message?: string;
+  /** Every location the function resolved to (one per monomorphized instance) */
+  locations: BreakpointLocation[];
+}
+
+/**
+ * Instruction breakpoint definition (breaks at a machine code address)
+ */
+export interface InstructionBreakpoint {
+  /** Unique identifier */
+  id: string;
+  /** Normalized hex address, e.g. 0x555555559a4c */
+  address: string;
+  /** Conditional expression (if any) */
+  condition?: string;
+  /** Hit count condition (if any) */
+  hitCondition?: string;
+  /** Whether the breakpoint is verified */
+  verified: boolean;
+  /** Validation message from DAP adapter */
+  message?: string;
+  /** Source location of the address, when it has line information */
+  location?: BreakpointLocation;
+}
+
+/**
+ * A call on the current line that step_into can enter directly
+ */
+export interface StepInTarget {
+  /** Adapter-assigned ID to pass to step_into as targetId */
+  id: number;
+  /** Call as displayed by the adapter, e.g. a function or method name */
+  label: string;
+  line?: number;
+  column?: number;
+  endLine?: number;
+  endColumn?: number;
+}
+
+/**
+ * Step granularity: `line`/`statement` step source lines, `instruction` steps one machine instruction
+ */
+export type SteppingGranularity = 'statement' | 'line' | 'instruction';
+
 /**
  * Debug session information
  */
@@ -210,6 +322,8 @@ export interface Variable {
   expandable: boolean;
   /** Variable children (for complex objects) */
   children?: Variable[];
+  /** Memory reference for read_memory/write_memory (when the adapter reports one) */
+  memoryReference?: string;
 }
 
 /**
@@ -226,6 +340,103 @@ export interface StackFrame {
   line: number;
   /** Column number */
   column?: number;
+  /** Memory reference of the frame's current instruction (for disassembly) */
+  instructionPointerReference?: string;
+  /** Set when the frame runs the body of an async function or block */
+  asyncBody?: AsyncBody;
+  /** Number of runtime frames this placeholder stands for (async stack mode) */
+  collapsedFrames?: number;
+}
+
+/**
+ * Frame ID of placeholders for collapsed runtime frames. They are not frames
+ * of the debug adapter, so tools taking a frameId reject it.
+ */
+export const COLLAPSED_FRAME_ID = -1;
+
+/**
+ * The async function or block a stack frame is executing. Its body is the
+ * state machine (generator) behind the future, polled by an executor.
+ */
+export interface AsyncBody {
+  /** `async fn`, async block or async closure */
+  kind: 'fn' | 'block' | 'closure';
+  /** Path of the async function, or of the function containing the block */
+  function: string;
+}
+
+/**
+ * Scheduling state of an async runtime task: waiting to be woken (idle),
+ * woken and queued (notified), being polled (running) or finished (complete)
+ */
+export type AsyncTaskState = 'idle' | 'notified' | 'running' | 'complete';
+
+/**
+ * A task owned by an async runtime, e.g. one spawned with `tokio::spawn`
+ */
+export interface AsyncTaskInfo {
+  /** Runtime task ID (`tokio::task::Id`) */
+  id: number;
+  state: AsyncTaskState;
+  /** Whether the task was aborted or its runtime is shutting down */
+  cancelled: boolean;
+  /** Where the task was spawned (Tokio records this only with `--cfg tokio_unstable`) */
+  spawnLocation?: { file: string; line: number; column?: number };
+  /** The spawned future, e.g. `app::fetch` or `async block in app::main` */
+  future?: string;
+  /** State of the spawned future's state machine, e.g. `Unresumed` or `Suspend0` */
+  suspendState?: string;
+  /** The innermost future the task is waiting on, e.g. `tokio::time::sleep::Sleep` */
+  suspendedIn?: string;
+  /** Futures from the spawned one down to suspendedIn, following each `.await` */
+  awaitChain: string[];
+}
+
+/**
+ * An async runtime in the debuggee and the tasks it owns
+ */
+export interface AsyncRuntimeInfo {
+  /** Runtime flavor, e.g. Tokio's `multi_thread` or `current_thread` */
+  flavor: string;
+  tasks: AsyncTaskInfo[];
+}
+
+/**
+ * A single disassembled machine instruction
+ */
+export interface DisassembledInstruction {
+  /** Instruction address (hex) */
+  address: string;
+  /** Raw instruction bytes (hex), if reported */
+  instructionBytes?: string;
+  /** Assembly text, e.g. `mov eax, dword ptr [rbp - 0x14]` */
+  instruction: string;
+  /** Function/symbol containing the instruction */
+  symbol?: string;
+  /** Source file the instruction maps to */
+  file?: string;
+  /** Source line the instruction maps to */
+  line?: number;
+  /** Whether this is the instruction the frame is stopped at */
+  isCurrent: boolean;
+}
+
+/**
+ * Details about an exception or panic that caused the debuggee to stop
+ */
+export interface ExceptionStopInfo {
+  /** DAP stop reason (normally 'exception') */
+  reason: string;
+  /** Exception or filter identifier reported by the adapter (e.g. 'rust_panic') */
+  exceptionId?: string;
+  /** Human readable exception / panic message */
+  message?: string;
+  /** First user-code location after unwinding the panic machinery */
+  location?: { file: string; line: number; column?: number };
+  /** Stack frames starting at the user-code location */
+  frames: StackFrame[];
+  /** Number of runtime frames skipped while unwinding to the user-code location */
+  unwoundFrames: number;
 }
 
 /**
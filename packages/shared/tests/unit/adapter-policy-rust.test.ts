@@ -3,6 +3,7 @@ import type { DebugProtocol } from '@vscode/debugprotocol';
 import { EventEmitter } from 'events';
 import { RustAdapterPolicy } from '../../src/interfaces/adapter-policy-rust.js';
 import { SessionState } from '@debugmcp/shared';
+import { COLLAPSED_FRAME_ID, type StackFrame } from '../../src/models/index.js';
 
 const accessMock = vi.fn<[], Promise<void>>();
 const spawnMock = vi.fn();
@@ -64,6 +65,27 @@ describe('RustAdapterPolicy', () => {
       expect(filtered.map(v => v.name)).toEqual(['app']);
     });
 
+    it('keeps the future an async body is awaiting', () => {
+      const scopes: Record<number, DebugProtocol.Scope[]> = {
+        1: [{ name: 'Local', variablesReference: 9, expensive: false }]
+      };
+      const vars: Record<number, DebugProtocol.Variable[]> = {
+        9: [
+          { name: '__0', value: '2', type: 'u32', variablesReference: 0 },
+          { name: 'delay', value: '200ms', type: 'core::time::Duration', variablesReference: 10 },
+          { name: '__awaitee', value: 'tokio::time::sleep::Sleep', type: 'tokio::time::sleep::Sleep', variablesReference: 11 },
+          {
+            name: '__next',
+            value: 'async_example::fetch_data (suspended at .await #1, main.rs:46)',
+            type: 'core::pin::Pin<&mut async_example::fetch_data::{async_fn_env#0}>',
+            variablesReference: 12
+          }
+        ]
+      };
+      const result = RustAdapterPolicy.extractLocalVariables!([frame], scopes, vars);
+      expect(result.map(v => v.name)).toEqual(['delay', '__awaitee', '__next']);
+    });
+
     it('returns special variables when includeSpecial is true', () => {
       const scopes: Record<number, DebugProtocol.Scope[]> = {
         1: [{ name: 'Local', variablesReference: 7, expensive: false }]
@@ -79,6 +101,417 @@ describe('RustAdapterPolicy', () => {
     });
   });
 
+  describe('isPanicFrame', () => {
+    it('recognizes std/core panic machinery frames', () => {
+      const names = [
+        'rust_panic',
+        '__rust_start_panic',
+        'std::panicking::begin_panic_handler::{{closure}}',
+        'core::panicking::panic_fmt',
+        'core::result::unwrap_failed',
+        'core::option::Option<i32>::unwrap'
+      ];
+      for (const name of names) {
+        expect(RustAdapterPolicy.isPanicFrame!({ id: 1, name, file: '', line: 0 })).toBe(true);
+      }
+    });
+
+    it('treats user and sysroot frames differently', () => {
+      expect(
+        RustAdapterPolicy.isPanicFrame!({ id: 1, name: 'hello_world::main', file: '/work/src/main.rs', line: 12 })
+      ).toBe(false);
+      expect(
+        RustAdapterPolicy.isPanicFrame!({
+          id: 2,
+          name: 'alloc::vec::Vec<T>::push',
+          file: '/rustc/90b35a6239c3d8bdabc530a6a0816f7ff89a0aaf/library/alloc/src/vec/mod.rs',
+          line: 1
+        })
+      ).toBe(true);
+    });
+  });
+
+  describe('async stack frames', () => {
+    const tokio = '/cargo/registry/src/index.crates.io-1949cf8c6b5b557f/tokio-1.53.2/src';
+    const sysroot = '/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860/library';
+    const frames = (entries: Array<[string, string]>): StackFrame[] =>
+      entries.map(([name, file], index) => ({ id: index + 1, name, file, line: 1 }));
+
+    // async_example stopped in fetch_data, awaited by the #[tokio::main] body
+    const blockOn = frames([
+      ['async_example::fetch_data::{async_fn#0}', '/ws/src/main.rs'],
+      ['async_example::main::{async_block#0}', '/ws/src/main.rs'],
+      ['tokio::runtime::park::CachedParkThread::block_on::{closure#0}', `${tokio}/runtime/park.rs`],
+      ['tokio::task::coop::with_budget', `${tokio}/task/coop/mod.rs`],
+      ['tokio::runtime::runtime::Runtime::block_on', `${tokio}/runtime/runtime.rs`],
+      ['async_example::main', '/ws/src/main.rs'],
+      ['core::ops::function::FnOnce::call_once', `${sysroot}/core/src/ops/function.rs`],
+      ['std::rt::lang_start', `${sysroot}/std/src/rt.rs`],
+      ['main', '<unknown_source>']
+    ]);
+
+    it('marks async bodies and hides runtime frames by default', () => {
+      const filtered = RustAdapterPolicy.filterStackFrames!(blockOn, false);
+
+      expect(filtered.map(frame => frame.name)).toEqual([
+        'async_example::fetch_data::{async_fn#0}',
+        'async_example::main::{async_block#0}',
+        'async_example::main'
+      ]);
+      expect(filtered.map(frame => frame.asyncBody)).toEqual([
+        { kind: 'fn', function: 'async_example::fetch_data' },
+        { kind: 'block', function: 'async_example::main' },
+        undefined
+      ]);
+      expect(RustAdapterPolicy.filterStackFrames!(blockOn, true)).toHaveLength(blockOn.length);
+    });
+
+    it('keeps the frame the program stopped in', () => {
+      const inStd = frames([
+        ['alloc::vec::Vec<T,A>::push', `${sysroot}/alloc/src/vec/mod.rs`],
+        ['app::main', '/ws/src/main.rs'],
+        ['std::rt::lang_start', `${sysroot}/std/src/rt.rs`]
+      ]);
+
+      expect(RustAdapterPolicy.filterStackFrames!(inStd, false).map(frame => frame.name))
+        .toEqual(['alloc::vec::Vec<T,A>::push', 'app::main']);
+    });
+
+    it('collapses the executor below the outermost async body', () => {
+      const collapsed = RustAdapterPolicy.collapseAsyncFrames!(blockOn);
+
+      expect(collapsed.map(frame => frame.name)).toEqual([
+        'async_example::fetch_data::{async_fn#0}',
+        'async_example::main::{async_block#0}',
+        '[block_on: 7 frames]'
+      ]);
+      expect(collapsed[2]).toMatchObject({ id: COLLAPSED_FRAME_ID, file: `${tokio}/runtime/park.rs`, collapsedFrames: 7 });
+      expect(RustAdapterPolicy.filterStackFrames!(collapsed, false)).toEqual(collapsed);
+    });
+
+    it('collapses poll glue between bodies and names spawned tasks', () => {
+      const spawned = frames([
+        ['async_example::trace_task', '/ws/src/main.rs'],
+        ['async_example::process_item::{async_fn#0}', '/ws/src/main.rs'],
+        ['<core::pin::Pin<P> as core::future::future::Future>::poll', `${sysroot}/core/src/future/future.rs`],
+        ['<tokio::future::maybe_done::MaybeDone<Fut> as core::future::future::Future>::poll', `${tokio}/future/maybe_done.rs`],
+        ['async_example::worker::{async_fn#0}', '/ws/src/main.rs'],
+        ['tokio::runtime::task::core::Core<T,S>::poll', `${tokio}/runtime/task/core.rs`],
+        ['__rust_try', '<unknown_source>'],
+        ['tokio::runtime::scheduler::multi_thread::worker::run', `${tokio}/runtime/scheduler/multi_thread/worker.rs`]
+      ]);
+
+      expect(RustAdapterPolicy.collapseAsyncFrames!(spawned).map(frame => frame.name)).toEqual([
+        'async_example::trace_task',
+        'async_example::process_item::{async_fn#0}',
+        '[poll: 2 frames]',
+        'async_example::worker::{async_fn#0}',
+        '[tokio task: 3 frames]'
+      ]);
+    });
+
+    it('recognizes older generator names and leaves synchronous stacks alone', () => {
+      const legacy = frames([['app::load::{generator#0}', '/ws/src/lib.rs']]);
+      expect(RustAdapterPolicy.collapseAsyncFrames!(legacy)[0].asyncBody).toEqual({ kind: 'fn', function: 'app::load' });
+
+      const sync = frames([
+        ['app::main::{closure#0}', '/ws/src/main.rs'],
+        ['app::main', '/ws/src/main.rs'],
+        ['std::rt::lang_start', `${sysroot}/std/src/rt.rs`]
+      ]);
+      expect(RustAdapterPolicy.collapseAsyncFrames!(sync)).toEqual(sync);
+    });
+  });
+
+  describe('function breakpoints', () => {
+    it('normalizes Rust item paths for LLDB', () => {
+      const normalize = RustAdapterPolicy.normalizeFunctionBreakpointName!;
+      expect(normalize('hello_world::calculate_sum')).toBe('hello_world::calculate_sum');
+      expect(normalize('crate::utils::parse()')).toBe('utils::parse');
+      expect(normalize('app::Stack<T>::push')).toBe('app::Stack::push');
+      expect(normalize('app::convert::<Vec<u8>>')).toBe('app::convert');
+    });
+
+    it('keeps the self type of trait-qualified paths', () => {
+      const normalize = RustAdapterPolicy.normalizeFunctionBreakpointName!;
+      expect(normalize('<Foo as Trait>::method')).toBe('Foo::method');
+      expect(normalize('<crate::shapes::Circle as core::fmt::Display>::fmt')).toBe('shapes::Circle::fmt');
+      expect(normalize('<Vec<T> as app::Summary<T>>::summarize')).toBe('Vec::summarize');
+      expect(normalize('<Foo>::new')).toBe('Foo::new');
+    });
+
+    it('parses every resolved location from breakpoint list output', () => {
+      const output = [
+        "1: name = 'app::largest', locations = 2, resolved = 2, hit count = 0",
+        '  1.1: where = app`app::largest::h0123456789abcdef + 16 at main.rs:4:5, address = 0x0000555555559a34, resolved, hit count = 0 ',
+        '  1.2: where = app`app::largest::hfedcba9876543210 + 16 at main.rs:4:5, address = 0x0000555555559b70, unresolved, hit count = 0 '
+      ].join('\n');
+
+      const locations = RustAdapterPolicy.parseBreakpointLocations!(output);
+
+      expect(locations).toEqual([
+        { function: 'app::largest', file: 'main.rs', line: 4, column: 5, address: '0x0000555555559a34', verified: true },
+        { function: 'app::largest', file: 'main.rs', line: 4, column: 5, address: '0x0000555555559b70', verified: false }
+      ]);
+      expect(RustAdapterPolicy.getBreakpointLocationsCommand!(1)).toBe('breakpoint list 1');
+    });
+  });
+
+  it('derives the watchpoint limit from the target architecture', () => {
+    expect(RustAdapterPolicy.getHardwareWatchpointLimit!('x86_64')).toBe(4);
+    expect(RustAdapterPolicy.getHardwareWatchpointLimit!('aarch64')).toBe(4);
+    expect(RustAdapterPolicy.getHardwareWatchpointLimit!('i686')).toBe(4);
+    expect(RustAdapterPolicy.getHardwareWatchpointLimit!('riscv64gc')).toBeUndefined();
+    expect(RustAdapterPolicy.getHardwareWatchpointLimit!()).toBeUndefined();
+  });
+
+  describe('hit conditions', () => {
+    it('accepts CodeLLDB hit condition syntax', () => {
+      for (const hitCondition of ['5', '== 5', '=5', '>= 3', '> 1', '< 10', '<= 2', '% 2']) {
+        expect(RustAdapterPolicy.validateHitCondition!(hitCondition)).toBeUndefined();
+      }
+    });
+
+    it('rejects expressions and modulo zero', () => {
+      expect(RustAdapterPolicy.validateHitCondition!('i > 5')).toContain('Invalid hit condition');
+      expect(RustAdapterPolicy.validateHitCondition!('!= 3')).toContain('Invalid hit condition');
+      expect(RustAdapterPolicy.validateHitCondition!('% 0')).toContain('never stop');
+    });
+
+    it('parses hit counts from breakpoint list output', () => {
+      const output = [
+        'Current breakpoints:',
+        "1: file = '/work/src/main.rs', line = 8, exact_match = 0, locations = 1, resolved = 1, hit count = 5",
+        '  1.1: where = app`app::main + 40 at main.rs:8:21, address = 0x0000555555559a34, resolved, hit count = 5 ',
+        "2: name = 'app::largest', locations = 2, resolved = 2, hit count = 0"
+      ].join('\n');
+
+      expect(RustAdapterPolicy.parseBreakpointHitCounts!(output)).toEqual([
+        { file: '/work/src/main.rs', line: 8, name: undefined, hitCount: 5 },
+        { file: undefined, line: undefined, name: 'app::largest', hitCount: 0 }
+      ]);
+      expect(RustAdapterPolicy.getBreakpointHitCountsCommand!()).toBe('breakpoint list');
+    });
+  });
+
+  describe('async tasks', () => {
+    it('parses tokio-tasks output into task states and await chains', () => {
+      const output = JSON.stringify({
+        runtimes: [{
+          flavor: 'multi_thread',
+          tasks: [
+            {
+              id: 2,
+              state: 200,
+              spawnLocation: { file: 'src/main.rs', line: 21, column: 17 },
+              stage: 'Running',
+              futures: [
+                { type: 'async_example::async_task::{async_fn_env#0}', variant: 'Suspend0' },
+                { type: 'tokio::time::sleep::Sleep' }
+              ]
+            },
+            {
+              id: 3,
+              state: 108,
+              stage: 'Running',
+              futures: [{ type: 'async_example::main::{async_block_env#0}', variant: 'Unresumed' }]
+            },
+            { id: 4, state: 201, stage: 'Running', futures: [] },
+            { id: 5, state: 194, stage: 'Finished' }
+          ]
+        }]
+      });
+
+      const result = RustAdapterPolicy.parseAsyncTasks!(`${output}\n`);
+
+      expect(result).toEqual({
+        runtimes: [{
+          flavor: 'multi_thread',
+          tasks: [
+            {
+              id: 2,
+              state: 'idle',
+              cancelled: false,
+              spawnLocation: { file: 'src/main.rs', line: 21, column: 17 },
+              future: 'async_example::async_task',
+              suspendState: 'Suspend0',
+              suspendedIn: 'tokio::time::sleep::Sleep',
+              awaitChain: ['async_example::async_task', 'tokio::time::sleep::Sleep']
+            },
+            {
+              id: 3,
+              state: 'notified',
+              cancelled: true,
+              spawnLocation: undefined,
+              future: 'async block in async_example::main',
+              suspendState: 'Unresumed',
+              suspendedIn: undefined,
+              awaitChain: ['async block in async_example::main']
+            },
+            expect.objectContaining({ id: 4, state: 'running', awaitChain: [] }),
+            expect.objectContaining({ id: 5, state: 'complete', future: undefined })
+          ]
+        }]
+      });
+      expect(RustAdapterPolicy.getAsyncTasksCommand!()).toBe('tokio-tasks');
+    });
+
+    it('reports errors from the command', () => {
+      expect(RustAdapterPolicy.parseAsyncTasks!('{"error": "No Tokio runtime found"}')).toEqual({
+        error: 'No Tokio runtime found'
+      });
+      expect(RustAdapterPolicy.parseAsyncTasks!("error: 'tokio-tasks' is not a valid command.")).toEqual({
+        error: 'The tokio-tasks LLDB command is not loaded in this session'
+      });
+    });
+  });
+
+  describe('buildValuePreview', () => {
+    const variable = (
+      name: string,
+      value: string,
+      type: string,
+      variablesReference = 0
+    ): DebugProtocol.Variable => ({ name, value, type, variablesReference });
+
+    const preview = (value: DebugProtocol.Variable, children: Record<number, DebugProtocol.Variable[]>) =>
+      RustAdapterPolicy.buildValuePreview!(value, async (reference) => children[reference] ?? []);
+
+    const ints = (...values: number[]) => values.map((n, i) => variable(`[${i}]`, String(n), 'i32'));
+
+    it('renders a Vec with its length and capacity', async () => {
+      const children = {
+        1: [...ints(1, 2, 3, 4, 5, 6), variable('[raw]', '{...}', 'alloc::vec::Vec<i32, alloc::alloc::Global>', 2)],
+        2: [variable('buf', '{...}', 'alloc::raw_vec::RawVec<i32, alloc::alloc::Global>', 3), variable('len', '6', 'usize')],
+        3: [variable('inner', '{...}', 'alloc::raw_vec::RawVecInner<alloc::alloc::Global>', 4)],
+        4: [variable('cap', '{...}', 'core::num::niche_types::UsizeNoHighBit', 5)],
+        5: [variable('__0', '8', 'usize')]
+      };
+
+      await expect(preview(variable('v', 'size=6', 'alloc::vec::Vec<i32, alloc::alloc::Global>', 1), children))
+        .resolves.toBe('vec![1, 2, 3, … (6 items)] (capacity 8)');
+      await expect(preview(variable('s', 'size=2', '&[i32]', 6), { 6: ints(7, 8) }))
+        .resolves.toBe('[7, 8]');
+    });
+
+    it('falls back to the Vec header without the std formatters', async () => {
+      const children = {
+        1: [variable('buf', '{...}', 'alloc::raw_vec::RawVec<i32, alloc::alloc::Global>', 2), variable('len', '6', 'usize')],
+        2: [variable('ptr', '0x5555', 'core::ptr::unique::Unique<i32>'), variable('cap', '8', 'usize')]
+      };
+
+      await expect(preview(variable('v', '{...}', 'alloc::vec::Vec<i32, alloc::alloc::Global>', 1), children))
+        .resolves.toBe('Vec(len=6, capacity=8)');
+    });
+
+    it('renders map entries, strings and options', async () => {
+      const mapType = 'std::collections::hash::map::HashMap<alloc::string::String, core::option::Option<i32>, std::hash::random::RandomState>';
+      const children = {
+        1: [variable('[0]', '("a", Some(5))', '(alloc::string::String, core::option::Option<i32>)', 2)],
+        2: [
+          variable('0', '"a"', 'alloc::string::String', 3),
+          variable('1', 'Some(5)', 'core::option::Option<i32>', 4)
+        ],
+        3: [variable('[0]', "'a'", 'u8')],
+        4: [variable('0', '5', 'i32')]
+      };
+
+      await expect(preview(variable('m', 'size=1', mapType, 1), children)).resolves.toBe('{"a": Some(5)}');
+      await expect(preview(variable('s', '"hello"', 'alloc::string::String', 3), children)).resolves.toBe('"hello"');
+    });
+
+    it('resolves the variant of a raw enum', async () => {
+      const resultType = 'core::result::Result<alloc::string::String, i32>';
+      const children = {
+        1: [variable('$variants$', '{...}', '', 2)],
+        2: [variable('$variant$0', '{...}', '', 3), variable('$variant$1', '{...}', '', 4)],
+        3: [variable('$discr$', '1', 'u64'), variable('value', '{...}', `${resultType}::Ok$Variant`, 5)],
+        4: [variable('$discr$', '1', 'u64'), variable('value', '{...}', `${resultType}::Err$Variant`, 6)],
+        6: [variable('__0', '-1', 'i32')]
+      };
+
+      await expect(preview(variable('r', '{...}', resultType, 1), children)).resolves.toBe('Err(-1)');
+    });
+
+    it('renders Arc, RefCell and Box with the wrapped value', async () => {
+      const children = {
+        1: [variable('value', 'borrow_mut=1', 'core::cell::RefCell<alloc::vec::Vec<i32, alloc::alloc::Global>>', 2)],
+        2: [variable('value', 'size=2', 'alloc::vec::Vec<i32, alloc::alloc::Global>', 3)],
+        3: ints(1, 2),
+        4: [variable('*b', '7', 'i32')]
+      };
+
+      await expect(preview(
+        variable('shared', 'strong=2, weak=0', 'alloc::sync::Arc<core::cell::RefCell<alloc::vec::Vec<i32, alloc::alloc::Global>>, alloc::alloc::Global>', 1),
+        children
+      )).resolves.toBe('Arc(strong=2) -> RefCell(vec![1, 2]) [borrow_mut=1]');
+      await expect(preview(variable('b', '0x5555', 'alloc::boxed::Box<i32, alloc::alloc::Global>', 4), children))
+        .resolves.toBe('Box(7)');
+    });
+
+    it('leaves other types to the generic preview', async () => {
+      await expect(preview(variable('p', '{...}', 'app::Point', 1), { 1: [variable('x', '1', 'i32')] }))
+        .resolves.toBeUndefined();
+    });
+  });
+
+  describe('prepareVariableValue', () => {
+    const prepare = (type: string, value: string) => RustAdapterPolicy.prepareVariableValue!(type, value);
+
+    it('normalizes integer literals and enforces the type range', () => {
+      expect(prepare('i32', '1_000')).toEqual({ value: '1000' });
+      expect(prepare('u8', '0xff')).toEqual({ value: '255' });
+      expect(prepare('u64', '5u64')).toEqual({ value: '5' });
+      expect(prepare('i8', '-128')).toEqual({ value: '-128' });
+      expect(prepare('i8', '-129')).toEqual({ error: '-129 is out of range for i8 (-128..=127)' });
+      expect(prepare('usize', '-1')).toHaveProperty('error');
+      expect(prepare('i32', 'ten')).toEqual({ error: "'ten' is not a valid i32 literal" });
+    });
+
+    it('sizes isize and usize by the target architecture', () => {
+      const prepareFor = (arch: string | undefined, type: string, value: string) =>
+        RustAdapterPolicy.prepareVariableValue!(type, value, arch);
+
+      expect(prepareFor('x86_64', 'usize', '4294967296')).toEqual({ value: '4294967296' });
+      expect(prepareFor(undefined, 'usize', '4294967296')).toEqual({ value: '4294967296' });
+      expect(prepareFor('i686', 'usize', '4294967296')).toEqual({
+        error: '4294967296 is out of range for usize (0..=4294967295)'
+      });
+      expect(prepareFor('armv7', 'isize', '-2147483648')).toEqual({ value: '-2147483648' });
+      expect(prepareFor('armv7', 'isize', '2147483648')).toHaveProperty('error');
+    });
+
+    it('rejects radix prefixes without digits and mismatched suffixes', () => {
+      expect(prepare('u32', '0x_')).toEqual({ error: "'0x_' is not a valid u32 literal" });
+      expect(prepare('u8', '0b_')).toHaveProperty('error');
+      expect(prepare('u8', '0o')).toHaveProperty('error');
+      expect(prepare('u32', '0x_ff')).toEqual({ value: '255' });
+      expect(prepare('i32', '5u8')).toEqual({ error: "'5u8' is a u8 literal, but the variable is i32" });
+      expect(prepare('u8', '0xffu8')).toEqual({ value: '255' });
+    });
+
+    it('validates bools and floats', () => {
+      expect(prepare('bool', 'false')).toEqual({ value: 'false' });
+      expect(prepare('bool', '0')).toHaveProperty('error');
+      expect(prepare('f64', '1.5e3')).toEqual({ value: '1500' });
+      expect(prepare('f32', '1e39')).toEqual({ error: '1e39 is out of range for f32' });
+    });
+
+    it('converts chars to code points', () => {
+      expect(prepare('char', "'a'")).toEqual({ value: '97' });
+      expect(prepare('char', "'\\n'")).toEqual({ value: '10' });
+      expect(prepare('char', "'\\u{1F600}'")).toEqual({ value: '128512' });
+      expect(prepare('char', "'ab'")).toHaveProperty('error');
+    });
+
+    it('requires quoted literals for strings and passes other types through', () => {
+      expect(prepare('&str', '"hello"')).toEqual({ value: '"hello"' });
+      expect(prepare('alloc::string::String', 'hello')).toHaveProperty('error');
+      expect(prepare('core::option::Option<i32>', 'None')).toEqual({ value: 'None' });
+      expect(prepare('i32', '  ')).toEqual({ error: 'Value must not be empty' });
+    });
+  });
+
   it('resolves executable path using inputs and env', () => {
     expect(RustAdapterPolicy.resolveExecutablePath!('/custom/bin')).toBe('/custom/bin');
 
@@ -91,6 +91,7 @@ export class DapConnectionManager {
       linesStartAt1: true,
       columnsStartAt1: true,
       supportsVariableType: true,
+      supportsMemoryReferences: true,
       supportsRunInTerminalRequest: false,
       locale: 'en-US'
     };
@@ -278,11 +279,13 @@ export class DapConnectionManager {
   async setBreakpoints(
     client: IDapClient,
     sourcePath: string,
-    breakpoints: { line: number; condition?: string }[]
+    breakpoints: { line: number; condition?: string; hitCondition?: string; logMessage?: string }[]
   ): Promise<DebugProtocol.SetBreakpointsResponse> {
     const sourceBreakpoints: DebugProtocol.SourceBreakpoint[] = breakpoints.map(bp => ({
       line: bp.line,
-      condition: bp.condition
+      condition: bp.condition,
+      hitCondition: bp.hitCondition,
+      logMessage: bp.logMessage
     }));
 
     const setBreakpointsArgs: DebugProtocol.SetBreakpointsArguments = {
@@ -300,6 +303,40 @@ export class DapConnectionManager {
     return response;
   }
 
+  /**
+   * Set function breakpoints (replaces all previously set function breakpoints)
+   */
+  async setFunctionBreakpoints(
+    client: IDapClient,
+    breakpoints: { name: string; condition?: string }[]
+  ): Promise<DebugProtocol.SetFunctionBreakpointsResponse> {
+    this.logger.info(`[ConnectionManager] Setting ${breakpoints.length} function breakpoint(s)`);
+    const response = await client.sendRequest<DebugProtocol.SetFunctionBreakpointsResponse>(
+      'setFunctionBreakpoints',
+      { breakpoints: breakpoints.map(bp => ({ name: bp.name, condition: bp.condition })) }
+    );
+    this.logger.info('[ConnectionManager] Function breakpoints set. Response:', response);
+
+    return response;
+  }
+
+  /**
+   * Set exception breakpoint filters
+   */
+  async setExceptionBreakpoints(
+    client: IDapClient,
+    filters: string[]
+  ): Promise<DebugProtocol.SetExceptionBreakpointsResponse> {
+    this.logger.info(`[ConnectionManager] Setting exception breakpoint filters: ${filters.join(', ')}`);
+    const response = await client.sendRequest<DebugProtocol.SetExceptionBreakpointsResponse>(
+      'setExceptionBreakpoints',
+      { filters }
+    );
+    this.logger.info('[ConnectionManager] Exception breakpoints set. Response:', response);
+
+    return response;
+  }
+
   /**
    * Send configuration done notification
    */
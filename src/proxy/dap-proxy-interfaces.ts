@@ -20,7 +20,9 @@ export interface ProxyInitPayload {
   scriptArgs?: string[];
   stopOnEntry?: boolean;
   justMyCode?: boolean;
-  initialBreakpoints?: { file: string; line: number; condition?: string }[];
+  initialBreakpoints?: { file: string; line: number; condition?: string; hitCondition?: string; logMessage?: string }[];
+  exceptionFilters?: string[];
+  initialFunctionBreakpoints?: { name: string; condition?: string }[];
   dryRunSpawn?: boolean;
   launchConfig?: LanguageSpecificLaunchConfig;
   // Adapter command info for language-agnostic adapter spawning
@@ -63,6 +65,16 @@ export interface StatusMessage extends ProxyMessage {
   script?: string;
 }
 
+/**
+ * Adapter result for a function breakpoint set before launch, reported with
+ * the `adapter_configured_and_launched` status
+ */
+export interface LaunchedFunctionBreakpoint {
+  /** Name as sent to the adapter */
+  name: string;
+  breakpoint?: DebugProtocol.Breakpoint;
+}
+
 export interface DapResponseMessage extends ProxyMessage {
   type: 'dapResponse';
   requestId: string;
@@ -244,6 +256,7 @@ export interface ExtendedInitializeArgs extends DebugProtocol.InitializeRequestA
   linesStartAt1: boolean;
   columnsStartAt1: boolean;
   supportsVariableType: boolean;
+  supportsMemoryReferences: boolean;
   supportsRunInTerminalRequest: boolean;
   locale: string;
 }
@@ -109,6 +109,29 @@ export class MessageParser {
         if (bpObj.condition !== undefined && typeof bpObj.condition !== 'string') {
           throw new Error(`Breakpoint 'condition' must be a string if provided`);
         }
+        if (bpObj.hitCondition !== undefined && typeof bpObj.hitCondition !== 'string') {
+          throw new Error(`Breakpoint 'hitCondition' must be a string if provided`);
+        }
+        if (bpObj.logMessage !== undefined && typeof bpObj.logMessage !== 'string') {
+          throw new Error(`Breakpoint 'logMessage' must be a string if provided`);
+        }
+      }
+    }
+
+    if (obj.exceptionFilters !== undefined) {
+      if (!Array.isArray(obj.exceptionFilters) || obj.exceptionFilters.some(f => typeof f !== 'string')) {
+        throw new Error(`Init payload 'exceptionFilters' must be an array of strings if provided`);
+      }
+    }
+
+    if (obj.initialFunctionBreakpoints !== undefined) {
+      if (!Array.isArray(obj.initialFunctionBreakpoints)) {
+        throw new Error(`Init payload 'initialFunctionBreakpoints' must be an array if provided`);
+      }
+      for (const bp of obj.initialFunctionBreakpoints) {
+        if (!bp || typeof bp !== 'object' || typeof (bp as Record<string, unknown>).name !== 'string') {
+          throw new Error(`Function breakpoint must have 'name' (string)`);
+        }
       }
     }
 
@@ -17,7 +17,8 @@ import {
   StatusMessage,
   DapResponseMessage,
   DapEventMessage,
-  ErrorMessage
+  ErrorMessage,
+  LaunchedFunctionBreakpoint
 } from './dap-proxy-interfaces.js';
 import { CallbackRequestTracker } from './dap-proxy-request-tracker.js';
 import { GenericAdapterManager } from './dap-proxy-adapter-manager.js';
@@ -445,7 +446,7 @@ export class DapProxyWorker {
       // Set initial breakpoints if provided
       if (this.currentInitPayload.initialBreakpoints?.length) {
         this.logger!.info('[Worker] Initial breakpoints payload:', this.currentInitPayload.initialBreakpoints);
-        const groupedBreakpoints = new Map<string, { line: number; condition?: string }[]>();
+        const groupedBreakpoints = new Map<string, { line: number; condition?: string; hitCondition?: string; logMessage?: string }[]>();
 
         for (const breakpoint of this.currentInitPayload.initialBreakpoints) {
           const filePath = path.resolve(breakpoint.file);
@@ -454,7 +455,9 @@ export class DapProxyWorker {
           }
           groupedBreakpoints.get(filePath)!.push({
             line: breakpoint.line,
-            condition: breakpoint.condition
+            condition: breakpoint.condition,
+            hitCondition: breakpoint.hitCondition,
+            logMessage: breakpoint.logMessage
           });
         }
 
@@ -467,12 +470,36 @@ export class DapProxyWorker {
         }
       }
 
+      // Function breakpoints are resolved by name, so they need no source path
+      let functionBreakpoints: LaunchedFunctionBreakpoint[] | undefined;
+      const initialFunctionBreakpoints = this.currentInitPayload.initialFunctionBreakpoints;
+      if (initialFunctionBreakpoints?.length) {
+        const response = await this.connectionManager.setFunctionBreakpoints(
+          this.dapClient,
+          initialFunctionBreakpoints
+        );
+        const results = response?.body?.breakpoints ?? [];
+        functionBreakpoints = initialFunctionBreakpoints.map((bp, index) => ({
+          name: bp.name,
+          breakpoint: results[index]
+        }));
+      }
+
+      // Apply exception breakpoint filters (e.g. rust_panic) requested before launch
+      if (this.currentInitPayload.exceptionFilters?.length) {
+        await this.connectionManager.setExceptionBreakpoints(
+          this.dapClient,
+          this.currentInitPayload.exceptionFilters
+        );
+      }
+
       // Send configuration done
       await this.connectionManager.sendConfigurationDone(this.dapClient);
 
       // Update state and notify parent
       this.state = ProxyState.CONNECTED;
-      this.sendStatus('adapter_configured_and_launched');
+      // The session manager reads verified state and locations from the function breakpoint results
+      this.sendStatus('adapter_configured_and_launched', functionBreakpoints ? { data: { functionBreakpoints } } : {});
     } catch (error) {
       const message = error instanceof Error ? error.message : String(error);
       this.logger!.error('[Worker] Error in initialized handler:', error);
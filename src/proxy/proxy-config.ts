@@ -17,7 +17,9 @@ export interface ProxyConfig {
   scriptArgs?: string[];
   stopOnEntry?: boolean;
   justMyCode?: boolean;
-  initialBreakpoints?: Array<{ file: string; line: number; condition?: string }>;
+  initialBreakpoints?: Array<{ file: string; line: number; condition?: string; hitCondition?: string; logMessage?: string }>;
+  exceptionFilters?: string[];    // DAP exception breakpoint filters applied before configurationDone
+  initialFunctionBreakpoints?: Array<{ name: string; condition?: string }>;
   dryRunSpawn?: boolean;
   launchConfig?: LanguageSpecificLaunchConfig;
   
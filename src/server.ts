@@ -10,6 +10,8 @@ import {
   ErrorCode as McpErrorCode,
   McpError,
   ServerResult,
+  type CallToolRequest,
+  type ServerNotification,
 } from '@modelcontextprotocol/sdk/types.js';
 import {
   SessionNotFoundError,
@@ -17,7 +19,25 @@ import {
   UnsupportedLanguageError,
   ProxyNotRunningError
 } from './errors/debug-errors.js';
-import { SessionManager, SessionManagerConfig } from './session/session-manager.js';
+import {
+  SessionManager,
+  SessionManagerConfig,
+  type AsyncTasksResult,
+  type BreakpointOptions,
+  type DisassembleResult,
+  type ExceptionBreakpointsResult,
+  type FunctionBreakpointResult,
+  type FunctionBreakpointRemovalResult,
+  type DataBreakpointResult,
+  type InstructionBreakpointResult,
+  type MemoryReadResult,
+  type SetVariableResult,
+  type MemoryWriteResult,
+  type StepOptions,
+  type StepInTargetsResult
+} from './session/session-manager.js';
+import { formatHexDump, interpretMemory, normalizeAddress, parseHexBytes } from './utils/memory-format.js';
+import { getBuildFailureData } from './utils/build-failure.js';
 import { createProductionDependencies } from './container/dependencies.js';
 import { ContainerConfig } from './container/types.js';
 import {
@@ -26,8 +46,15 @@ import {
   StackFrame,
   DebugLanguage,
   Breakpoint,
-  SessionLifecycleState
+  SessionLifecycleState,
+  type DataBreakpointAccessType,
+  type DisassembledInstruction,
+  type ExceptionStopInfo,
+  type LaunchPreparationOptions,
+  type SteppingGranularity,
+  COLLAPSED_FRAME_ID
 } from '@debugmcp/shared';
+import type { CargoBuildOptions } from '@debugmcp/adapter-rust';
 import { DebugProtocol } from '@vscode/debugprotocol';
 import path from 'path';
 import { SimpleFileChecker, createSimpleFileChecker } from './utils/simple-file-checker.js';
@@ -86,9 +113,36 @@ interface ToolArguments {
   frameId?: number;
   expression?: string;
   linesContext?: number;
+  instructionsContext?: number;
   includeInternals?: boolean;
+  asyncStack?: boolean;
+  filters?: string[];
+  logMessage?: string;
+  hitCondition?: string;
+  sinceSeq?: number;
+  category?: string;
+  limit?: number;
+  accessType?: DataBreakpointAccessType;
+  variablesReference?: number;
+  breakpointId?: string;
+  address?: string;
+  granularity?: SteppingGranularity;
+  memoryReference?: string;
+  offset?: number;
+  count?: number;
+  data?: string;
+  allowPartial?: boolean;
+  targetId?: number;
+  value?: string;
+  path?: string;
+  testName?: string;
+  testTarget?: string;
 }
 
+const MAX_MEMORY_READ_BYTES = 4096;
+
+const STEPPING_GRANULARITIES: readonly SteppingGranularity[] = ['statement', 'line', 'instruction'];
+
 /**
  * Main Debug MCP Server class
  */
@@ -237,7 +291,8 @@ export class DebugMcpServer {
     args?: string[],
     dapLaunchArgs?: Partial<DebugProtocol.LaunchRequestArguments>,
     dryRunSpawn?: boolean,
-    adapterLaunchConfig?: Record<string, unknown>
+    adapterLaunchConfig?: Record<string, unknown>,
+    launchOptions?: LaunchPreparationOptions
   ): Promise<{ success: boolean; state: string; error?: string; data?: unknown; errorType?: string; errorCode?: number; }> {
     this.validateSession(sessionId);
 
@@ -257,7 +312,8 @@ export class DebugMcpServer {
       args,
       dapLaunchArgs,
       dryRunSpawn,
-      adapterLaunchConfig
+      adapterLaunchConfig,
+      launchOptions
     );
     return result;
   }
@@ -266,7 +322,13 @@ export class DebugMcpServer {
     return this.sessionManager.closeSession(sessionId);
   }
 
-  public async setBreakpoint(sessionId: string, file: string, line: number, condition?: string): Promise<Breakpoint> {
+  public async setBreakpoint(
+    sessionId: string,
+    file: string,
+    line: number,
+    condition?: string,
+    options?: BreakpointOptions
+  ): Promise<Breakpoint> {
     this.validateSession(sessionId);
 
     // Check file exists for immediate feedback
@@ -279,7 +341,100 @@ export class DebugMcpServer {
     this.logger.info(`[DebugMcpServer.setBreakpoint] File exists: ${fileCheck.effectivePath} (original: ${file})`);
 
     // Pass the effective path (which has been resolved for container) to session manager
-    return this.sessionManager.setBreakpoint(sessionId, fileCheck.effectivePath, line, condition);
+    return this.sessionManager.setBreakpoint(
+      sessionId,
+      fileCheck.effectivePath,
+      line,
+      condition,
+      options
+    );
+  }
+
+  public async setExceptionBreakpoints(sessionId: string, filters: string[]): Promise<ExceptionBreakpointsResult> {
+    this.validateSession(sessionId);
+    return this.sessionManager.setExceptionBreakpoints(sessionId, filters);
+  }
+
+  public async setFunctionBreakpoint(
+    sessionId: string,
+    name: string,
+    condition?: string
+  ): Promise<FunctionBreakpointResult> {
+    this.validateSession(sessionId);
+    return this.sessionManager.setFunctionBreakpoint(sessionId, name, condition);
+  }
+
+  public async removeFunctionBreakpoint(sessionId: string, breakpointId: string): Promise<FunctionBreakpointRemovalResult> {
+    this.validateSession(sessionId);
+    return this.sessionManager.removeFunctionBreakpoint(sessionId, breakpointId);
+  }
+
+  public async setInstructionBreakpoint(
+    sessionId: string,
+    address: string,
+    options: { condition?: string; hitCondition?: string } = {}
+  ): Promise<InstructionBreakpointResult> {
+    this.validateSession(sessionId);
+    return this.sessionManager.setInstructionBreakpoint(sessionId, address, options);
+  }
+
+  public async setVariable(sessionId: string, variablesReference: number, name: string, value: string): Promise<SetVariableResult> {
+    this.validateSession(sessionId);
+    return this.sessionManager.setVariable(sessionId, variablesReference, name, value);
+  }
+
+  public async readMemory(sessionId: string, memoryReference: string, count: number, offset?: number): Promise<MemoryReadResult> {
+    this.validateSession(sessionId);
+    return this.sessionManager.readMemory(sessionId, memoryReference, count, offset);
+  }
+
+  public async writeMemory(
+    sessionId: string,
+    memoryReference: string,
+    data: Buffer,
+    options: { offset?: number; allowPartial?: boolean } = {}
+  ): Promise<MemoryWriteResult> {
+    this.validateSession(sessionId);
+    return this.sessionManager.writeMemory(sessionId, memoryReference, data, options);
+  }
+
+  public async disassemble(sessionId: string, frameId?: number, instructionsContext?: number): Promise<DisassembleResult> {
+    this.validateSession(sessionId);
+    return this.sessionManager.disassemble(sessionId, {
+      frameId,
+      instructionsBefore: instructionsContext,
+      instructionsAfter: instructionsContext
+    });
+  }
+
+  public async listAsyncTasks(sessionId: string): Promise<AsyncTasksResult> {
+    this.validateSession(sessionId);
+    return this.sessionManager.listAsyncTasks(sessionId);
+  }
+
+  public async getDataBreakpointInfo(
+    sessionId: string,
+    name: string,
+    variablesReference?: number,
+    frameId?: number
+  ): Promise<DataBreakpointResult> {
+    this.validateSession(sessionId);
+    return this.sessionManager.getDataBreakpointInfo(sessionId, name, variablesReference, frameId);
+  }
+
+  public async setDataBreakpoint(
+    sessionId: string,
+    name: string,
+    accessType?: DataBreakpointAccessType,
+    options: { variablesReference?: number; frameId?: number; condition?: string } = {}
+  ): Promise<DataBreakpointResult> {
+    this.validateSession(sessionId);
+    return this.sessionManager.setDataBreakpoint(sessionId, name, accessType, options);
+  }
+
+  public async removeDataBreakpoint(sessionId: string, breakpointId: string): Promise<DataBreakpointResult> {
+    this.validateSession(sessionId);
+    return this.sessionManager.removeDataBreakpoint(sessionId, breakpointId);
   }
 
   public async getVariables(sessionId: string, variablesReference: number): Promise<Variable[]> {
@@ -287,14 +442,18 @@ export class DebugMcpServer {
     return this.sessionManager.getVariables(sessionId, variablesReference);
   }
 
-  public async getStackTrace(sessionId: string, includeInternals: boolean = false): Promise<StackFrame[]> {
+  public async getStackTrace(
+    sessionId: string,
+    includeInternals: boolean = false,
+    asyncStack: boolean = false
+  ): Promise<StackFrame[]> {
     this.validateSession(sessionId);
     const session = this.sessionManager.getSession(sessionId);
     const currentThreadId = session?.proxyManager?.getCurrentThreadId();
     if (!session || !session.proxyManager || typeof currentThreadId !== 'number') {
       throw new ProxyNotRunningError(sessionId || 'unknown', 'get stack trace');
     }
-    return this.sessionManager.getStackTrace(sessionId, currentThreadId, includeInternals);
+    return this.sessionManager.getStackTrace(sessionId, currentThreadId, includeInternals, asyncStack);
   }
 
   public async getScopes(sessionId: string, frameId: number): Promise<DebugProtocol.Scope[]> {
@@ -320,27 +479,32 @@ export class DebugMcpServer {
     return true;
   }
 
-  public async stepOver(sessionId: string): Promise<{ success: boolean; state: string; error?: string; data?: unknown; }> {
+  public async stepOver(sessionId: string, options: StepOptions = {}): Promise<{ success: boolean; state: string; error?: string; data?: unknown; }> {
     this.validateSession(sessionId);
-    const result = await this.sessionManager.stepOver(sessionId);
+    const result = await this.sessionManager.stepOver(sessionId, options);
     if (!result.success) {
       throw new Error(result.error || 'Failed to step over');
     }
     return result;
   }
 
-  public async stepInto(sessionId: string): Promise<{ success: boolean; state: string; error?: string; data?: unknown; }> {
+  public async stepInto(sessionId: string, options: StepOptions = {}): Promise<{ success: boolean; state: string; error?: string; data?: unknown; }> {
     this.validateSession(sessionId);
-    const result = await this.sessionManager.stepInto(sessionId);
+    const result = await this.sessionManager.stepInto(sessionId, options);
     if (!result.success) {
       throw new Error(result.error || 'Failed to step into');
     }
     return result;
   }
 
-  public async stepOut(sessionId: string): Promise<{ success: boolean; state: string; error?: string; data?: unknown; }> {
+  public async getStepInTargets(sessionId: string, frameId?: number): Promise<StepInTargetsResult> {
     this.validateSession(sessionId);
-    const result = await this.sessionManager.stepOut(sessionId);
+    return this.sessionManager.getStepInTargets(sessionId, frameId);
+  }
+
+  public async stepOut(sessionId: string, options: StepOptions = {}): Promise<{ success: boolean; state: string; error?: string; data?: unknown; }> {
+    this.validateSession(sessionId);
+    const result = await this.sessionManager.stepOut(sessionId, options);
     if (!result.success) {
       throw new Error(result.error || 'Failed to step out');
     }
@@ -407,6 +571,32 @@ export class DebugMcpServer {
     return sanitized;
   }
 
+  /**
+   * Progress and cancellation for work done before a launch, such as building
+   * a Rust program. Progress notifications are only sent when the client asked
+   * for them with a progress token; cancelling the request aborts the build.
+   */
+  private createLaunchPreparationOptions(
+    request: CallToolRequest,
+    extra?: { signal?: AbortSignal; sendNotification?: (notification: ServerNotification) => Promise<void> }
+  ): LaunchPreparationOptions {
+    const progressToken = request.params._meta?.progressToken;
+    const sendNotification = extra?.sendNotification;
+    return {
+      signal: extra?.signal,
+      onProgress: progressToken !== undefined && sendNotification
+        ? (progress) => {
+          sendNotification({
+            method: 'notifications/progress',
+            params: { progressToken, ...progress }
+          }).catch((error) => {
+            this.logger.debug('Could not send progress notification', { error: (error as Error)?.message });
+          });
+        }
+        : undefined
+    };
+  }
+
   /**
    * Get session name for logging
    */
@@ -444,7 +634,15 @@ export class DebugMcpServer {
           { name: 'create_debug_session', description: 'Create a new debugging session', inputSchema: { type: 'object', properties: { language: { type: 'string', enum: supportedLanguages, description: 'Programming language for debugging' }, name: { type: 'string', description: 'Optional session name' }, executablePath: { type: 'string', description: 'Path to language executable (optional, will auto-detect if not provided)' } }, required: ['language'] } },
           { name: 'list_supported_languages', description: 'List all supported debugging languages with metadata', inputSchema: { type: 'object', properties: {} } },
           { name: 'list_debug_sessions', description: 'List all active debugging sessions', inputSchema: { type: 'object', properties: {} } },
-          { name: 'set_breakpoint', description: 'Set a breakpoint. Setting breakpoints on non-executable lines (structural, declarative) may lead to unexpected behavior', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, file: { type: 'string', description: fileDescription }, line: { type: 'number', description: 'Line number where to set breakpoint. Executable statements (assignments, function calls, conditionals, returns) work best. Structural lines (function/class definitions), declarative lines (imports), or non-executable lines (comments, blank lines) may cause unexpected stepping behavior' }, condition: { type: 'string' } }, required: ['sessionId', 'file', 'line'] } },
+          { name: 'set_breakpoint', description: 'Set a breakpoint. Setting breakpoints on non-executable lines (structural, declarative) may lead to unexpected behavior', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, file: { type: 'string', description: fileDescription }, line: { type: 'number', description: 'Line number where to set breakpoint. Executable statements (assignments, function calls, conditionals, returns) work best. Structural lines (function/class definitions), declarative lines (imports), or non-executable lines (comments, blank lines) may cause unexpected stepping behavior' }, condition: { type: 'string' }, logMessage: { type: 'string', description: 'Turn the breakpoint into a logpoint: log this message instead of stopping. Expressions in braces are interpolated, e.g. "i={i} total={total}". Read the output with get_debug_output' }, hitCondition: { type: 'string', description: "Only stop when the hit count matches, e.g. '== 5', '>= 3' or '% 2'" } }, required: ['sessionId', 'file', 'line'] } },
+          { name: 'get_debug_output', description: 'Get output captured for a session: logpoint messages and program output, oldest first. Pass the returned lastSeq as sinceSeq to fetch only new entries', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, sinceSeq: { type: 'number', description: 'Only return entries after this sequence number (default: 0)' }, category: { type: 'string', description: "Only return this DAP output category, e.g. 'console' (logpoints), 'stdout', 'stderr'" }, limit: { type: 'number', description: 'Maximum entries to return (default: 100)' } }, required: ['sessionId'] } },
+          { name: 'set_instruction_breakpoint', description: 'Set a breakpoint at a machine code address, e.g. one taken from a disassemble listing. The program must be running or paused; addresses are not kept across launches', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, address: { type: 'string', description: 'Instruction address, hex (0x...) or decimal' }, condition: { type: 'string' }, hitCondition: { type: 'string', description: 'Break only when the hit count matches, e.g. ">= 3"' } }, required: ['sessionId', 'address'] } },
+          { name: 'set_function_breakpoint', description: 'Set a breakpoint on a function by name, without knowing its file. For Rust use item paths such as "hello_world::calculate_sum" or "crate::utils::parse"; generic functions match every monomorphized instance. Returns every resolved location. Can be called before start_debugging', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, name: { type: 'string', description: 'Function name or Rust item path' }, condition: { type: 'string' } }, required: ['sessionId', 'name'] } },
+          { name: 'remove_function_breakpoint', description: 'Remove a function breakpoint set with set_function_breakpoint, from the running program and from the breakpoints applied at the next launch', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, breakpointId: { type: 'string', description: 'breakpointId returned by set_function_breakpoint' } }, required: ['sessionId', 'breakpointId'] } },
+          { name: 'set_exception_breakpoints', description: 'Break when the debuggee raises an exception or panics. Filters are adapter-specific: Rust (CodeLLDB) supports "rust_panic", "cpp_throw" and "cpp_catch". Can be called before start_debugging; filters are applied at launch. Pass an empty array to clear', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, filters: { type: 'array', items: { type: 'string' }, description: 'Exception filter IDs to enable, e.g. ["rust_panic"]' } }, required: ['sessionId', 'filters'] } },
+          { name: 'get_data_breakpoint_info', description: 'Check whether a variable can be watched with a data breakpoint (watchpoint). Returns the allowed access types and remaining hardware watchpoint slots. Session must be paused. Defaults to the local scope of the top frame', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, name: { type: 'string', description: 'Variable name as shown by get_local_variables' }, variablesReference: { type: 'number', description: 'Container variablesReference (optional, defaults to the local scope)' }, frameId: { type: 'number', description: 'Stack frame ID (optional, defaults to the top frame)' } }, required: ['sessionId', 'name'] } },
+          { name: 'set_data_breakpoint', description: 'Break when a variable is written or read (hardware watchpoint). Session must be paused. Rust requires CodeLLDB 1.7.0 or newer', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, name: { type: 'string', description: 'Variable name as shown by get_local_variables' }, accessType: { type: 'string', enum: ['write', 'read', 'readWrite'], description: "Access kind to break on (default: 'write')" }, condition: { type: 'string' }, variablesReference: { type: 'number', description: 'Container variablesReference (optional, defaults to the local scope)' }, frameId: { type: 'number', description: 'Stack frame ID (optional, defaults to the top frame)' } }, required: ['sessionId', 'name'] } },
+          { name: 'remove_data_breakpoint', description: 'Remove a data breakpoint and free its watchpoint slot', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, breakpointId: { type: 'string', description: 'ID returned by set_data_breakpoint' } }, required: ['sessionId', 'breakpointId'] } },
           {
             name: 'start_debugging', description: 'Start debugging a script', inputSchema: {
               type: 'object',
@@ -470,17 +668,25 @@ export class DebugMcpServer {
               required: ['sessionId', 'scriptPath']
             }
           },
+          { name: 'list_rust_targets', description: 'List the bin, example, test, bench and lib targets of a Cargo package or workspace, with source path, required features, the debug build path (null when not built) and whether that build is stale according to the cargo fingerprint check (which rebuilds stale targets). Does not require a session', inputSchema: { type: 'object', properties: { path: { type: 'string', description: 'Cargo package or workspace directory, or a .rs file (lists the targets of the package that owns it)' } }, required: ['path'] } },
+          { name: 'debug_rust_test', description: 'Debug a single Rust #[test] function: builds the test harnesses with cargo test --no-run, finds the executable containing the test, sets a breakpoint on the first statement of the test body and launches it with --exact <name> --nocapture --test-threads=1. Requires a Rust session', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, path: { type: 'string', description: 'Cargo package directory, or any .rs file inside the package' }, testName: { type: 'string', description: 'Test name, fully qualified as cargo test lists it (e.g. "tests::parses_input") or just the function name when unique' }, testTarget: { type: 'string', description: 'Only build this integration test target (tests/<name>.rs). Use when the same test name exists in several test files' }, dapLaunchArgs: { type: 'object', properties: { stopOnEntry: { type: 'boolean' } }, additionalProperties: true } }, required: ['sessionId', 'path', 'testName'] } },
           { name: 'close_debug_session', description: 'Close a debugging session', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' } }, required: ['sessionId'] } },
-          { name: 'step_over', description: 'Step over', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' } }, required: ['sessionId'] } },
-          { name: 'step_into', description: 'Step into', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' } }, required: ['sessionId'] } },
-          { name: 'step_out', description: 'Step out', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' } }, required: ['sessionId'] } },
+          { name: 'step_over', description: 'Step over', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, granularity: { type: 'string', enum: ['statement', 'line', 'instruction'], description: 'Step granularity. "instruction" steps a single machine instruction and reports the new instruction pointer and disassembled instruction. Default: line' } }, required: ['sessionId'] } },
+          { name: 'step_into', description: 'Step into. By default enters the first call on the line; pass a targetId from get_step_in_targets to enter a specific call', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, granularity: { type: 'string', enum: ['statement', 'line', 'instruction'], description: 'Step granularity. "instruction" steps a single machine instruction and reports the new instruction pointer and disassembled instruction. Default: line' }, targetId: { type: 'number', description: 'Step-in target ID from get_step_in_targets' } }, required: ['sessionId'] } },
+          { name: 'get_step_in_targets', description: 'List the calls on the current line that step_into can enter, e.g. "calculate_sum", "Vec::push" or "format!" internals, so you can skip iterator adapters and trait glue. Session must be paused', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, frameId: { type: 'number', description: 'Stack frame ID from get_stack_trace (default: top frame)' } }, required: ['sessionId'] } },
+          { name: 'step_out', description: 'Step out', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, granularity: { type: 'string', enum: ['statement', 'line', 'instruction'], description: 'Step granularity. "instruction" steps a single machine instruction and reports the new instruction pointer and disassembled instruction. Default: line' } }, required: ['sessionId'] } },
           { name: 'continue_execution', description: 'Continue execution', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' } }, required: ['sessionId'] } },
           { name: 'pause_execution', description: 'Pause execution', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' } }, required: ['sessionId'] } },
           { name: 'get_variables', description: 'Get variables (scope is variablesReference: number)', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, scope: { type: 'number', description: "The variablesReference number from a StackFrame or Variable" } }, required: ['sessionId', 'scope'] } },
           { name: 'get_local_variables', description: 'Get local variables for the current stack frame. This is a convenience tool that returns just the local variables without needing to traverse stack->scopes->variables manually', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, includeSpecial: { type: 'boolean', description: 'Include special/internal variables like this, __proto__, __builtins__, etc. Default: false' } }, required: ['sessionId'] } },
-          { name: 'get_stack_trace', description: 'Get stack trace', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, includeInternals: { type: 'boolean', description: 'Include internal/framework frames (e.g., Node.js internals). Default: false for cleaner output.' } }, required: ['sessionId'] } },
+          { name: 'get_stack_trace', description: 'Get stack trace', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, includeInternals: { type: 'boolean', description: 'Include internal/framework frames (e.g., Node.js internals, Rust std and async runtime frames). Default: false for cleaner output.' }, asyncStack: { type: 'boolean', description: 'Rust: collapse async executor and poll frames into placeholders and report the await chain of async fn bodies. Default: false.' } }, required: ['sessionId'] } },
           { name: 'get_scopes', description: 'Get scopes for a stack frame', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, frameId: { type: 'number', description: "The ID of the stack frame from a stackTrace response" } }, required: ['sessionId', 'frameId'] } },
           { name: 'evaluate_expression', description: 'Evaluate expression in the current debug context. Expressions can read and modify program state', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, expression: { type: 'string' }, frameId: { type: 'number', description: 'Optional stack frame ID for evaluation context. Must be a frame ID from a get_stack_trace response. If not provided, uses the current (top) frame automatically' } }, required: ['sessionId', 'expression'] } },
+          { name: 'disassemble', description: 'Disassemble machine instructions around the current instruction pointer of a stack frame, interleaved with the source lines they came from. Useful for release-mode or inlined code where source-level stepping is unreliable. Session must be paused', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, frameId: { type: 'number', description: 'Stack frame ID from get_stack_trace (default: top frame)' }, instructionsContext: { type: 'number', description: 'Number of instructions before and after the instruction pointer to include (default: 10, max: 100)' } }, required: ['sessionId'] } },
+          { name: 'list_async_tasks', description: 'List the async tasks of a paused Rust program\'s Tokio runtimes (e.g. spawned with tokio::spawn), with task ID, state (idle, notified, running or complete), spawn location and the chain of futures each task is suspended in. Use it to find stuck or starved tasks. Spawn locations need a build with RUSTFLAGS="--cfg tokio_unstable"', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' } }, required: ['sessionId'] } },
+          { name: 'set_variable', description: 'Change the value of a variable in a paused frame, e.g. to test a fix without rebuilding. The value is checked against the variable\'s type first (Rust integers with range checks, bool, f32/f64, char, quoted strings for &str/String). Returns the new value and updated child variables', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, variablesReference: { type: 'number', description: 'variablesReference of the scope or parent variable that contains the variable (from get_scopes or get_variables)' }, name: { type: 'string', description: 'Variable name as shown by get_variables' }, value: { type: 'string', description: 'New value, e.g. "42", "true", "2.5", "\'x\'"' } }, required: ['sessionId', 'variablesReference', 'name', 'value'] } },
+          { name: 'read_memory', description: 'Read raw process memory as a hex dump with ASCII and u8/u32/f64/usize interpretations in the target's byte order and pointer width. Pass the memoryReference of a variable or evaluate_expression result, or a raw address such as a pointer value or a Vec buffer pointer. Session must be paused', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, memoryReference: { type: 'string', description: 'memoryReference from get_variables, get_local_variables or evaluate_expression' }, address: { type: 'string', description: 'Raw address, hex (0x...) or decimal. Use instead of memoryReference' }, offset: { type: 'number', description: 'Byte offset from the reference (may be negative). Default: 0' }, count: { type: 'number', description: `Number of bytes to read (default: 64, max: ${MAX_MEMORY_READ_BYTES})` } }, required: ['sessionId'] } },
+          { name: 'write_memory', description: 'Write raw bytes into process memory at a memoryReference or raw address. Session must be paused', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, memoryReference: { type: 'string', description: 'memoryReference from get_variables, get_local_variables or evaluate_expression' }, address: { type: 'string', description: 'Raw address, hex (0x...) or decimal. Use instead of memoryReference' }, offset: { type: 'number', description: 'Byte offset from the reference (may be negative). Default: 0' }, data: { type: 'string', description: 'Bytes to write as hex, e.g. "2a 00 00 00"' }, allowPartial: { type: 'boolean', description: 'Write as many bytes as possible instead of failing when part of the range is not writable. Default: false' } }, required: ['sessionId', 'data'] } },
           { name: 'get_source_context', description: 'Get source context around a specific line in a file', inputSchema: { type: 'object', properties: { sessionId: { type: 'string' }, file: { type: 'string', description: fileDescription }, line: { type: 'number', description: 'Line number to get context for' }, linesContext: { type: 'number', description: 'Number of lines before and after to include (default: 5)' } }, required: ['sessionId', 'file', 'line'] } },
         ],
       };
@@ -488,7 +694,7 @@ export class DebugMcpServer {
 
     this.server.setRequestHandler(
       CallToolRequestSchema,
-      async (request): Promise<ServerResult> => {
+      async (request, extra): Promise<ServerResult> => {
         const toolName = request.params.name;
         const args = request.params.arguments as ToolArguments;
 
@@ -504,6 +710,13 @@ export class DebugMcpServer {
         try {
           let result: ServerResult;
 
+          if (args.frameId === COLLAPSED_FRAME_ID) {
+            throw new McpError(
+              McpErrorCode.InvalidParams,
+              'frameId refers to a placeholder for collapsed runtime frames; use a frame ID from get_stack_trace with asyncStack: false'
+            );
+          }
+
           switch (toolName) {
             case 'create_debug_session': {
               // Ensure requested language is among dynamically supported ones
@@ -542,9 +755,23 @@ export class DebugMcpServer {
               if (!args.sessionId || !args.file || args.line === undefined) {
                 throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
               }
+              if (args.logMessage !== undefined) {
+                const logMessageError = this.validateLogMessage(args.logMessage);
+                if (logMessageError) {
+                  throw new McpError(McpErrorCode.InvalidParams, logMessageError);
+                }
+              }
 
               try {
-                const breakpoint = await this.setBreakpoint(args.sessionId, args.file, args.line, args.condition);
+                const breakpoint = await this.setBreakpoint(
+                  args.sessionId,
+                  args.file,
+                  args.line,
+                  args.condition,
+                  args.logMessage !== undefined || args.hitCondition !== undefined
+                    ? { logMessage: args.logMessage, hitCondition: args.hitCondition }
+                    : undefined
+                );
 
                 // Log breakpoint event
                 this.logger.info('debug:breakpoint', {
@@ -590,11 +817,18 @@ export class DebugMcpServer {
                       file: breakpoint.file,
                       line: breakpoint.line,
                       verified: breakpoint.verified,
-                      message: breakpoint.message || `Breakpoint set at ${breakpoint.file}:${breakpoint.line}`,
+                      message: breakpoint.message ||
+                        `${breakpoint.logMessage !== undefined ? 'Logpoint' : 'Breakpoint'} set at ${breakpoint.file}:${breakpoint.line}`,
                       // Include condition info if provided
                       condition: breakpoint.condition || undefined,
+                      logMessage: breakpoint.logMessage,
                       conditionVerified: breakpoint.conditionVerified,
                       conditionError: breakpoint.conditionError || undefined,
+                      // Include hit condition info if provided
+                      hitCondition: breakpoint.hitCondition,
+                      hitConditionVerified: breakpoint.hitConditionVerified,
+                      hitConditionError: breakpoint.hitConditionError || undefined,
+                      hitCount: breakpoint.hitCount,
                       // Only add warning if there's a message from debugpy (indicating a problem)
                       warning: breakpoint.message || undefined,
                       // Include context if available
@@ -632,6 +866,32 @@ export class DebugMcpServer {
               }
               break;
             }
+            case 'get_debug_output': {
+              result = await this.handleGetDebugOutput(args);
+              break;
+            }
+            case 'set_exception_breakpoints': {
+              result = await this.handleSetExceptionBreakpoints(args as { sessionId: string; filters: string[] });
+              break;
+            }
+            case 'set_function_breakpoint': {
+              result = await this.handleSetFunctionBreakpoint(args as { sessionId: string; name: string; condition?: string });
+              break;
+            }
+            case 'remove_function_breakpoint': {
+              result = await this.handleRemoveFunctionBreakpoint(args);
+              break;
+            }
+            case 'set_instruction_breakpoint': {
+              result = await this.handleSetInstructionBreakpoint(args);
+              break;
+            }
+            case 'get_data_breakpoint_info':
+            case 'set_data_breakpoint':
+            case 'remove_data_breakpoint': {
+              result = await this.handleDataBreakpointTool(toolName, args);
+              break;
+            }
             case 'start_debugging': {
               if (!args.sessionId || !args.scriptPath) {
                 throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
@@ -651,7 +911,8 @@ export class DebugMcpServer {
                   args.args,
                   args.dapLaunchArgs,
                   args.dryRunSpawn,
-                  args.adapterLaunchConfig
+                  args.adapterLaunchConfig,
+                  this.createLaunchPreparationOptions(request, extra)
                 );
                 const responsePayload: Record<string, unknown> = {
                   success: debugResult.success,
@@ -704,15 +965,25 @@ export class DebugMcpServer {
               if (!args.sessionId) {
                 throw new McpError(McpErrorCode.InvalidParams, 'Missing required sessionId');
               }
+              if (args.granularity !== undefined && !STEPPING_GRANULARITIES.includes(args.granularity)) {
+                throw new McpError(McpErrorCode.InvalidParams, "granularity must be one of 'statement', 'line', 'instruction'");
+              }
+              if (args.targetId !== undefined && (toolName !== 'step_into' || !Number.isInteger(args.targetId))) {
+                throw new McpError(McpErrorCode.InvalidParams, 'targetId must be an integer and is only supported by step_into');
+              }
 
               try {
+                const stepOptions: StepOptions = { granularity: args.granularity };
+                if (args.targetId !== undefined) {
+                  stepOptions.targetId = args.targetId;
+                }
                 let stepResult: { success: boolean; state: string; error?: string; data?: unknown; };
                 if (toolName === 'step_over') {
-                  stepResult = await this.stepOver(args.sessionId);
+                  stepResult = await this.stepOver(args.sessionId, stepOptions);
                 } else if (toolName === 'step_into') {
-                  stepResult = await this.stepInto(args.sessionId);
+                  stepResult = await this.stepInto(args.sessionId, stepOptions);
                 } else {
-                  stepResult = await this.stepOut(args.sessionId);
+                  stepResult = await this.stepOut(args.sessionId, stepOptions);
                 }
 
                 // Build response with location and line context if available
@@ -724,9 +995,28 @@ export class DebugMcpServer {
                 };
 
                 // Extract location from result data
-                const resultData = stepResult.data as { message?: string; location?: { file: string; line: number; column?: number } } | undefined;
+                const resultData = stepResult.data as {
+                  message?: string;
+                  location?: { file: string; line: number; column?: number };
+                  exception?: ExceptionStopInfo;
+                  instructionPointer?: string;
+                  instruction?: DisassembledInstruction;
+                } | undefined;
                 const location = resultData?.location;
 
+                if (resultData?.exception) {
+                  response.exception = resultData.exception;
+                }
+                if (resultData?.instruction) {
+                  response.instructionPointer = resultData.instructionPointer;
+                  response.instruction = {
+                    address: resultData.instruction.address,
+                    instructionBytes: resultData.instruction.instructionBytes,
+                    instruction: resultData.instruction.instruction,
+                    symbol: resultData.instruction.symbol
+                  };
+                }
+
                 if (location) {
                   response.location = location;
 
@@ -845,8 +1135,41 @@ export class DebugMcpServer {
               try {
                 // Default to false for cleaner output
                 const includeInternals = args.includeInternals ?? false;
-                const stackFrames = await this.getStackTrace(args.sessionId, includeInternals);
-                result = { content: [{ type: 'text', text: JSON.stringify({ success: true, stackFrames, count: stackFrames.length, includeInternals }) }] };
+                const asyncStack = args.asyncStack ?? false;
+                const stackFrames = await this.getStackTrace(args.sessionId, includeInternals, asyncStack);
+                const stopReason = this.sessionManager.getSession(args.sessionId)?.lastStop?.reason;
+                const exception = stopReason === 'exception'
+                  ? await this.sessionManager.getExceptionStopInfo(args.sessionId)
+                  : null;
+                // Report hit counts for the breakpoint(s) the session is stopped at
+                const hitBreakpoints = stopReason === 'breakpoint' && stackFrames.length > 0
+                  ? await this.sessionManager.getHitBreakpoints(args.sessionId, stackFrames[0])
+                  : [];
+                result = {
+                  content: [{
+                    type: 'text',
+                    text: JSON.stringify({
+                      success: true,
+                      stackFrames,
+                      count: stackFrames.length,
+                      includeInternals,
+                      // Outermost first: each async body awaits the next
+                      awaitChain: asyncStack
+                        ? stackFrames.filter(frame => frame.asyncBody).map(frame => frame.asyncBody!.function).reverse()
+                        : undefined,
+                      exception: exception ?? undefined,
+                      hitBreakpoints: hitBreakpoints.length > 0
+                        ? hitBreakpoints.map(bp => ({
+                          id: bp.id,
+                          file: bp.file,
+                          line: bp.line,
+                          hitCondition: bp.hitCondition,
+                          hitCount: bp.hitCount
+                        }))
+                        : undefined
+                    })
+                  }]
+                };
               } catch (error) {
                 // Handle validation errors specifically
                 if (error instanceof SessionTerminatedError ||
@@ -885,6 +1208,35 @@ export class DebugMcpServer {
               result = await this.handleEvaluateExpression(args as { sessionId: string; expression: string });
               break;
             }
+            case 'disassemble': {
+              result = await this.handleDisassemble(args);
+              break;
+            }
+            case 'list_async_tasks': {
+              result = await this.handleListAsyncTasks(args);
+              break;
+            }
+            case 'set_variable': {
+              result = await this.handleSetVariable(args);
+              break;
+            }
+            case 'get_step_in_targets': {
+              result = await this.handleGetStepInTargets(args);
+              break;
+            }
+            case 'debug_rust_test': {
+              result = await this.handleDebugRustTest(args, this.createLaunchPreparationOptions(request, extra));
+              break;
+            }
+            case 'list_rust_targets': {
+              result = await this.handleListRustTargets(args);
+              break;
+            }
+            case 'read_memory':
+            case 'write_memory': {
+              result = await this.handleMemoryTool(toolName, args);
+              break;
+            }
             case 'get_source_context': {
               result = await this.handleGetSourceContext(args as { sessionId: string; file: string; line: number; linesContext?: number });
               break;
@@ -953,6 +1305,345 @@ export class DebugMcpServer {
     }
   }
 
+  private async handleSetExceptionBreakpoints(args: { sessionId: string; filters: string[] }): Promise<ServerResult> {
+    if (!args.sessionId || !Array.isArray(args.filters)) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+    if (args.filters.some(filter => typeof filter !== 'string' || filter.length === 0)) {
+      throw new McpError(McpErrorCode.InvalidParams, 'filters must be an array of non-empty strings');
+    }
+
+    try {
+      const exceptionResult = await this.setExceptionBreakpoints(args.sessionId, args.filters);
+
+      this.logger.info('tool:set_exception_breakpoints', {
+        sessionId: args.sessionId,
+        sessionName: this.getSessionName(args.sessionId),
+        filters: exceptionResult.filters,
+        applied: exceptionResult.applied,
+        success: exceptionResult.success,
+        timestamp: Date.now()
+      });
+
+      const response: Record<string, unknown> = {
+        success: exceptionResult.success,
+        filters: exceptionResult.filters,
+        applied: exceptionResult.applied,
+        message: exceptionResult.success
+          ? exceptionResult.applied
+            ? `Exception breakpoints set: ${exceptionResult.filters.join(', ') || '(none)'}`
+            : `Exception breakpoints queued for launch: ${exceptionResult.filters.join(', ') || '(none)'}`
+          : undefined
+      };
+      if (exceptionResult.breakpoints) {
+        response.breakpoints = exceptionResult.breakpoints;
+      }
+      if (exceptionResult.error) {
+        response.error = exceptionResult.error;
+      }
+      return { content: [{ type: 'text', text: JSON.stringify(response) }] };
+    } catch (error) {
+      // Handle session state errors specifically
+      if (error instanceof SessionTerminatedError ||
+        (error instanceof McpError &&
+          (error.message.includes('terminated') ||
+            (error.message.includes('not found') && error.message.includes('Session'))))) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: error.message }) }] };
+      }
+      throw error;
+    }
+  }
+
+  /**
+   * Check `{expr}` placeholders in a logpoint message; returns an error message or undefined
+   */
+  private validateLogMessage(logMessage: string): string | undefined {
+    if (logMessage.length === 0) {
+      return 'logMessage must not be empty';
+    }
+    let depth = 0;
+    let expressionStart = -1;
+    for (let i = 0; i < logMessage.length; i++) {
+      const ch = logMessage[i];
+      if (ch === '{') {
+        if (depth === 0) {
+          expressionStart = i;
+        }
+        depth++;
+      } else if (ch === '}') {
+        if (depth === 0) {
+          return `logMessage has an unmatched '}' at position ${i}`;
+        }
+        depth--;
+        if (depth === 0 && logMessage.slice(expressionStart + 1, i).trim().length === 0) {
+          return `logMessage has an empty '{}' placeholder at position ${expressionStart}`;
+        }
+      }
+    }
+    if (depth !== 0) {
+      return `logMessage has an unmatched '{' at position ${expressionStart}`;
+    }
+    return undefined;
+  }
+
+  private async handleGetDebugOutput(args: ToolArguments): Promise<ServerResult> {
+    if (!args.sessionId) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+    if (args.limit !== undefined && (!Number.isInteger(args.limit) || args.limit <= 0)) {
+      throw new McpError(McpErrorCode.InvalidParams, 'limit must be a positive integer');
+    }
+
+    try {
+      // Deliberately no lifecycle check: output stays readable after the session terminates
+      if (!this.sessionManager.getSession(args.sessionId)) {
+        throw new McpError(McpErrorCode.InvalidParams, `Session not found: ${args.sessionId}`);
+      }
+      const output = this.sessionManager.getOutput(args.sessionId, {
+        sinceSeq: args.sinceSeq,
+        category: args.category,
+        limit: args.limit
+      });
+
+      this.logger.info('tool:get_debug_output', {
+        sessionId: args.sessionId,
+        sessionName: this.getSessionName(args.sessionId),
+        count: output.entries.length,
+        lastSeq: output.lastSeq,
+        timestamp: Date.now()
+      });
+
+      return {
+        content: [{
+          type: 'text',
+          text: JSON.stringify({
+            success: true,
+            entries: output.entries,
+            count: output.entries.length,
+            lastSeq: output.lastSeq,
+            truncated: output.truncated
+          })
+        }]
+      };
+    } catch (error) {
+      if (error instanceof McpError && error.message.includes('not found') && error.message.includes('Session')) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: error.message }) }] };
+      }
+      throw error;
+    }
+  }
+
+  private async handleSetFunctionBreakpoint(args: { sessionId: string; name: string; condition?: string }): Promise<ServerResult> {
+    if (!args.sessionId || typeof args.name !== 'string' || args.name.trim().length === 0) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+
+    try {
+      const functionResult = await this.setFunctionBreakpoint(args.sessionId, args.name.trim(), args.condition);
+      const breakpoint = functionResult.breakpoint;
+      if (!breakpoint) {
+        return {
+          content: [{
+            type: 'text',
+            text: JSON.stringify({ success: false, name: args.name.trim(), error: functionResult.error })
+          }]
+        };
+      }
+
+      this.logger.info('tool:set_function_breakpoint', {
+        sessionId: args.sessionId,
+        sessionName: this.getSessionName(args.sessionId),
+        breakpointId: breakpoint.id,
+        function: breakpoint.name,
+        applied: functionResult.applied,
+        verified: breakpoint.verified,
+        locations: breakpoint.locations.length,
+        timestamp: Date.now()
+      });
+
+      let message: string | undefined;
+      if (functionResult.success) {
+        if (!functionResult.applied) {
+          message = `Function breakpoint on ${breakpoint.name} queued for launch`;
+        } else if (breakpoint.verified) {
+          message = `Function breakpoint set on ${breakpoint.name} (${breakpoint.locations.length} location(s))`;
+        } else {
+          message = `Function breakpoint on ${breakpoint.name} is not resolved yet${breakpoint.message ? `: ${breakpoint.message}` : ''}`;
+        }
+      }
+
+      const response: Record<string, unknown> = {
+        success: functionResult.success,
+        breakpointId: breakpoint.id,
+        name: breakpoint.name,
+        condition: breakpoint.condition,
+        verified: breakpoint.verified,
+        applied: functionResult.applied,
+        locations: breakpoint.locations,
+        message
+      };
+      if (functionResult.error) {
+        response.error = functionResult.error;
+      }
+      return { content: [{ type: 'text', text: JSON.stringify(response) }] };
+    } catch (error) {
+      // Handle session state errors specifically
+      if (error instanceof SessionTerminatedError ||
+        (error instanceof McpError &&
+          (error.message.includes('terminated') ||
+            (error.message.includes('not found') && error.message.includes('Session'))))) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: error.message }) }] };
+      }
+      throw error;
+    }
+  }
+
+  private async handleSetInstructionBreakpoint(args: ToolArguments): Promise<ServerResult> {
+    if (!args.sessionId || typeof args.address !== 'string' || args.address.trim().length === 0) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+
+    try {
+      const instructionResult = await this.setInstructionBreakpoint(args.sessionId, args.address.trim(), {
+        condition: args.condition,
+        hitCondition: args.hitCondition
+      });
+      const breakpoint = instructionResult.breakpoint;
+
+      this.logger.info('tool:set_instruction_breakpoint', {
+        sessionId: args.sessionId,
+        sessionName: this.getSessionName(args.sessionId),
+        breakpointId: breakpoint?.id,
+        address: breakpoint?.address,
+        verified: breakpoint?.verified,
+        timestamp: Date.now()
+      });
+
+      let message: string | undefined;
+      if (instructionResult.success && breakpoint) {
+        message = breakpoint.verified
+          ? `Instruction breakpoint set at ${breakpoint.address}`
+          : `Instruction breakpoint at ${breakpoint.address} is not verified${breakpoint.message ? `: ${breakpoint.message}` : ''}`;
+      }
+
+      const response: Record<string, unknown> = {
+        success: instructionResult.success,
+        breakpointId: breakpoint?.id,
+        address: breakpoint?.address,
+        condition: breakpoint?.condition,
+        hitCondition: breakpoint?.hitCondition,
+        verified: breakpoint?.verified ?? false,
+        location: breakpoint?.location,
+        message
+      };
+      if (instructionResult.error) {
+        response.error = instructionResult.error;
+      }
+      return { content: [{ type: 'text', text: JSON.stringify(response) }] };
+    } catch (error) {
+      // Handle session state errors specifically
+      if (error instanceof SessionTerminatedError ||
+        error instanceof ProxyNotRunningError ||
+        (error instanceof McpError &&
+          (error.message.includes('terminated') ||
+            (error.message.includes('not found') && error.message.includes('Session'))))) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: error.message }) }] };
+      }
+      throw error;
+    }
+  }
+
+  private async handleRemoveFunctionBreakpoint(args: ToolArguments): Promise<ServerResult> {
+    if (!args.sessionId || !args.breakpointId) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+
+    try {
+      const removal = await this.removeFunctionBreakpoint(args.sessionId, args.breakpointId);
+
+      this.logger.info('tool:remove_function_breakpoint', {
+        sessionId: args.sessionId,
+        sessionName: this.getSessionName(args.sessionId),
+        breakpointId: args.breakpointId,
+        function: removal.breakpoint?.name,
+        applied: removal.applied,
+        success: removal.success,
+        timestamp: Date.now()
+      });
+
+      const response: Record<string, unknown> = {
+        success: removal.success,
+        breakpointId: args.breakpointId,
+        name: removal.breakpoint?.name,
+        applied: removal.applied
+      };
+      if (removal.error) {
+        response.error = removal.error;
+      } else {
+        response.message = `Function breakpoint on ${removal.breakpoint!.name} removed`;
+      }
+      return { content: [{ type: 'text', text: JSON.stringify(response) }] };
+    } catch (error) {
+      // Handle session state errors specifically
+      if (error instanceof SessionTerminatedError ||
+        (error instanceof McpError &&
+          (error.message.includes('terminated') ||
+            (error.message.includes('not found') && error.message.includes('Session'))))) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: error.message }) }] };
+      }
+      throw error;
+    }
+  }
+
+  private async handleDataBreakpointTool(toolName: string, args: ToolArguments): Promise<ServerResult> {
+    if (!args.sessionId) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+    if (toolName === 'remove_data_breakpoint' ? !args.breakpointId : !args.name) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+    if (args.accessType !== undefined && !['write', 'read', 'readWrite'].includes(args.accessType)) {
+      throw new McpError(McpErrorCode.InvalidParams, "accessType must be one of 'write', 'read', 'readWrite'");
+    }
+
+    try {
+      let dataResult: DataBreakpointResult;
+      if (toolName === 'get_data_breakpoint_info') {
+        dataResult = await this.getDataBreakpointInfo(args.sessionId, args.name!, args.variablesReference, args.frameId);
+      } else if (toolName === 'set_data_breakpoint') {
+        dataResult = await this.setDataBreakpoint(args.sessionId, args.name!, args.accessType ?? 'write', {
+          variablesReference: args.variablesReference,
+          frameId: args.frameId,
+          condition: args.condition
+        });
+      } else {
+        dataResult = await this.removeDataBreakpoint(args.sessionId, args.breakpointId!);
+      }
+
+      this.logger.info(`tool:${toolName}`, {
+        sessionId: args.sessionId,
+        sessionName: this.getSessionName(args.sessionId),
+        name: dataResult.name,
+        breakpointId: dataResult.breakpoint?.id ?? args.breakpointId,
+        success: dataResult.success,
+        remainingSlots: dataResult.remainingSlots,
+        timestamp: Date.now()
+      });
+
+      return { content: [{ type: 'text', text: JSON.stringify(dataResult) }] };
+    } catch (error) {
+      // Handle session state errors specifically
+      if (error instanceof SessionTerminatedError ||
+        error instanceof ProxyNotRunningError ||
+        (error instanceof McpError &&
+          (error.message.includes('terminated') ||
+            (error.message.includes('not found') && error.message.includes('Session'))))) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: error.message }) }] };
+      }
+      throw error;
+    }
+  }
+
   private async handlePause(args: { sessionId: string }): Promise<ServerResult> {
     try {
       this.validateSession(args.sessionId);
@@ -1036,6 +1727,446 @@ export class DebugMcpServer {
     }
   }
 
+  private async handleSetVariable(args: ToolArguments): Promise<ServerResult> {
+    if (!args.sessionId || typeof args.variablesReference !== 'number' ||
+      typeof args.name !== 'string' || args.name.length === 0 || typeof args.value !== 'string') {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+
+    try {
+      const setResult = await this.setVariable(args.sessionId, args.variablesReference, args.name, args.value);
+
+      this.logger.info('tool:set_variable', {
+        sessionId: args.sessionId,
+        sessionName: this.getSessionName(args.sessionId),
+        variablesReference: args.variablesReference,
+        name: args.name,
+        success: setResult.success,
+        timestamp: Date.now()
+      });
+
+      return { content: [{ type: 'text', text: JSON.stringify(setResult) }] };
+    } catch (error) {
+      // Handle session state errors specifically
+      if (error instanceof SessionTerminatedError ||
+        error instanceof ProxyNotRunningError ||
+        (error instanceof McpError &&
+          (error.message.includes('terminated') ||
+            (error.message.includes('not found') && error.message.includes('Session'))))) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: error.message }) }] };
+      }
+      throw error;
+    }
+  }
+
+  private async handleDebugRustTest(args: ToolArguments, hooks: LaunchPreparationOptions = {}): Promise<ServerResult> {
+    if (!args.sessionId || !args.path || !args.testName) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+
+    try {
+      this.validateSession(args.sessionId);
+      const session = this.sessionManager.getSession(args.sessionId);
+      if (session?.language !== DebugLanguage.RUST) {
+        throw new McpError(McpErrorCode.InvalidParams, `debug_rust_test requires a Rust session (session language: ${session?.language})`);
+      }
+
+      const pathCheck = await this.fileChecker.checkExists(args.path);
+      if (!pathCheck.exists) {
+        throw new McpError(McpErrorCode.InvalidParams,
+          `Path not found: '${args.path}'\nLooked for: '${pathCheck.effectivePath}'${pathCheck.errorMessage ? `\nError: ${pathCheck.errorMessage}` : ''}`);
+      }
+
+      const rustAdapter = await import('@debugmcp/adapter-rust');
+      // The same cargo options start_debugging takes apply to the test build
+      const cargo = (args.dapLaunchArgs as { cargo?: CargoBuildOptions } | undefined)?.cargo ?? {};
+      let launch: Awaited<ReturnType<typeof rustAdapter.resolveRustTest>>;
+      try {
+        launch = await rustAdapter.resolveRustTest(pathCheck.effectivePath, args.testName, {
+          build: {
+            release: cargo.release,
+            profile: cargo.profile,
+            features: cargo.features,
+            allFeatures: cargo.allFeatures,
+            noDefaultFeatures: cargo.noDefaultFeatures,
+            target: cargo.target,
+            package: cargo.package
+          },
+          target: args.testTarget ? { kind: 'test', name: args.testTarget } : undefined,
+          hooks,
+          logger: this.logger
+        });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        const data = getBuildFailureData(error);
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: message, ...(data ? { data } : {}) }) }] };
+      }
+
+      let breakpoint: Breakpoint | undefined;
+      if (launch.location) {
+        breakpoint = await this.setBreakpoint(args.sessionId, launch.location.file, launch.location.line);
+      }
+
+      const debugResult = await this.startDebugging(
+        args.sessionId,
+        launch.executable,
+        launch.args,
+        args.dapLaunchArgs,
+        undefined,
+        { cwd: launch.cwd }
+      );
+
+      this.logger.info('tool:debug_rust_test', {
+        sessionId: args.sessionId,
+        sessionName: this.getSessionName(args.sessionId),
+        testName: launch.testName,
+        executable: launch.executable,
+        breakpointLine: breakpoint?.line,
+        success: debugResult.success,
+        timestamp: Date.now()
+      });
+
+      const response: Record<string, unknown> = {
+        success: debugResult.success,
+        state: debugResult.state,
+        testName: launch.testName,
+        executable: launch.executable,
+        args: launch.args,
+        breakpoint: breakpoint
+          ? { id: breakpoint.id, file: breakpoint.file, line: breakpoint.line, verified: breakpoint.verified }
+          : undefined
+      };
+      if (debugResult.error) {
+        response.error = debugResult.error;
+      } else if (!breakpoint) {
+        response.message = `Could not locate the body of ${launch.testName}; no breakpoint was set`;
+      }
+      return { content: [{ type: 'text', text: JSON.stringify(response) }] };
+    } catch (error) {
+      // Handle session state errors specifically
+      if (error instanceof SessionTerminatedError ||
+        error instanceof ProxyNotRunningError ||
+        (error instanceof McpError &&
+          (error.message.includes('terminated') ||
+            (error.message.includes('not found') && error.message.includes('Session'))))) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: error.message }) }] };
+      }
+      throw error;
+    }
+  }
+
+  private async handleListRustTargets(args: ToolArguments): Promise<ServerResult> {
+    if (!args.path) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+
+    const pathCheck = await this.fileChecker.checkExists(args.path);
+    if (!pathCheck.exists) {
+      throw new McpError(McpErrorCode.InvalidParams,
+        `Path not found: '${args.path}'\nLooked for: '${pathCheck.effectivePath}'${pathCheck.errorMessage ? `\nError: ${pathCheck.errorMessage}` : ''}`);
+    }
+
+    try {
+      const rustAdapter = await import('@debugmcp/adapter-rust');
+      const listing = await rustAdapter.listRustTargets(pathCheck.effectivePath);
+
+      this.logger.info('tool:list_rust_targets', {
+        path: pathCheck.effectivePath,
+        workspaceRoot: listing.workspaceRoot,
+        targetCount: listing.targets.length,
+        timestamp: Date.now()
+      });
+
+      return {
+        content: [{
+          type: 'text',
+          text: JSON.stringify({
+            success: true,
+            workspaceRoot: listing.workspaceRoot,
+            targetDirectory: listing.targetDirectory,
+            targets: listing.targets,
+            count: listing.targets.length
+          })
+        }]
+      };
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: message }) }] };
+    }
+  }
+
+  private async handleGetStepInTargets(args: ToolArguments): Promise<ServerResult> {
+    if (!args.sessionId) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+
+    try {
+      const targetsResult = await this.getStepInTargets(args.sessionId, args.frameId);
+
+      this.logger.info('tool:get_step_in_targets', {
+        sessionId: args.sessionId,
+        sessionName: this.getSessionName(args.sessionId),
+        frameId: targetsResult.frameId,
+        targetCount: targetsResult.targets.length,
+        success: targetsResult.success,
+        timestamp: Date.now()
+      });
+
+      const response: Record<string, unknown> = {
+        success: targetsResult.success,
+        frameId: targetsResult.frameId,
+        targets: targetsResult.targets,
+        count: targetsResult.targets.length
+      };
+      if (targetsResult.error) {
+        response.error = targetsResult.error;
+      } else if (targetsResult.targets.length === 0) {
+        response.message = 'No calls on the current line; step_into will behave like step_over';
+      }
+      return { content: [{ type: 'text', text: JSON.stringify(response) }] };
+    } catch (error) {
+      // Handle session state errors specifically
+      if (error instanceof SessionTerminatedError ||
+        error instanceof ProxyNotRunningError ||
+        (error instanceof McpError &&
+          (error.message.includes('terminated') ||
+            (error.message.includes('not found') && error.message.includes('Session'))))) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: error.message }) }] };
+      }
+      throw error;
+    }
+  }
+
+  private resolveMemoryReference(args: ToolArguments): string {
+    const hasReference = typeof args.memoryReference === 'string' && args.memoryReference.trim().length > 0;
+    const hasAddress = typeof args.address === 'string' && args.address.trim().length > 0;
+    if (hasReference === hasAddress) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Provide exactly one of memoryReference or address');
+    }
+    if (hasReference) {
+      return args.memoryReference!.trim();
+    }
+    const address = normalizeAddress(args.address!);
+    if (!address) {
+      throw new McpError(McpErrorCode.InvalidParams, `Invalid address: ${args.address}`);
+    }
+    return address;
+  }
+
+  private async handleMemoryTool(toolName: string, args: ToolArguments): Promise<ServerResult> {
+    if (!args.sessionId) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+    const memoryReference = this.resolveMemoryReference(args);
+    const offset = args.offset ?? 0;
+    if (!Number.isInteger(offset)) {
+      throw new McpError(McpErrorCode.InvalidParams, 'offset must be an integer');
+    }
+
+    try {
+      if (toolName === 'read_memory') {
+        const count = args.count ?? 64;
+        if (!Number.isInteger(count) || count < 1 || count > MAX_MEMORY_READ_BYTES) {
+          throw new McpError(McpErrorCode.InvalidParams, `count must be an integer between 1 and ${MAX_MEMORY_READ_BYTES}`);
+        }
+
+        const readResult = await this.readMemory(args.sessionId, memoryReference, count, offset);
+
+        this.logger.info('tool:read_memory', {
+          sessionId: args.sessionId,
+          sessionName: this.getSessionName(args.sessionId),
+          memoryReference,
+          offset,
+          count,
+          bytesRead: readResult.bytes?.length ?? 0,
+          success: readResult.success,
+          timestamp: Date.now()
+        });
+
+        if (!readResult.success || !readResult.bytes || !readResult.address) {
+          return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: readResult.error ?? 'Memory read failed' }) }] };
+        }
+        return {
+          content: [{
+            type: 'text',
+            text: JSON.stringify({
+              success: true,
+              address: readResult.address,
+              count: readResult.bytes.length,
+              unreadableBytes: readResult.unreadableBytes ?? 0,
+              hexDump: formatHexDump(readResult.bytes, readResult.address),
+              interpretations: interpretMemory(
+                readResult.bytes,
+                this.sessionManager.getSession(args.sessionId)?.targetArchitecture
+              )
+            })
+          }]
+        };
+      }
+
+      const bytes = typeof args.data === 'string' ? parseHexBytes(args.data) : undefined;
+      if (!bytes) {
+        throw new McpError(McpErrorCode.InvalidParams, 'data must be a non-empty hex byte string, e.g. "2a 00 00 00"');
+      }
+      const writeResult = await this.writeMemory(args.sessionId, memoryReference, bytes, {
+        offset,
+        allowPartial: args.allowPartial
+      });
+
+      this.logger.info('tool:write_memory', {
+        sessionId: args.sessionId,
+        sessionName: this.getSessionName(args.sessionId),
+        memoryReference,
+        offset,
+        bytes: bytes.length,
+        bytesWritten: writeResult.bytesWritten,
+        success: writeResult.success,
+        timestamp: Date.now()
+      });
+
+      const response: Record<string, unknown> = {
+        success: writeResult.success,
+        memoryReference,
+        offset,
+        bytesWritten: writeResult.bytesWritten ?? 0
+      };
+      if (writeResult.error) {
+        response.error = writeResult.error;
+      }
+      return { content: [{ type: 'text', text: JSON.stringify(response) }] };
+    } catch (error) {
+      // Handle session state errors specifically
+      if (error instanceof SessionTerminatedError ||
+        error instanceof ProxyNotRunningError ||
+        (error instanceof McpError &&
+          (error.message.includes('terminated') ||
+            (error.message.includes('not found') && error.message.includes('Session'))))) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: error.message }) }] };
+      }
+      throw error;
+    }
+  }
+
+  private async handleDisassemble(args: ToolArguments): Promise<ServerResult> {
+    if (!args.sessionId) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+    const context = args.instructionsContext ?? 10;
+    if (!Number.isInteger(context) || context < 0 || context > 100) {
+      throw new McpError(McpErrorCode.InvalidParams, 'instructionsContext must be an integer between 0 and 100');
+    }
+
+    try {
+      const disassembly = await this.disassemble(args.sessionId, args.frameId, context);
+
+      this.logger.info('tool:disassemble', {
+        sessionId: args.sessionId,
+        sessionName: this.getSessionName(args.sessionId),
+        frameId: disassembly.frameId,
+        instructionPointer: disassembly.instructionPointer,
+        count: disassembly.instructions.length,
+        success: disassembly.success,
+        timestamp: Date.now()
+      });
+
+      if (!disassembly.success) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: disassembly.error }) }] };
+      }
+
+      return {
+        content: [{
+          type: 'text',
+          text: JSON.stringify({
+            success: true,
+            frameId: disassembly.frameId,
+            instructionPointer: disassembly.instructionPointer,
+            count: disassembly.instructions.length,
+            listing: await this.buildDisassemblyListing(disassembly.instructions)
+          })
+        }]
+      };
+    } catch (error) {
+      // Handle session state errors specifically
+      if (error instanceof SessionTerminatedError ||
+        error instanceof ProxyNotRunningError ||
+        (error instanceof McpError &&
+          (error.message.includes('terminated') ||
+            (error.message.includes('not found') && error.message.includes('Session'))))) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: error.message }) }] };
+      }
+      throw error;
+    }
+  }
+
+  /**
+   * Interleave instructions with the source line each run of instructions came from
+   */
+  private async buildDisassemblyListing(
+    instructions: DisassembledInstruction[]
+  ): Promise<Array<Record<string, unknown>>> {
+    const listing: Array<Record<string, unknown>> = [];
+    let previousKey: string | undefined;
+    for (const instruction of instructions) {
+      if (instruction.file && instruction.line !== undefined) {
+        const key = `${instruction.file}:${instruction.line}`;
+        if (key !== previousKey) {
+          previousKey = key;
+          let source: string | undefined;
+          try {
+            const lineContext = await this.lineReader.getLineContext(instruction.file, instruction.line, { contextLines: 0 });
+            source = lineContext?.lineContent;
+          } catch {
+            // Source may be unavailable (e.g. std library paths); keep the location only
+          }
+          listing.push({ kind: 'source', file: instruction.file, line: instruction.line, source });
+        }
+      }
+      listing.push({
+        kind: 'instruction',
+        address: instruction.address,
+        instructionBytes: instruction.instructionBytes,
+        instruction: instruction.instruction,
+        symbol: instruction.symbol,
+        isCurrent: instruction.isCurrent || undefined
+      });
+    }
+    return listing;
+  }
+
+  private async handleListAsyncTasks(args: ToolArguments): Promise<ServerResult> {
+    if (!args.sessionId) {
+      throw new McpError(McpErrorCode.InvalidParams, 'Missing required parameters');
+    }
+
+    try {
+      const listing = await this.listAsyncTasks(args.sessionId);
+
+      this.logger.info('tool:list_async_tasks', {
+        sessionId: args.sessionId,
+        sessionName: this.getSessionName(args.sessionId),
+        runtimes: listing.runtimes.length,
+        tasks: listing.runtimes.reduce((count, runtime) => count + runtime.tasks.length, 0),
+        success: listing.success,
+        timestamp: Date.now()
+      });
+
+      if (!listing.success) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: listing.error }) }] };
+      }
+      return { content: [{ type: 'text', text: JSON.stringify({ success: true, runtimes: listing.runtimes }) }] };
+    } catch (error) {
+      // Handle session state errors specifically
+      if (error instanceof SessionTerminatedError ||
+        error instanceof ProxyNotRunningError ||
+        (error instanceof McpError &&
+          (error.message.includes('terminated') ||
+            (error.message.includes('not found') && error.message.includes('Session'))))) {
+        return { content: [{ type: 'text', text: JSON.stringify({ success: false, error: error.message }) }] };
+      }
+      throw error;
+    }
+  }
+
   private async handleGetSourceContext(args: { sessionId: string, file: string, line: number, linesContext?: number }): Promise<ServerResult> {
     try {
       // Validate session
@@ -17,10 +17,14 @@ import {
 } from '@debugmcp/shared';
 import { ISessionStoreFactory } from '../factories/session-store-factory.js';
 import { IProxyManager } from '../proxy/proxy-manager.js';
+import type { LaunchedFunctionBreakpoint } from '../proxy/dap-proxy-interfaces.js';
 import { IProxyManagerFactory } from '../factories/proxy-manager-factory.js';
 import { IDebugTargetLauncher } from '@debugmcp/shared';
 import { IAdapterRegistry } from '@debugmcp/shared';
 
+// Output events retained per session; older entries are dropped first
+const MAX_SESSION_OUTPUT_ENTRIES = 1000;
+
 // Custom launch arguments interface extending DebugProtocol.LaunchRequestArguments
 export interface CustomLaunchRequestArguments extends DebugProtocol.LaunchRequestArguments {
   stopOnEntry?: boolean;
@@ -212,9 +216,10 @@ export class SessionManagerCore {
     const handlers = new Map<string, (...args: any[]) => void>(); // eslint-disable-line @typescript-eslint/no-explicit-any -- Event handlers require flexible argument signatures to support various event types
 
     // Named function for stopped event
-    const handleStopped = (threadId: number, reason: string) => {
+    const handleStopped = (threadId: number, reason: string, body?: DebugProtocol.StoppedEvent['body']) => {
       this.logger.debug(`[SessionManager] 'stopped' event handler called for session ${sessionId}`);
       this.logger.info(`[ProxyManager ${sessionId}] Stopped event: thread=${threadId}, reason=${reason}`);
+      session.lastStop = { threadId, reason, body };
 
       // Log debug state change with structured logging
       // Note: We don't have location info at this point, but that could be added later if needed
@@ -311,6 +316,18 @@ export class SessionManagerCore {
     proxyManager.on('adapter-configured', handleAdapterConfigured);
     handlers.set('adapter-configured', handleAdapterConfigured);
 
+    // Named function for the results of function breakpoints set during launch
+    const handleFunctionBreakpointsSet = (launched: LaunchedFunctionBreakpoint[]) => {
+      this.logger.debug(`[SessionManager] 'function-breakpoints-set' event handler called for session ${sessionId}`);
+      this.applyLaunchFunctionBreakpoints(session, launched).catch((error) => {
+        this.logger.warn(
+          `[ProxyManager ${sessionId}] Could not apply launch function breakpoint results: ${error instanceof Error ? error.message : String(error)}`
+        );
+      });
+    };
+    proxyManager.on('function-breakpoints-set', handleFunctionBreakpointsSet);
+    handlers.set('function-breakpoints-set', handleFunctionBreakpointsSet);
+
     // Named function for dry run complete event
     const handleDryRunComplete = (command: string, script: string) => {
       this.logger.debug(`[SessionManager] 'dry-run-complete' event handler called for session ${sessionId}`);
@@ -335,6 +352,34 @@ export class SessionManagerCore {
     proxyManager.on('error', handleError);
     handlers.set('error', handleError);
 
+    // Named function for generic DAP events; 'output' carries program output and logpoint messages
+    const handleDapEvent = (event: string, body: unknown) => {
+      if (event !== 'output' || !body || typeof body !== 'object') {
+        return;
+      }
+      const outputBody = body as DebugProtocol.OutputEvent['body'];
+      // Telemetry is adapter-internal and never useful to the user
+      if (typeof outputBody.output !== 'string' || outputBody.category === 'telemetry') {
+        return;
+      }
+      const seq = (session.outputSeq ?? 0) + 1;
+      session.outputSeq = seq;
+      if (!session.output) session.output = [];
+      session.output.push({
+        seq,
+        category: outputBody.category ?? 'console',
+        output: outputBody.output,
+        timestamp: Date.now(),
+        file: outputBody.source?.path,
+        line: outputBody.line
+      });
+      if (session.output.length > MAX_SESSION_OUTPUT_ENTRIES) {
+        session.output.splice(0, session.output.length - MAX_SESSION_OUTPUT_ENTRIES);
+      }
+    };
+    proxyManager.on('dap-event', handleDapEvent);
+    handlers.set('dap-event', handleDapEvent);
+
     // Named function for exit event
     const handleExit = (code: number | null, signal?: string) => {
       this.logger.debug(`[SessionManager] 'exit' event handler called for session ${sessionId}`);
@@ -394,6 +439,16 @@ export class SessionManagerCore {
     return this.cleanupProxyEventHandlers(session, proxyManager);
   }
 
+  // Overridden in SessionManagerOperations, which owns function breakpoint bookkeeping
+  protected async applyLaunchFunctionBreakpoints(
+    session: ManagedSession,
+    launched: LaunchedFunctionBreakpoint[]
+  ): Promise<void> {
+    this.logger.debug(
+      `[SessionManager] Ignoring ${launched.length} launch function breakpoint result(s) for session ${session.id}`
+    );
+  }
+
   // This method will be overridden in the main SessionManager class
   protected async handleAutoContinue(): Promise<void> {
     // Will be implemented in the main class that has access to continue method
@@ -12,9 +12,11 @@ import {
   JsDebugAdapterPolicy,
   RustAdapterPolicy,
   MockAdapterPolicy,
-  DebugLanguage
+  DebugLanguage,
+  ExceptionStopInfo
 } from '@debugmcp/shared';
 import { SessionManagerCore } from './session-manager-core.js';
+import type { SessionOutputEntry } from './session-store.js';
 import { DebugProtocol } from '@vscode/debugprotocol';
 
 /**
@@ -65,7 +67,8 @@ export class SessionManagerData extends SessionManagerCore {
         const vars = response.body.variables.map((v: DebugProtocol.Variable) => ({ 
             name: v.name, value: v.value, type: v.type || "<unknown_type>", 
             variablesReference: v.variablesReference,
-            expandable: v.variablesReference > 0 
+            expandable: v.variablesReference > 0,
+            ...(v.memoryReference ? { memoryReference: v.memoryReference } : {})
         }));
         this.logger.info(`[SM getVariables ${sessionId}] Parsed variables:`, vars.map(v => ({name: v.name, value: v.value, type: v.type}))); 
         return vars;
@@ -78,7 +81,12 @@ export class SessionManagerData extends SessionManagerCore {
     }
   }
 
-  async getStackTrace(sessionId: string, threadId?: number, includeInternals: boolean = false): Promise<StackFrame[]> {
+  async getStackTrace(
+    sessionId: string,
+    threadId?: number,
+    includeInternals: boolean = false,
+    asyncStack: boolean = false
+  ): Promise<StackFrame[]> {
     const session = this._getSessionById(sessionId);
     const currentThreadId = session.proxyManager?.getCurrentThreadId();
     this.logger.info(`[SM getStackTrace ${sessionId}] Entered. Requested threadId: ${threadId}, Current state: ${session.state}, Actual currentThreadId: ${currentThreadId}, includeInternals: ${includeInternals}`);
@@ -107,11 +115,16 @@ export class SessionManagerData extends SessionManagerCore {
         let frames: StackFrame[] = response.body.stackFrames.map((sf: DebugProtocol.StackFrame) => ({ 
             id: sf.id, name: sf.name, 
             file: sf.source?.path || sf.source?.name || "<unknown_source>", 
-            line: sf.line, column: sf.column
+            line: sf.line, column: sf.column,
+            ...(sf.instructionPointerReference ? { instructionPointerReference: sf.instructionPointerReference } : {})
         }));
         
         // Apply filtering using the language's policy
         const policy = this.selectPolicy(session.language);
+        if (asyncStack && policy.collapseAsyncFrames) {
+          frames = policy.collapseAsyncFrames(frames);
+          this.logger.info(`[SM getStackTrace ${sessionId}] Collapsed async runtime frames: ${frames.length} frames`);
+        }
         if (policy.filterStackFrames) {
           this.logger.info(`[SM getStackTrace ${sessionId}] Applying stack frame filtering for ${session.language}. Original count: ${frames.length}`);
           frames = policy.filterStackFrames(frames, includeInternals);
@@ -129,6 +142,91 @@ export class SessionManagerData extends SessionManagerCore {
     }
   }
 
+  /**
+   * Describe the exception/panic the session is currently stopped on.
+   * Returns null unless the last stop was caused by an exception breakpoint.
+   */
+  async getExceptionStopInfo(sessionId: string): Promise<ExceptionStopInfo | null> {
+    const session = this._getSessionById(sessionId);
+    const lastStop = session.lastStop;
+    if (!lastStop || lastStop.reason !== 'exception') {
+      return null;
+    }
+    if (!session.proxyManager || !session.proxyManager.isRunning() || session.state !== SessionState.PAUSED) {
+      return null;
+    }
+
+    let exceptionId: string | undefined;
+    let message = lastStop.body?.text || lastStop.body?.description;
+    try {
+      const response = await session.proxyManager.sendDapRequest<DebugProtocol.ExceptionInfoResponse>(
+        'exceptionInfo',
+        { threadId: lastStop.threadId }
+      );
+      if (response?.body) {
+        exceptionId = response.body.exceptionId || undefined;
+        message = response.body.details?.message || response.body.description || message;
+      }
+    } catch (error) {
+      // Not every adapter implements exceptionInfo; the stopped event text is the fallback
+      this.logger.debug(`[SM getExceptionStopInfo ${sessionId}] exceptionInfo unavailable:`, error);
+    }
+
+    // Unwind the raise/panic machinery so the location points at user code
+    const frames = await this.getStackTrace(sessionId, lastStop.threadId, true);
+    const policy = this.selectPolicy(session.language);
+    const userFrameIndex = frames.findIndex(frame =>
+      frame.file !== '<unknown_source>' && !(policy.isPanicFrame?.(frame) ?? false)
+    );
+    const userFrames = userFrameIndex >= 0 ? frames.slice(userFrameIndex) : frames;
+    const locationFrame = userFrameIndex >= 0 ? frames[userFrameIndex] : undefined;
+
+    this.logger.info('debug:exception', {
+      sessionId,
+      sessionName: session.name,
+      exceptionId,
+      message,
+      unwoundFrames: Math.max(userFrameIndex, 0),
+      timestamp: Date.now()
+    });
+
+    return {
+      reason: lastStop.reason,
+      exceptionId,
+      message,
+      location: locationFrame
+        ? { file: locationFrame.file, line: locationFrame.line, column: locationFrame.column }
+        : undefined,
+      frames: userFrames,
+      unwoundFrames: Math.max(userFrameIndex, 0)
+    };
+  }
+
+  /**
+   * Read captured output (program output and logpoint messages) for a session.
+   * Pass the returned `lastSeq` as `sinceSeq` to fetch only newer entries.
+   */
+  getOutput(
+    sessionId: string,
+    options: { sinceSeq?: number; category?: string; limit?: number } = {}
+  ): { entries: SessionOutputEntry[]; lastSeq: number; truncated: boolean } {
+    const session = this._getSessionById(sessionId);
+    const sinceSeq = options.sinceSeq ?? 0;
+    let entries = (session.output ?? []).filter(entry => entry.seq > sinceSeq);
+    if (options.category) {
+      entries = entries.filter(entry => entry.category === options.category);
+    }
+
+    // Return the oldest entries first so a caller paging with sinceSeq never skips any
+    const limit = options.limit ?? 100;
+    const truncated = entries.length > limit;
+    if (truncated) {
+      entries = entries.slice(0, limit);
+    }
+    const lastSeq = entries.length > 0 ? entries[entries.length - 1].seq : Math.max(sinceSeq, 0);
+    return { entries, lastSeq, truncated };
+  }
+
   async getScopes(sessionId: string, frameId: number): Promise<DebugProtocol.Scope[]> {
     const session = this._getSessionById(sessionId);
     this.logger.info(`[SM getScopes ${sessionId}] Entered. frameId: ${frameId}, Current state: ${session.state}`);
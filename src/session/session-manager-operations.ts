@@ -6,19 +6,34 @@ import { v4 as uuidv4 } from 'uuid';
 import {
   Breakpoint,
   SessionState,
-  SessionLifecycleState
+  SessionLifecycleState,
+  DebugFeature,
+  type DataBreakpoint,
+  type DataBreakpointAccessType,
+  type FunctionBreakpoint,
+  type InstructionBreakpoint,
+  type SteppingGranularity,
+  type StepInTarget,
+  type Variable,
+  type BreakpointLocation,
+  type DisassembledInstruction,
+  type ExceptionStopInfo,
+  type AsyncRuntimeInfo
 } from '@debugmcp/shared';
 import { ManagedSession, ToolchainValidationState } from './session-store.js';
 import { DebugProtocol } from '@vscode/debugprotocol';
 import path from 'path';
 import { ProxyConfig } from '../proxy/proxy-config.js';
+import type { LaunchedFunctionBreakpoint } from '../proxy/dap-proxy-interfaces.js';
 import { ErrorMessages } from '../utils/error-messages.js';
+import { getBuildFailureData } from '../utils/build-failure.js';
 import { SessionManagerData } from './session-manager-data.js';
 import { CustomLaunchRequestArguments, DebugResult } from './session-manager-core.js';
 import {
   AdapterConfig,
   type GenericLaunchConfig,
-  type LanguageSpecificLaunchConfig
+  type LanguageSpecificLaunchConfig,
+  type LaunchPreparationOptions
 } from '@debugmcp/shared';
 import {
   SessionTerminatedError,
@@ -26,7 +41,7 @@ import {
   DebugSessionCreationError,
   PythonNotFoundError
 } from '../errors/debug-errors.js';
-import { McpError } from '@modelcontextprotocol/sdk/types.js';
+import { McpError, ErrorCode as McpErrorCode } from '@modelcontextprotocol/sdk/types.js';
 
 /**
  * Constants for expression evaluation preview formatting
@@ -68,11 +83,159 @@ export interface EvaluateResult {
   namedVariables?: number;
   indexedVariables?: number;
   presentationHint?: DebugProtocol.VariablePresentationHint;
+  /** Memory reference for read_memory/write_memory (when the adapter reports one) */
+  memoryReference?: string;
   error?: string;
   /** Structured error information with category and suggestions */
   errorInfo?: EvaluateErrorInfo;
 }
 
+/**
+ * Optional settings for source breakpoints
+ */
+export interface BreakpointOptions {
+  /** Log this message (with `{expr}` interpolation) instead of stopping */
+  logMessage?: string;
+  /** Only stop when the hit count satisfies this (e.g. `== 5`, `>= 3`, `% 2`) */
+  hitCondition?: string;
+}
+
+/**
+ * Result type for set exception breakpoints operations
+ */
+export interface ExceptionBreakpointsResult {
+  success: boolean;
+  filters: string[];
+  /** True when the filters were sent to a running adapter, false when queued for launch */
+  applied: boolean;
+  breakpoints?: Array<{ filter: string; verified: boolean; message?: string }>;
+  error?: string;
+}
+
+/**
+ * Result type for function breakpoint operations
+ */
+export interface FunctionBreakpointResult {
+  success: boolean;
+  /** Absent when nothing was stored, e.g. the adapter does not support function breakpoints */
+  breakpoint?: FunctionBreakpoint;
+  /** True when sent to a running adapter, false when queued for launch */
+  applied: boolean;
+  error?: string;
+}
+
+/**
+ * Result type for removing a function breakpoint
+ */
+export interface FunctionBreakpointRemovalResult {
+  success: boolean;
+  breakpoint?: FunctionBreakpoint;
+  /** True when the running adapter was updated, false when only the queued entry was dropped */
+  applied: boolean;
+  error?: string;
+}
+
+/**
+ * Result type for data breakpoint (watchpoint) operations
+ */
+export interface DataBreakpointResult {
+  success: boolean;
+  name?: string;
+  description?: string;
+  /** Access kinds the adapter allows for this variable */
+  accessTypes?: DataBreakpointAccessType[];
+  breakpoint?: DataBreakpoint;
+  /** Hardware watchpoint slots still free (when the adapter reports a limit) */
+  remainingSlots?: number;
+  error?: string;
+}
+
+/**
+ * Result type for instruction breakpoint operations
+ */
+export interface InstructionBreakpointResult {
+  success: boolean;
+  breakpoint?: InstructionBreakpoint;
+  error?: string;
+}
+
+/**
+ * Options for step operations
+ */
+export interface StepOptions {
+  /** Defaults to the adapter's own granularity (source lines) when omitted */
+  granularity?: SteppingGranularity;
+  /** Step-in target from getStepInTargets (stepInto only) */
+  targetId?: number;
+}
+
+/**
+ * Result type for listing the step-in targets of a frame's current line
+ */
+export interface StepInTargetsResult {
+  success: boolean;
+  frameId?: number;
+  targets: StepInTarget[];
+  error?: string;
+}
+
+/**
+ * Result type for setting a variable's value
+ */
+export interface SetVariableResult {
+  success: boolean;
+  name: string;
+  previousValue?: string;
+  value?: string;
+  type?: string;
+  variablesReference?: number;
+  /** Child variables after the update (for structured values) */
+  children?: Variable[];
+  error?: string;
+}
+
+/**
+ * Result type for reading process memory
+ */
+export interface MemoryReadResult {
+  success: boolean;
+  /** Address of the first byte read, as reported by the adapter */
+  address?: string;
+  bytes?: Buffer;
+  /** Bytes after the readable region that could not be read */
+  unreadableBytes?: number;
+  error?: string;
+}
+
+/**
+ * Result type for writing process memory
+ */
+export interface MemoryWriteResult {
+  success: boolean;
+  bytesWritten?: number;
+  error?: string;
+}
+
+/**
+ * Result type for disassembly around a frame's instruction pointer
+ */
+export interface DisassembleResult {
+  success: boolean;
+  frameId?: number;
+  instructionPointer?: string;
+  instructions: DisassembledInstruction[];
+  error?: string;
+}
+
+/**
+ * Result type for listing the tasks of the debuggee's async runtimes
+ */
+export interface AsyncTasksResult {
+  success: boolean;
+  runtimes: AsyncRuntimeInfo[];
+  error?: string;
+}
+
 /**
  * Debug operations functionality for session management
  */
@@ -83,7 +246,8 @@ export class SessionManagerOperations extends SessionManagerData {
     scriptArgs?: string[],
     dapLaunchArgs?: Partial<CustomLaunchRequestArguments>,
     dryRunSpawn?: boolean,
-    adapterLaunchConfig?: Record<string, unknown>
+    adapterLaunchConfig?: Record<string, unknown>,
+    launchOptions?: LaunchPreparationOptions
   ): Promise<LanguageSpecificLaunchConfig> {
     const sessionId = session.id;
 
@@ -123,14 +287,31 @@ export class SessionManagerOperations extends SessionManagerData {
     // Get free port for adapter
     const adapterPort = await this.findFreePort();
 
+    // Stop details from a previous run must not leak into this one
+    session.lastStop = undefined;
+
     const initialBreakpoints = Array.from(session.breakpoints.values()).map((bp) => {
       // Breakpoint file path has been validated by server.ts before reaching here
       return {
         file: bp.file, // Use the validated path
         line: bp.line,
         condition: bp.condition,
+        hitCondition: bp.hitCondition,
+        logMessage: bp.logMessage,
       };
     });
+    // Hit counts restart with the new process
+    for (const bp of session.breakpoints.values()) {
+      if (bp.hitCount !== undefined) {
+        bp.hitCount = 0;
+      }
+    }
+
+    const policy = this.selectPolicy(session.language);
+    const initialFunctionBreakpoints = Array.from(session.functionBreakpoints?.values() ?? []).map((bp) => ({
+      name: policy.normalizeFunctionBreakpointName?.(bp.name) ?? bp.name,
+      condition: bp.condition,
+    }));
 
     // Merge launch args
     const effectiveLaunchArgs = {
@@ -172,8 +353,12 @@ export class SessionManagerOperations extends SessionManagerData {
     const adapter = await this.adapterRegistry.create(session.language, adapterConfig);
 
     try {
-      transformedLaunchConfig = await adapter.transformLaunchConfig(genericLaunchConfig as GenericLaunchConfig);
+      transformedLaunchConfig = await adapter.transformLaunchConfig(genericLaunchConfig as GenericLaunchConfig, launchOptions);
     } catch (error) {
+      // Launching without the program that failed to build cannot work
+      if (getBuildFailureData(error)) {
+        throw error;
+      }
       this.logger.warn(
         `[SessionManager] transformLaunchConfig failed for ${session.language}: ${error instanceof Error ? error.message : String(error)
         }`
@@ -202,6 +387,46 @@ export class SessionManagerOperations extends SessionManagerData {
       this.sessionStore.update(sessionId, { toolchainValidation: undefined });
     }
 
+    // Record features the adapter cannot provide (e.g. data breakpoints on an old CodeLLDB)
+    const unsupportedFeatures: Record<string, string> = {};
+    if (adapter.checkFeatureSupport) {
+      try {
+        for (const feature of Object.values(DebugFeature)) {
+          const support = await adapter.checkFeatureSupport(feature);
+          if (!support.supported) {
+            unsupportedFeatures[feature] = support.reason ?? `${feature} is not supported`;
+          }
+        }
+      } catch (error) {
+        this.logger.warn(
+          `[SessionManager] Feature support check failed for ${session.language}: ${error instanceof Error ? error.message : String(error)}`
+        );
+      }
+    }
+    // Adapters without the capability ignore `granularity` and step by line
+    const capabilities = typeof adapter.getCapabilities === 'function' ? adapter.getCapabilities() : undefined;
+    if (!capabilities?.supportsSteppingGranularity && !unsupportedFeatures[DebugFeature.STEPPING_GRANULARITY]) {
+      unsupportedFeatures[DebugFeature.STEPPING_GRANULARITY] =
+        `The ${session.language} debug adapter does not support stepping granularity`;
+    }
+    let targetArchitecture: string | undefined;
+    if (adapter.getTargetArchitecture && transformedLaunchConfig) {
+      try {
+        targetArchitecture = await adapter.getTargetArchitecture(transformedLaunchConfig);
+      } catch (error) {
+        this.logger.warn(
+          `[SessionManager] Could not determine the target architecture for ${session.language}: ${error instanceof Error ? error.message : String(error)}`
+        );
+      }
+    }
+    // Watchpoints and instruction breakpoints are address based and do not survive a relaunch
+    this.sessionStore.update(sessionId, {
+      unsupportedFeatures,
+      targetArchitecture,
+      dataBreakpoints: new Map(),
+      instructionBreakpoints: new Map(),
+    });
+
     // Use the adapter to resolve the executable path
     let resolvedExecutablePath: string;
     try {
@@ -301,6 +526,8 @@ export class SessionManagerOperations extends SessionManagerData {
       stopOnEntry: stopOnEntryFlag,
       justMyCode: justMyCodeFlag,
       initialBreakpoints,
+      exceptionFilters: session.exceptionFilters?.length ? [...session.exceptionFilters] : undefined,
+      initialFunctionBreakpoints: initialFunctionBreakpoints.length ? initialFunctionBreakpoints : undefined,
       dryRunSpawn: dryRunSpawn === true,
       launchConfig: launchConfigData,
       adapterCommand, // Pass the adapter command
@@ -386,7 +613,8 @@ export class SessionManagerOperations extends SessionManagerData {
     scriptArgs?: string[],
     dapLaunchArgs?: Partial<CustomLaunchRequestArguments>,
     dryRunSpawn?: boolean,
-    adapterLaunchConfig?: Record<string, unknown>
+    adapterLaunchConfig?: Record<string, unknown>,
+    launchOptions?: LaunchPreparationOptions
   ): Promise<DebugResult> {
     const session = this._getSessionById(sessionId);
     this.logger.info(
@@ -433,7 +661,15 @@ export class SessionManagerOperations extends SessionManagerData {
         }
 
         // Start the proxy manager
-        await this.startProxyManager(session, scriptPath, scriptArgs, dapLaunchArgs, dryRunSpawn, adapterLaunchConfig);
+        await this.startProxyManager(
+          session,
+          scriptPath,
+          scriptArgs,
+          dapLaunchArgs,
+          dryRunSpawn,
+          adapterLaunchConfig,
+          launchOptions
+        );
         this.logger.info(`[SessionManager] ProxyManager started for session ${sessionId}`);
 
         // CI Debug: After startProxyManager
@@ -550,7 +786,15 @@ export class SessionManagerOperations extends SessionManagerData {
 
       // Normal (non-dry-run) flow
       // Start the proxy manager
-      const launchConfigData = await this.startProxyManager(session, scriptPath, scriptArgs, dapLaunchArgs, dryRunSpawn, adapterLaunchConfig);
+      const launchConfigData = await this.startProxyManager(
+        session,
+        scriptPath,
+        scriptArgs,
+        dapLaunchArgs,
+        dryRunSpawn,
+        adapterLaunchConfig,
+        launchOptions
+      );
       this.logger.info(`[SessionManager] ProxyManager started for session ${sessionId}`);
 
       // Perform language-specific handshake if required
@@ -678,6 +922,12 @@ export class SessionManagerOperations extends SessionManagerData {
         `[SessionManager] Debugging started for session ${sessionId}. State: ${finalState}`
       );
 
+      // A panic/exception breakpoint may already have fired before we return
+      const exception =
+        finalState === SessionState.PAUSED
+          ? await this.getExceptionStopInfo(sessionId).catch(() => null)
+          : null;
+
       return {
         success: true,
         state: finalState,
@@ -685,11 +935,14 @@ export class SessionManagerOperations extends SessionManagerData {
           message: `Debugging started for ${scriptPath}. Current state: ${finalState}`,
           reason:
             finalState === SessionState.PAUSED
-              ? dapLaunchArgs?.stopOnEntry
-                ? 'entry'
-                : 'breakpoint'
+              ? exception
+                ? 'exception'
+                : dapLaunchArgs?.stopOnEntry
+                  ? 'entry'
+                  : 'breakpoint'
               : undefined,
           stopOnEntrySuccessful: !!dapLaunchArgs?.stopOnEntry && finalState === SessionState.PAUSED,
+          ...(exception ? { exception } : {}),
         },
       };
     } catch (error) {
@@ -753,8 +1006,11 @@ export class SessionManagerOperations extends SessionManagerData {
         session.toolchainValidation;
       const incompatibleToolchain =
         Boolean(toolchainValidation) && toolchainValidation?.compatible === false;
+      const buildFailure = getBuildFailureData(error);
+      const buildFailed = Boolean(buildFailure);
 
-      if (incompatibleToolchain) {
+      if (incompatibleToolchain || buildFailed) {
+        // Nothing was launched; the session can be started again once fixed
         this._updateSessionState(session, SessionState.CREATED);
         this.sessionStore.update(sessionId, {
           sessionLifecycle: SessionLifecycleState.CREATED,
@@ -778,135 +1034,1217 @@ export class SessionManagerOperations extends SessionManagerData {
         errorType = error.constructor.name || 'Error';
       }
 
-      if (incompatibleToolchain && toolchainValidation) {
-        const behavior = (toolchainValidation.behavior ?? 'warn').toLowerCase();
-        const canContinue = behavior !== 'error';
-        const updatedSession = this._getSessionById(sessionId);
+      if (buildFailure) {
+        return {
+          success: false,
+          error: errorMessage,
+          state: this._getSessionById(sessionId).state,
+          data: buildFailure,
+          errorType,
+          errorCode,
+        };
+      }
+
+      if (incompatibleToolchain && toolchainValidation) {
+        const behavior = (toolchainValidation.behavior ?? 'warn').toLowerCase();
+        const canContinue = behavior !== 'error';
+        const updatedSession = this._getSessionById(sessionId);
+        return {
+          success: false,
+          error: 'MSVC_TOOLCHAIN_DETECTED',
+          state: updatedSession.state,
+          data: {
+            message: toolchainValidation.message ?? errorMessage,
+            toolchainValidation,
+          },
+          canContinue,
+          errorType,
+          errorCode,
+        };
+      }
+
+      return { success: false, error: errorMessage, state: session.state, errorType, errorCode };
+    }
+  }
+
+
+  async setBreakpoint(
+    sessionId: string,
+    file: string,
+    line: number,
+    condition?: string,
+    options: BreakpointOptions = {}
+  ): Promise<Breakpoint> {
+    const session = this._getSessionById(sessionId);
+
+    // Check if session is terminated
+    if (session.sessionLifecycle === SessionLifecycleState.TERMINATED) {
+      throw new SessionTerminatedError(sessionId);
+    }
+
+    const bpId = uuidv4();
+
+    // The file path has been validated and translated by server.ts before reaching here
+    this.logger.info(
+      `[SessionManager setBreakpoint] Using validated file path "${file}" for session ${sessionId}`
+    );
+
+    // A logpoint the adapter cannot honour would silently become a stopping breakpoint
+    const logpointUnsupported = session.unsupportedFeatures?.[DebugFeature.LOG_POINTS];
+    if (options.logMessage !== undefined && logpointUnsupported) {
+      throw new McpError(McpErrorCode.InvalidRequest, `Logpoints unavailable: ${logpointUnsupported}`);
+    }
+
+    if (options.hitCondition !== undefined) {
+      const hitConditionError = options.hitCondition.trim().length === 0
+        ? 'hitCondition must not be empty'
+        : this.selectPolicy(session.language).validateHitCondition?.(options.hitCondition);
+      if (hitConditionError) {
+        throw new McpError(McpErrorCode.InvalidParams, hitConditionError);
+      }
+    }
+
+    const newBreakpoint: Breakpoint = { id: bpId, file, line, condition, verified: false };
+    if (options.hitCondition !== undefined) {
+      newBreakpoint.hitCondition = options.hitCondition.trim();
+      newBreakpoint.hitCount = 0;
+    }
+    if (options.logMessage !== undefined) {
+      newBreakpoint.logMessage = options.logMessage;
+    }
+
+    if (!session.breakpoints) session.breakpoints = new Map();
+    session.breakpoints.set(bpId, newBreakpoint);
+    this.logger.info(
+      `[SessionManager] Breakpoint ${bpId} queued for ${file}:${line} in session ${sessionId}.`
+    );
+
+    if (
+      session.proxyManager &&
+      session.proxyManager.isRunning() &&
+      (session.state === SessionState.RUNNING || session.state === SessionState.PAUSED)
+    ) {
+      try {
+        this.logger.info(
+          `[SessionManager] Active proxy for session ${sessionId}, sending breakpoint ${bpId}.`
+        );
+        const response =
+          await session.proxyManager.sendDapRequest<DebugProtocol.SetBreakpointsResponse>(
+            'setBreakpoints',
+            {
+              source: { path: newBreakpoint.file },
+              breakpoints: [{
+                line: newBreakpoint.line,
+                condition: newBreakpoint.condition,
+                hitCondition: newBreakpoint.hitCondition,
+                logMessage: newBreakpoint.logMessage,
+              }],
+            }
+          );
+        if (
+          response &&
+          response.body &&
+          response.body.breakpoints &&
+          response.body.breakpoints.length > 0
+        ) {
+          const bpInfo = response.body.breakpoints[0];
+          newBreakpoint.verified = bpInfo.verified;
+          newBreakpoint.line = bpInfo.line || newBreakpoint.line;
+          newBreakpoint.message = bpInfo.message; // Capture validation message
+
+          // Determine condition verification status
+          // If a condition was provided, check if the adapter accepted it
+          if (newBreakpoint.condition) {
+            // If breakpoint is verified, we assume condition is syntactically valid
+            // (actual runtime evaluation may still fail for undefined variables)
+            if (bpInfo.verified) {
+              newBreakpoint.conditionVerified = true;
+            } else {
+              // If not verified and there's a message, it's likely a condition error
+              // Common messages: "Unbound breakpoint" (js-debug), condition syntax errors
+              newBreakpoint.conditionVerified = false;
+              if (bpInfo.message) {
+                newBreakpoint.conditionError = bpInfo.message;
+              }
+            }
+          }
+
+          // DAP reports hit conditions only through the breakpoint's verified/message fields.
+          // An unverified breakpoint without a message is still pending, not rejected.
+          if (newBreakpoint.hitCondition) {
+            if (bpInfo.verified) {
+              newBreakpoint.hitConditionVerified = true;
+            } else if (bpInfo.message) {
+              newBreakpoint.hitConditionVerified = false;
+              newBreakpoint.hitConditionError = bpInfo.message;
+            }
+            // Setting a breakpoint the adapter already had keeps its count
+            await this._refreshHitCounts(session, sessionId, [newBreakpoint]);
+          }
+
+          this.logger.info(
+            `[SessionManager] Breakpoint ${bpId} sent and response received. Verified: ${newBreakpoint.verified}` +
+            `${bpInfo.message ? `, Message: ${bpInfo.message}` : ''}` +
+            `${newBreakpoint.condition ? `, ConditionVerified: ${newBreakpoint.conditionVerified}` : ''}`
+          );
+
+          // Log breakpoint verification with structured logging
+          if (newBreakpoint.verified) {
+            this.logger.info('debug:breakpoint', {
+              event: 'verified',
+              sessionId: sessionId,
+              sessionName: session.name,
+              breakpointId: bpId,
+              file: newBreakpoint.file,
+              line: newBreakpoint.line,
+              verified: true,
+              condition: newBreakpoint.condition,
+              conditionVerified: newBreakpoint.conditionVerified,
+              timestamp: Date.now(),
+            });
+          }
+        }
+      } catch (error) {
+        this.logger.error(
+          `[SessionManager] Error sending setBreakpoint to proxy for session ${sessionId}:`,
+          error
+        );
+      }
+    }
+    return newBreakpoint;
+  }
+
+  /**
+   * Breakpoints at the location the session is stopped at, with hit counts
+   * refreshed from the adapter when its policy can report them
+   */
+  async getHitBreakpoints(
+    sessionId: string,
+    location: { file: string; line: number }
+  ): Promise<Breakpoint[]> {
+    const session = this._getSessionById(sessionId);
+    if (session.lastStop?.reason !== 'breakpoint' || !location.file) {
+      return [];
+    }
+    const atLocation = Array.from(session.breakpoints.values()).filter(
+      bp => bp.line === location.line && this._isSameSourceFile(bp.file, location.file)
+    );
+    if (atLocation.length === 0) {
+      return [];
+    }
+
+    await this._refreshHitCounts(session, sessionId, atLocation);
+    return atLocation;
+  }
+
+  /**
+   * Read hit counts from the adapter while paused, when its policy can report them
+   */
+  private async _refreshHitCounts(session: ManagedSession, sessionId: string, breakpoints: Breakpoint[]): Promise<void> {
+    const policy = this.selectPolicy(session.language);
+    if (
+      !policy.getBreakpointHitCountsCommand ||
+      !policy.parseBreakpointHitCounts ||
+      !session.proxyManager ||
+      !session.proxyManager.isRunning() ||
+      session.state !== SessionState.PAUSED
+    ) {
+      return;
+    }
+
+    try {
+      const response = await session.proxyManager.sendDapRequest<DebugProtocol.EvaluateResponse>('evaluate', {
+        expression: policy.getBreakpointHitCountsCommand(),
+        context: 'repl',
+      });
+      const counts = policy.parseBreakpointHitCounts(response?.body?.result ?? '');
+      for (const bp of breakpoints) {
+        const entry = counts.find(
+          count => count.line === bp.line && count.file !== undefined && this._isSameSourceFile(bp.file, count.file)
+        );
+        if (entry) {
+          bp.hitCount = entry.hitCount;
+        }
+      }
+    } catch (error) {
+      this.logger.debug(
+        `[SM _refreshHitCounts ${sessionId}] Could not read hit counts: ${error instanceof Error ? error.message : String(error)}`
+      );
+    }
+  }
+
+  private _isSameSourceFile(a: string, b: string): boolean {
+    const normalize = (p: string) => path.normalize(p).replace(/\\/g, '/').toLowerCase();
+    const left = normalize(a);
+    const right = normalize(b);
+    // Adapters may report a base name or a path relative to the workspace
+    return left === right || left.endsWith(`/${right}`) || right.endsWith(`/${left}`);
+  }
+
+  async setExceptionBreakpoints(
+    sessionId: string,
+    filters: string[]
+  ): Promise<ExceptionBreakpointsResult> {
+    const session = this._getSessionById(sessionId);
+
+    // Check if session is terminated
+    if (session.sessionLifecycle === SessionLifecycleState.TERMINATED) {
+      throw new SessionTerminatedError(sessionId);
+    }
+
+    const uniqueFilters = Array.from(new Set(filters));
+    // Persist so the filters are applied before configurationDone on (re)launch
+    session.exceptionFilters = uniqueFilters;
+    this.logger.info(
+      `[SessionManager setExceptionBreakpoints] Filters [${uniqueFilters.join(', ')}] stored for session ${sessionId}`
+    );
+
+    if (
+      !session.proxyManager ||
+      !session.proxyManager.isRunning() ||
+      (session.state !== SessionState.RUNNING && session.state !== SessionState.PAUSED)
+    ) {
+      return { success: true, filters: uniqueFilters, applied: false };
+    }
+
+    try {
+      const response =
+        await session.proxyManager.sendDapRequest<DebugProtocol.SetExceptionBreakpointsResponse>(
+          'setExceptionBreakpoints',
+          { filters: uniqueFilters }
+        );
+      const breakpoints = response?.body?.breakpoints?.map((bp, index) => ({
+        filter: uniqueFilters[index],
+        verified: bp.verified,
+        message: bp.message
+      }));
+
+      this.logger.info('debug:breakpoint', {
+        event: 'exception-filters',
+        sessionId,
+        sessionName: session.name,
+        filters: uniqueFilters,
+        timestamp: Date.now(),
+      });
+
+      return { success: true, filters: uniqueFilters, applied: true, breakpoints };
+    } catch (error) {
+      const errorMessage = error instanceof Error ? error.message : String(error);
+      this.logger.error(
+        `[SessionManager setExceptionBreakpoints] Error sending filters for session ${sessionId}: ${errorMessage}`
+      );
+      return { success: false, filters: uniqueFilters, applied: false, error: errorMessage };
+    }
+  }
+
+  /**
+   * Set a breakpoint on a function by name (e.g. a Rust item path) and report
+   * every location it resolved to
+   */
+  async setFunctionBreakpoint(
+    sessionId: string,
+    name: string,
+    condition?: string
+  ): Promise<FunctionBreakpointResult> {
+    const session = this._getSessionById(sessionId);
+
+    // Check if session is terminated
+    if (session.sessionLifecycle === SessionLifecycleState.TERMINATED) {
+      throw new SessionTerminatedError(sessionId);
+    }
+
+    const unsupported = session.unsupportedFeatures?.[DebugFeature.FUNCTION_BREAKPOINTS];
+    if (unsupported) {
+      return { success: false, applied: false, error: unsupported };
+    }
+
+    if (!session.functionBreakpoints) session.functionBreakpoints = new Map();
+
+    // Setting the same function again updates its condition instead of duplicating it
+    let breakpoint = Array.from(session.functionBreakpoints.values()).find(bp => bp.name === name);
+    const previous = breakpoint ? { condition: breakpoint.condition } : undefined;
+    if (breakpoint) {
+      breakpoint.condition = condition;
+    } else {
+      breakpoint = { id: uuidv4(), name, condition, verified: false, locations: [] };
+      session.functionBreakpoints.set(breakpoint.id, breakpoint);
+    }
+    this.logger.info(
+      `[SessionManager] Function breakpoint ${breakpoint.id} queued for ${name} in session ${sessionId}.`
+    );
+
+    if (
+      !session.proxyManager ||
+      !session.proxyManager.isRunning() ||
+      (session.state !== SessionState.RUNNING && session.state !== SessionState.PAUSED)
+    ) {
+      return { success: true, breakpoint, applied: false };
+    }
+
+    try {
+      await this._syncFunctionBreakpoints(session, sessionId);
+
+      this.logger.info('debug:breakpoint', {
+        event: breakpoint.verified ? 'verified' : 'unverified',
+        sessionId,
+        sessionName: session.name,
+        breakpointId: breakpoint.id,
+        function: name,
+        locations: breakpoint.locations.length,
+        verified: breakpoint.verified,
+        timestamp: Date.now(),
+      });
+
+      return { success: true, breakpoint, applied: true };
+    } catch (error) {
+      // Keep the adapter and our bookkeeping in agreement: an existing
+      // breakpoint keeps its old condition, a new one is dropped
+      if (previous) {
+        breakpoint.condition = previous.condition;
+      } else {
+        session.functionBreakpoints.delete(breakpoint.id);
+      }
+      const errorMessage = error instanceof Error ? error.message : String(error);
+      this.logger.error(
+        `[SessionManager setFunctionBreakpoint] Error sending function breakpoints for session ${sessionId}: ${errorMessage}`
+      );
+      return { success: false, breakpoint, applied: false, error: errorMessage };
+    }
+  }
+
+  /**
+   * Remove a function breakpoint, from the running adapter as well as from the
+   * set applied at the next launch
+   */
+  async removeFunctionBreakpoint(sessionId: string, breakpointId: string): Promise<FunctionBreakpointRemovalResult> {
+    const session = this._getSessionById(sessionId);
+
+    // Check if session is terminated
+    if (session.sessionLifecycle === SessionLifecycleState.TERMINATED) {
+      throw new SessionTerminatedError(sessionId);
+    }
+
+    const existing = session.functionBreakpoints?.get(breakpointId);
+    if (!existing || !session.functionBreakpoints) {
+      return { success: false, applied: false, error: `Function breakpoint not found: ${breakpointId}` };
+    }
+    session.functionBreakpoints.delete(breakpointId);
+
+    if (
+      !session.proxyManager ||
+      !session.proxyManager.isRunning() ||
+      (session.state !== SessionState.RUNNING && session.state !== SessionState.PAUSED)
+    ) {
+      return { success: true, breakpoint: existing, applied: false };
+    }
+
+    try {
+      // setFunctionBreakpoints without the entry clears it in the adapter
+      await this._syncFunctionBreakpoints(session, sessionId);
+      return { success: true, breakpoint: existing, applied: true };
+    } catch (error) {
+      // Keep the adapter and our bookkeeping in agreement
+      session.functionBreakpoints.set(existing.id, existing);
+      const errorMessage = error instanceof Error ? error.message : String(error);
+      this.logger.error(
+        `[SessionManager removeFunctionBreakpoint] Error sending function breakpoints for session ${sessionId}: ${errorMessage}`
+      );
+      return { success: false, breakpoint: existing, applied: false, error: errorMessage };
+    }
+  }
+
+  private async _syncFunctionBreakpoints(session: ManagedSession, sessionId: string): Promise<void> {
+    const policy = this.selectPolicy(session.language);
+    // DAP setFunctionBreakpoints replaces the full set, so always send every function breakpoint
+    const active = Array.from(session.functionBreakpoints?.values() ?? []);
+    const response =
+      await session.proxyManager!.sendDapRequest<DebugProtocol.SetFunctionBreakpointsResponse>(
+        'setFunctionBreakpoints',
+        {
+          breakpoints: active.map(bp => ({
+            name: policy.normalizeFunctionBreakpointName?.(bp.name) ?? bp.name,
+            condition: bp.condition,
+          })),
+        }
+      );
+    const results = response?.body?.breakpoints ?? [];
+    for (let i = 0; i < active.length; i++) {
+      await this._applyFunctionBreakpointResult(session, sessionId, active[i], results[i]);
+    }
+  }
+
+  /**
+   * Take verified state and locations from the function breakpoints the proxy
+   * set during launch, matched by the name that was sent
+   */
+  protected async applyLaunchFunctionBreakpoints(
+    session: ManagedSession,
+    launched: LaunchedFunctionBreakpoint[]
+  ): Promise<void> {
+    const policy = this.selectPolicy(session.language);
+    for (const bp of session.functionBreakpoints?.values() ?? []) {
+      const sentName = policy.normalizeFunctionBreakpointName?.(bp.name) ?? bp.name;
+      const entry = launched.find(candidate => candidate.name === sentName);
+      if (entry) {
+        await this._applyFunctionBreakpointResult(session, session.id, bp, entry.breakpoint);
+      }
+    }
+  }
+
+  private async _applyFunctionBreakpointResult(
+    session: ManagedSession,
+    sessionId: string,
+    breakpoint: FunctionBreakpoint,
+    result: DebugProtocol.Breakpoint | undefined
+  ): Promise<void> {
+    breakpoint.verified = result?.verified ?? false;
+    breakpoint.message = result?.message;
+    breakpoint.locations = result ? await this._resolveBreakpointLocations(session, sessionId, result) : [];
+  }
+
+  private async _resolveBreakpointLocations(
+    session: ManagedSession,
+    sessionId: string,
+    dapBreakpoint: DebugProtocol.Breakpoint
+  ): Promise<BreakpointLocation[]> {
+    const policy = this.selectPolicy(session.language);
+
+    // DAP reports one location per breakpoint; generic functions can resolve to many
+    if (
+      dapBreakpoint.id !== undefined &&
+      policy.getBreakpointLocationsCommand &&
+      policy.parseBreakpointLocations
+    ) {
+      try {
+        const response = await session.proxyManager!.sendDapRequest<DebugProtocol.EvaluateResponse>('evaluate', {
+          expression: policy.getBreakpointLocationsCommand(dapBreakpoint.id),
+          context: 'repl',
+        });
+        const locations = policy.parseBreakpointLocations(response?.body?.result ?? '');
+        if (locations.length > 0) {
+          return locations;
+        }
+      } catch (error) {
+        this.logger.debug(
+          `[SM _resolveBreakpointLocations ${sessionId}] Could not list locations for breakpoint ${dapBreakpoint.id}: ${error instanceof Error ? error.message : String(error)}`
+        );
+      }
+    }
+
+    if (!dapBreakpoint.source?.path && dapBreakpoint.line === undefined && !dapBreakpoint.instructionReference) {
+      return [];
+    }
+    return [{
+      file: dapBreakpoint.source?.path,
+      line: dapBreakpoint.line,
+      column: dapBreakpoint.column,
+      address: dapBreakpoint.instructionReference,
+      verified: dapBreakpoint.verified,
+    }];
+  }
+
+  /**
+   * Query which access kinds can be watched for a variable
+   */
+  async getDataBreakpointInfo(
+    sessionId: string,
+    name: string,
+    variablesReference?: number,
+    frameId?: number
+  ): Promise<DataBreakpointResult> {
+    const session = this._getSessionById(sessionId);
+    const gateError = this._checkDataBreakpointPreconditions(session, sessionId, 'get data breakpoint info');
+    if (gateError) {
+      return gateError;
+    }
+
+    try {
+      const info = await this._requestDataBreakpointInfo(session, sessionId, name, variablesReference, frameId);
+      if (!info.dataId) {
+        return {
+          success: false,
+          name,
+          description: info.description,
+          accessTypes: [],
+          remainingSlots: this._remainingWatchpointSlots(session),
+          error: info.description || `Variable '${name}' cannot be watched`,
+        };
+      }
+      return {
+        success: true,
+        name,
+        description: info.description,
+        accessTypes: info.accessTypes,
+        remainingSlots: this._remainingWatchpointSlots(session),
+      };
+    } catch (error) {
+      const errorMessage = error instanceof Error ? error.message : String(error);
+      this.logger.error(`[SM getDataBreakpointInfo ${sessionId}] Error:`, error);
+      return { success: false, name, error: errorMessage };
+    }
+  }
+
+  /**
+   * Watch a variable for write/read/readWrite access (hardware watchpoint)
+   */
+  async setDataBreakpoint(
+    sessionId: string,
+    name: string,
+    accessType: DataBreakpointAccessType = 'write',
+    options: { variablesReference?: number; frameId?: number; condition?: string } = {}
+  ): Promise<DataBreakpointResult> {
+    const session = this._getSessionById(sessionId);
+    const gateError = this._checkDataBreakpointPreconditions(session, sessionId, 'set data breakpoint');
+    if (gateError) {
+      return gateError;
+    }
+
+    let previous: Map<string, DataBreakpoint> | undefined;
+    try {
+      const info = await this._requestDataBreakpointInfo(
+        session,
+        sessionId,
+        name,
+        options.variablesReference,
+        options.frameId
+      );
+      if (!info.dataId) {
+        return {
+          success: false,
+          name,
+          accessTypes: [],
+          error: info.description || `Variable '${name}' cannot be watched`,
+        };
+      }
+      if (!info.accessTypes.includes(accessType)) {
+        return {
+          success: false,
+          name,
+          description: info.description,
+          accessTypes: info.accessTypes,
+          error: `Access type '${accessType}' is not supported for '${name}'. Allowed: ${info.accessTypes.join(', ')}`,
+        };
+      }
+
+      const dataBreakpoints = session.dataBreakpoints ?? new Map<string, DataBreakpoint>();
+      session.dataBreakpoints = dataBreakpoints;
+
+      const limit = this.selectPolicy(session.language).getHardwareWatchpointLimit?.(session.targetArchitecture);
+      const alreadyWatched = Array.from(dataBreakpoints.values()).some(bp => bp.dataId === info.dataId);
+      if (!alreadyWatched && typeof limit === 'number' && dataBreakpoints.size >= limit) {
+        return {
+          success: false,
+          name,
+          description: info.description,
+          accessTypes: info.accessTypes,
+          remainingSlots: 0,
+          error: `All ${limit} hardware watchpoint slots are in use. Remove a data breakpoint first.`,
+        };
+      }
+
+      previous = new Map(dataBreakpoints);
+      // Replace any existing watch on the same data
+      for (const [id, existing] of dataBreakpoints) {
+        if (existing.dataId === info.dataId) {
+          dataBreakpoints.delete(id);
+        }
+      }
+
+      const dataBreakpoint: DataBreakpoint = {
+        id: uuidv4(),
+        dataId: info.dataId,
+        name,
+        accessType,
+        condition: options.condition,
+        description: info.description,
+        verified: false,
+      };
+      dataBreakpoints.set(dataBreakpoint.id, dataBreakpoint);
+
+      await this._syncDataBreakpoints(session, sessionId);
+
+      // Watches the adapter did not verify hold no hardware slot; keep them out of the count
+      for (const [id, existing] of dataBreakpoints) {
+        if (!existing.verified) {
+          dataBreakpoints.delete(id);
+        }
+      }
+
+      this.logger.info('debug:breakpoint', {
+        event: 'data-breakpoint-set',
+        sessionId,
+        sessionName: session.name,
+        breakpointId: dataBreakpoint.id,
+        name,
+        accessType,
+        verified: dataBreakpoint.verified,
+        timestamp: Date.now(),
+      });
+
+      return {
+        success: dataBreakpoint.verified,
+        name,
+        description: info.description,
+        accessTypes: info.accessTypes,
+        breakpoint: dataBreakpoint,
+        remainingSlots: this._remainingWatchpointSlots(session),
+        error: dataBreakpoint.verified ? undefined : dataBreakpoint.message || 'Data breakpoint was not verified',
+      };
+    } catch (error) {
+      // The adapter still has the previous set; keep our bookkeeping in agreement
+      if (previous) {
+        session.dataBreakpoints = previous;
+      }
+      const errorMessage = error instanceof Error ? error.message : String(error);
+      this.logger.error(`[SM setDataBreakpoint ${sessionId}] Error:`, error);
+      return { success: false, name, error: errorMessage };
+    }
+  }
+
+  /**
+   * Remove a data breakpoint and free its watchpoint slot
+   */
+  async removeDataBreakpoint(sessionId: string, breakpointId: string): Promise<DataBreakpointResult> {
+    const session = this._getSessionById(sessionId);
+
+    // Check if session is terminated
+    if (session.sessionLifecycle === SessionLifecycleState.TERMINATED) {
+      throw new SessionTerminatedError(sessionId);
+    }
+
+    const existing = session.dataBreakpoints?.get(breakpointId);
+    if (!existing || !session.dataBreakpoints) {
+      return { success: false, error: `Data breakpoint not found: ${breakpointId}` };
+    }
+    session.dataBreakpoints.delete(breakpointId);
+
+    if (session.proxyManager && session.proxyManager.isRunning()) {
+      try {
+        await this._syncDataBreakpoints(session, sessionId);
+      } catch (error) {
+        const errorMessage = error instanceof Error ? error.message : String(error);
+        this.logger.error(`[SM removeDataBreakpoint ${sessionId}] Error:`, error);
+        return { success: false, name: existing.name, error: errorMessage };
+      }
+    }
+
+    return {
+      success: true,
+      name: existing.name,
+      breakpoint: existing,
+      remainingSlots: this._remainingWatchpointSlots(session),
+    };
+  }
+
+  private _checkDataBreakpointPreconditions(
+    session: ManagedSession,
+    sessionId: string,
+    operation: string
+  ): DataBreakpointResult | undefined {
+    // Check if session is terminated
+    if (session.sessionLifecycle === SessionLifecycleState.TERMINATED) {
+      throw new SessionTerminatedError(sessionId);
+    }
+    if (!session.proxyManager || !session.proxyManager.isRunning()) {
+      throw new ProxyNotRunningError(sessionId, operation);
+    }
+    const unsupported = session.unsupportedFeatures?.[DebugFeature.DATA_BREAKPOINTS];
+    if (unsupported) {
+      return { success: false, error: unsupported };
+    }
+    if (session.state !== SessionState.PAUSED) {
+      return { success: false, error: 'Not paused' };
+    }
+    return undefined;
+  }
+
+  private async _requestDataBreakpointInfo(
+    session: ManagedSession,
+    sessionId: string,
+    name: string,
+    variablesReference?: number,
+    frameId?: number
+  ): Promise<{ dataId: string | null; description?: string; accessTypes: DataBreakpointAccessType[] }> {
+    let containerReference = variablesReference;
+    let effectiveFrameId = frameId;
+
+    // Default to the locals scope of the requested (or top) frame, as shown by get_local_variables
+    if (containerReference === undefined) {
+      if (effectiveFrameId === undefined) {
+        const frames = await this.getStackTrace(sessionId);
+        if (frames.length === 0) {
+          throw new Error('No stack frames available to resolve variable');
+        }
+        effectiveFrameId = frames[0].id;
+      }
+      const scopes = await this.getScopes(sessionId, effectiveFrameId);
+      const policy = this.selectPolicy(session.language);
+      const localNames = policy.getLocalScopeName?.();
+      const names = Array.isArray(localNames) ? localNames : localNames ? [localNames] : ['Locals', 'Local'];
+      const localScope = scopes.find(scope => names.includes(scope.name)) ?? scopes[0];
+      if (!localScope) {
+        throw new Error('No scopes available to resolve variable');
+      }
+      containerReference = localScope.variablesReference;
+    }
+
+    const response = await session.proxyManager!.sendDapRequest<DebugProtocol.DataBreakpointInfoResponse>(
+      'dataBreakpointInfo',
+      { variablesReference: containerReference, name, frameId: effectiveFrameId }
+    );
+    const body = response?.body;
+    return {
+      dataId: body?.dataId ?? null,
+      description: body?.description,
+      // Adapters that omit accessTypes allow every kind
+      accessTypes: (body?.accessTypes as DataBreakpointAccessType[] | undefined) ?? ['write', 'read', 'readWrite'],
+    };
+  }
+
+  private async _syncDataBreakpoints(session: ManagedSession, sessionId: string): Promise<void> {
+    // DAP setDataBreakpoints replaces the full set, so always send every active watch
+    const active = Array.from(session.dataBreakpoints?.values() ?? []);
+    const response = await session.proxyManager!.sendDapRequest<DebugProtocol.SetDataBreakpointsResponse>(
+      'setDataBreakpoints',
+      {
+        breakpoints: active.map(bp => ({
+          dataId: bp.dataId,
+          accessType: bp.accessType,
+          condition: bp.condition,
+        })),
+      }
+    );
+    const results = response?.body?.breakpoints ?? [];
+    active.forEach((bp, index) => {
+      const result = results[index];
+      bp.verified = result?.verified ?? false;
+      bp.message = result?.message;
+    });
+    this.logger.info(
+      `[SM setDataBreakpoints ${sessionId}] ${active.length} data breakpoint(s) active, ${active.filter(bp => bp.verified).length} verified`
+    );
+  }
+
+  private _remainingWatchpointSlots(session: ManagedSession): number | undefined {
+    const limit = this.selectPolicy(session.language).getHardwareWatchpointLimit?.(session.targetArchitecture);
+    if (typeof limit !== 'number') {
+      return undefined;
+    }
+    return Math.max(0, limit - (session.dataBreakpoints?.size ?? 0));
+  }
+
+  /**
+   * Set a breakpoint at a machine code address. Addresses are only meaningful
+   * for a running process, so unlike source breakpoints these are never queued.
+   */
+  async setInstructionBreakpoint(
+    sessionId: string,
+    address: string,
+    options: { condition?: string; hitCondition?: string } = {}
+  ): Promise<InstructionBreakpointResult> {
+    const session = this._getSessionById(sessionId);
+
+    // Check if session is terminated
+    if (session.sessionLifecycle === SessionLifecycleState.TERMINATED) {
+      throw new SessionTerminatedError(sessionId);
+    }
+    if (!session.proxyManager || !session.proxyManager.isRunning()) {
+      throw new ProxyNotRunningError(sessionId, 'set instruction breakpoint');
+    }
+
+    const parsed = this._parseAddress(address);
+    if (parsed === undefined || parsed < 0n) {
+      throw new McpError(McpErrorCode.InvalidParams, `Invalid instruction address: ${address}`);
+    }
+    if (options.hitCondition !== undefined) {
+      const hitConditionError = options.hitCondition.trim().length === 0
+        ? 'hitCondition must not be empty'
+        : this.selectPolicy(session.language).validateHitCondition?.(options.hitCondition);
+      if (hitConditionError) {
+        throw new McpError(McpErrorCode.InvalidParams, hitConditionError);
+      }
+    }
+    const normalized = `0x${parsed.toString(16)}`;
+
+    if (!session.instructionBreakpoints) session.instructionBreakpoints = new Map();
+
+    // Setting the same address again updates its conditions instead of duplicating it
+    let breakpoint = Array.from(session.instructionBreakpoints.values()).find(bp => bp.address === normalized);
+    const previous = breakpoint ? { condition: breakpoint.condition, hitCondition: breakpoint.hitCondition } : undefined;
+    if (breakpoint) {
+      breakpoint.condition = options.condition;
+      breakpoint.hitCondition = options.hitCondition?.trim();
+    } else {
+      breakpoint = {
+        id: uuidv4(),
+        address: normalized,
+        condition: options.condition,
+        hitCondition: options.hitCondition?.trim(),
+        verified: false,
+      };
+      session.instructionBreakpoints.set(breakpoint.id, breakpoint);
+    }
+
+    try {
+      await this._syncInstructionBreakpoints(session, sessionId);
+
+      this.logger.info('debug:breakpoint', {
+        event: breakpoint.verified ? 'verified' : 'unverified',
+        sessionId,
+        sessionName: session.name,
+        breakpointId: breakpoint.id,
+        address: normalized,
+        verified: breakpoint.verified,
+        timestamp: Date.now(),
+      });
+
+      return { success: true, breakpoint };
+    } catch (error) {
+      // Keep the adapter and our bookkeeping in agreement: an existing
+      // breakpoint keeps its old conditions, a new one is dropped
+      if (previous) {
+        breakpoint.condition = previous.condition;
+        breakpoint.hitCondition = previous.hitCondition;
+      } else {
+        session.instructionBreakpoints.delete(breakpoint.id);
+      }
+      const errorMessage = error instanceof Error ? error.message : String(error);
+      this.logger.error(
+        `[SessionManager setInstructionBreakpoint] Error sending instruction breakpoints for session ${sessionId}: ${errorMessage}`
+      );
+      return { success: false, breakpoint, error: errorMessage };
+    }
+  }
+
+  private async _syncInstructionBreakpoints(session: ManagedSession, sessionId: string): Promise<void> {
+    // DAP setInstructionBreakpoints replaces the full set, so always send every instruction breakpoint
+    const active = Array.from(session.instructionBreakpoints?.values() ?? []);
+    const response =
+      await session.proxyManager!.sendDapRequest<DebugProtocol.SetInstructionBreakpointsResponse>(
+        'setInstructionBreakpoints',
+        {
+          breakpoints: active.map(bp => ({
+            instructionReference: bp.address,
+            condition: bp.condition,
+            hitCondition: bp.hitCondition,
+          })),
+        }
+      );
+    const results = response?.body?.breakpoints ?? [];
+    active.forEach((bp, index) => {
+      const result = results[index];
+      bp.verified = result?.verified ?? false;
+      bp.message = result?.message;
+      const file = result?.source?.path || result?.source?.name;
+      bp.location = file && typeof result?.line === 'number'
+        ? { file, line: result.line, column: result.column, address: bp.address, verified: bp.verified }
+        : undefined;
+    });
+    this.logger.info(
+      `[SM setInstructionBreakpoints ${sessionId}] ${active.length} instruction breakpoint(s) active, ${active.filter(bp => bp.verified).length} verified`
+    );
+  }
+
+  /**
+   * Disassemble instructions around the instruction pointer of a frame
+   * (top frame by default)
+   */
+  async disassemble(
+    sessionId: string,
+    options: { frameId?: number; instructionsBefore?: number; instructionsAfter?: number } = {}
+  ): Promise<DisassembleResult> {
+    const session = this._getSessionById(sessionId);
+
+    // Check if session is terminated
+    if (session.sessionLifecycle === SessionLifecycleState.TERMINATED) {
+      throw new SessionTerminatedError(sessionId);
+    }
+    if (!session.proxyManager || !session.proxyManager.isRunning()) {
+      throw new ProxyNotRunningError(sessionId, 'disassemble');
+    }
+    const unsupported = session.unsupportedFeatures?.[DebugFeature.DISASSEMBLE_REQUEST];
+    if (unsupported) {
+      return { success: false, instructions: [], error: unsupported };
+    }
+    if (session.state !== SessionState.PAUSED) {
+      return { success: false, instructions: [], error: 'Not paused' };
+    }
+
+    const before = options.instructionsBefore ?? 10;
+    const after = options.instructionsAfter ?? 10;
+
+    try {
+      // Internal frames are kept so any frame ID from get_stack_trace can be used
+      const frames = await this.getStackTrace(sessionId, undefined, true);
+      const frame = options.frameId !== undefined
+        ? frames.find(f => f.id === options.frameId)
+        : frames[0];
+      if (!frame) {
         return {
           success: false,
-          error: 'MSVC_TOOLCHAIN_DETECTED',
-          state: updatedSession.state,
-          data: {
-            message: toolchainValidation.message ?? errorMessage,
-            toolchainValidation,
-          },
-          canContinue,
-          errorType,
-          errorCode,
+          instructions: [],
+          error: options.frameId !== undefined ? `Stack frame not found: ${options.frameId}` : 'No stack frames available',
+        };
+      }
+      if (!frame.instructionPointerReference) {
+        return {
+          success: false,
+          frameId: frame.id,
+          instructions: [],
+          error: 'The debug adapter did not report an instruction pointer for this frame',
         };
       }
 
-      return { success: false, error: errorMessage, state: session.state, errorType, errorCode };
+      const instructionPointer = frame.instructionPointerReference;
+      const response = await session.proxyManager.sendDapRequest<DebugProtocol.DisassembleResponse>('disassemble', {
+        memoryReference: instructionPointer,
+        offset: 0,
+        instructionOffset: -before,
+        instructionCount: before + after + 1,
+        resolveSymbols: true,
+      });
+
+      const currentAddress = this._parseAddress(instructionPointer);
+      let file: string | undefined;
+      const instructions: DisassembledInstruction[] = (response?.body?.instructions ?? []).map(raw => {
+        // DAP omits the location when it is the same as the previous instruction's
+        if (raw.location) {
+          file = raw.location.path || raw.location.name;
+        }
+        return {
+          address: raw.address,
+          instructionBytes: raw.instructionBytes,
+          instruction: raw.instruction,
+          symbol: raw.symbol,
+          file: raw.line !== undefined ? file : undefined,
+          line: raw.line,
+          isCurrent: currentAddress !== undefined && this._parseAddress(raw.address) === currentAddress,
+        };
+      });
+
+      return { success: true, frameId: frame.id, instructionPointer, instructions };
+    } catch (error) {
+      const errorMessage = error instanceof Error ? error.message : String(error);
+      this.logger.error(`[SM disassemble ${sessionId}] Error:`, error);
+      return { success: false, instructions: [], error: errorMessage };
     }
   }
 
+  /**
+   * List the tasks owned by the debuggee's async runtimes (Tokio for Rust),
+   * read from memory by a debugger command the adapter policy provides
+   */
+  async listAsyncTasks(sessionId: string): Promise<AsyncTasksResult> {
+    const session = this._getSessionById(sessionId);
 
-  async setBreakpoint(
+    if (session.sessionLifecycle === SessionLifecycleState.TERMINATED) {
+      throw new SessionTerminatedError(sessionId);
+    }
+    if (!session.proxyManager || !session.proxyManager.isRunning()) {
+      throw new ProxyNotRunningError(sessionId, 'list async tasks');
+    }
+    const policy = this.selectPolicy(session.language);
+    if (!policy.getAsyncTasksCommand || !policy.parseAsyncTasks) {
+      return { success: false, runtimes: [], error: `Async task inspection is not supported for ${session.language}` };
+    }
+    if (session.state !== SessionState.PAUSED) {
+      return { success: false, runtimes: [], error: 'Not paused' };
+    }
+
+    try {
+      const response = await session.proxyManager.sendDapRequest<DebugProtocol.EvaluateResponse>('evaluate', {
+        expression: policy.getAsyncTasksCommand(),
+        context: 'repl',
+      });
+      const parsed = policy.parseAsyncTasks(response?.body?.result ?? '');
+      if ('error' in parsed) {
+        return { success: false, runtimes: [], error: parsed.error };
+      }
+      return { success: true, runtimes: parsed.runtimes };
+    } catch (error) {
+      const errorMessage = error instanceof Error ? error.message : String(error);
+      this.logger.error(`[SM listAsyncTasks ${sessionId}] Error:`, error);
+      return { success: false, runtimes: [], error: errorMessage };
+    }
+  }
+
+  /**
+   * Set a variable in a paused frame. The new value is checked against the
+   * variable's current type by the adapter policy before it is sent.
+   */
+  async setVariable(
     sessionId: string,
-    file: string,
-    line: number,
-    condition?: string
-  ): Promise<Breakpoint> {
+    variablesReference: number,
+    name: string,
+    value: string
+  ): Promise<SetVariableResult> {
     const session = this._getSessionById(sessionId);
 
     // Check if session is terminated
     if (session.sessionLifecycle === SessionLifecycleState.TERMINATED) {
       throw new SessionTerminatedError(sessionId);
     }
+    if (!session.proxyManager || !session.proxyManager.isRunning()) {
+      throw new ProxyNotRunningError(sessionId, 'set variable');
+    }
+    const unsupported = session.unsupportedFeatures?.[DebugFeature.SET_VARIABLE];
+    if (unsupported) {
+      return { success: false, name, error: unsupported };
+    }
+    if (session.state !== SessionState.PAUSED) {
+      return { success: false, name, error: 'Not paused' };
+    }
 
-    const bpId = uuidv4();
+    const current = (await this.getVariables(sessionId, variablesReference)).find(v => v.name === name);
+    if (!current) {
+      return { success: false, name, error: `Variable '${name}' not found in variablesReference ${variablesReference}` };
+    }
 
-    // The file path has been validated and translated by server.ts before reaching here
-    this.logger.info(
-      `[SessionManager setBreakpoint] Using validated file path "${file}" for session ${sessionId}`
-    );
+    const prepared = this.selectPolicy(session.language)
+      .prepareVariableValue?.(current.type, value, session.targetArchitecture) ?? { value };
+    if ('error' in prepared) {
+      return { success: false, name, previousValue: current.value, type: current.type, error: prepared.error };
+    }
 
-    const newBreakpoint: Breakpoint = { id: bpId, file, line, condition, verified: false };
+    try {
+      const response = await session.proxyManager.sendDapRequest<DebugProtocol.SetVariableResponse>('setVariable', {
+        variablesReference,
+        name,
+        value: prepared.value,
+      });
+      const body = response?.body;
+      if (!body) {
+        return { success: false, name, previousValue: current.value, type: current.type, error: 'Debug adapter returned no value' };
+      }
 
-    if (!session.breakpoints) session.breakpoints = new Map();
-    session.breakpoints.set(bpId, newBreakpoint);
-    this.logger.info(
-      `[SessionManager] Breakpoint ${bpId} queued for ${file}:${line} in session ${sessionId}.`
-    );
+      const childReference = body.variablesReference ?? 0;
+      const children = childReference > 0 ? await this.getVariables(sessionId, childReference) : undefined;
 
-    if (
-      session.proxyManager &&
-      session.proxyManager.isRunning() &&
-      (session.state === SessionState.RUNNING || session.state === SessionState.PAUSED)
-    ) {
-      try {
-        this.logger.info(
-          `[SessionManager] Active proxy for session ${sessionId}, sending breakpoint ${bpId}.`
-        );
-        const response =
-          await session.proxyManager.sendDapRequest<DebugProtocol.SetBreakpointsResponse>(
-            'setBreakpoints',
-            {
-              source: { path: newBreakpoint.file },
-              breakpoints: [{ line: newBreakpoint.line, condition: newBreakpoint.condition }],
-            }
-          );
-        if (
-          response &&
-          response.body &&
-          response.body.breakpoints &&
-          response.body.breakpoints.length > 0
-        ) {
-          const bpInfo = response.body.breakpoints[0];
-          newBreakpoint.verified = bpInfo.verified;
-          newBreakpoint.line = bpInfo.line || newBreakpoint.line;
-          newBreakpoint.message = bpInfo.message; // Capture validation message
+      this.logger.info('debug:variable', {
+        event: 'set',
+        sessionId,
+        sessionName: session.name,
+        name,
+        type: body.type ?? current.type,
+        previousValue: current.value,
+        value: body.value,
+        timestamp: Date.now(),
+      });
 
-          // Determine condition verification status
-          // If a condition was provided, check if the adapter accepted it
-          if (newBreakpoint.condition) {
-            // If breakpoint is verified, we assume condition is syntactically valid
-            // (actual runtime evaluation may still fail for undefined variables)
-            if (bpInfo.verified) {
-              newBreakpoint.conditionVerified = true;
-            } else {
-              // If not verified and there's a message, it's likely a condition error
-              // Common messages: "Unbound breakpoint" (js-debug), condition syntax errors
-              newBreakpoint.conditionVerified = false;
-              if (bpInfo.message) {
-                newBreakpoint.conditionError = bpInfo.message;
-              }
-            }
-          }
+      return {
+        success: true,
+        name,
+        previousValue: current.value,
+        value: body.value,
+        type: body.type ?? current.type,
+        variablesReference: childReference,
+        children,
+      };
+    } catch (error) {
+      const errorMessage = error instanceof Error ? error.message : String(error);
+      this.logger.error(`[SM setVariable ${sessionId}] Error:`, error);
+      return { success: false, name, previousValue: current.value, type: current.type, error: errorMessage };
+    }
+  }
 
-          this.logger.info(
-            `[SessionManager] Breakpoint ${bpId} sent and response received. Verified: ${newBreakpoint.verified}` +
-            `${bpInfo.message ? `, Message: ${bpInfo.message}` : ''}` +
-            `${newBreakpoint.condition ? `, ConditionVerified: ${newBreakpoint.conditionVerified}` : ''}`
-          );
+  /**
+   * Read `count` bytes starting at a memory reference (from a variable, an
+   * evaluate result, or a raw address) plus an optional byte offset
+   */
+  async readMemory(
+    sessionId: string,
+    memoryReference: string,
+    count: number,
+    offset: number = 0
+  ): Promise<MemoryReadResult> {
+    const session = this._getSessionById(sessionId);
+    const unavailable = this._checkMemoryPreconditions(session, sessionId, 'read memory');
+    if (unavailable) {
+      return unavailable;
+    }
 
-          // Log breakpoint verification with structured logging
-          if (newBreakpoint.verified) {
-            this.logger.info('debug:breakpoint', {
-              event: 'verified',
-              sessionId: sessionId,
-              sessionName: session.name,
-              breakpointId: bpId,
-              file: newBreakpoint.file,
-              line: newBreakpoint.line,
-              verified: true,
-              condition: newBreakpoint.condition,
-              conditionVerified: newBreakpoint.conditionVerified,
-              timestamp: Date.now(),
-            });
-          }
-        }
-      } catch (error) {
-        this.logger.error(
-          `[SessionManager] Error sending setBreakpoint to proxy for session ${sessionId}:`,
-          error
-        );
+    try {
+      const response = await session.proxyManager!.sendDapRequest<DebugProtocol.ReadMemoryResponse>('readMemory', {
+        memoryReference,
+        offset,
+        count,
+      });
+      const body = response?.body;
+      if (!body) {
+        return { success: false, error: `Memory at ${memoryReference} is not readable` };
       }
+      const bytes = Buffer.from(body.data ?? '', 'base64');
+      this.logger.info(
+        `[SM readMemory ${sessionId}] Read ${bytes.length} byte(s) at ${body.address} (${body.unreadableBytes ?? 0} unreadable)`
+      );
+      return { success: true, address: body.address, bytes, unreadableBytes: body.unreadableBytes ?? 0 };
+    } catch (error) {
+      const errorMessage = error instanceof Error ? error.message : String(error);
+      this.logger.error(`[SM readMemory ${sessionId}] Error:`, error);
+      return { success: false, error: errorMessage };
+    }
+  }
+
+  /**
+   * Write bytes at a memory reference plus an optional byte offset
+   */
+  async writeMemory(
+    sessionId: string,
+    memoryReference: string,
+    data: Buffer,
+    options: { offset?: number; allowPartial?: boolean } = {}
+  ): Promise<MemoryWriteResult> {
+    const session = this._getSessionById(sessionId);
+    const unavailable = this._checkMemoryPreconditions(session, sessionId, 'write memory');
+    if (unavailable) {
+      return unavailable;
+    }
+
+    try {
+      const response = await session.proxyManager!.sendDapRequest<DebugProtocol.WriteMemoryResponse>('writeMemory', {
+        memoryReference,
+        offset: options.offset ?? 0,
+        allowPartial: options.allowPartial ?? false,
+        data: data.toString('base64'),
+      });
+      // Adapters may omit bytesWritten when everything was written
+      const bytesWritten = response?.body?.bytesWritten ?? data.length;
+      this.logger.info(`[SM writeMemory ${sessionId}] Wrote ${bytesWritten} byte(s) at ${memoryReference}`);
+      return { success: true, bytesWritten };
+    } catch (error) {
+      const errorMessage = error instanceof Error ? error.message : String(error);
+      this.logger.error(`[SM writeMemory ${sessionId}] Error:`, error);
+      return { success: false, error: errorMessage };
+    }
+  }
+
+  private _checkMemoryPreconditions(
+    session: ManagedSession,
+    sessionId: string,
+    operation: string
+  ): { success: false; error: string } | undefined {
+    // Check if session is terminated
+    if (session.sessionLifecycle === SessionLifecycleState.TERMINATED) {
+      throw new SessionTerminatedError(sessionId);
+    }
+    if (!session.proxyManager || !session.proxyManager.isRunning()) {
+      throw new ProxyNotRunningError(sessionId, operation);
+    }
+    if (session.state !== SessionState.PAUSED) {
+      return { success: false, error: 'Not paused' };
+    }
+    return undefined;
+  }
+
+  private _parseAddress(address: string): bigint | undefined {
+    try {
+      return BigInt(address.trim());
+    } catch {
+      return undefined;
     }
-    return newBreakpoint;
   }
 
-  async stepOver(sessionId: string): Promise<DebugResult> {
+  async stepOver(sessionId: string, options: StepOptions = {}): Promise<DebugResult> {
     const session = this._getSessionById(sessionId);
 
     // Check if session is terminated
@@ -931,12 +2269,15 @@ export class SessionManagerOperations extends SessionManagerData {
       return { success: false, error: 'No current thread ID', state: session.state };
     }
 
-    this.logger.info(`[SM stepOver ${sessionId}] Sending DAP 'next' for threadId ${threadId}`);
+    this.logger.info(
+      `[SM stepOver ${sessionId}] Sending DAP 'next' for threadId ${threadId} (granularity: ${options.granularity ?? 'default'})`
+    );
 
     try {
       return await this._executeStepOperation(session, sessionId, {
         command: 'next',
         threadId,
+        granularity: options.granularity,
         logTag: 'stepOver',
         successMessage: 'Step completed.',
       });
@@ -948,7 +2289,7 @@ export class SessionManagerOperations extends SessionManagerData {
     }
   }
 
-  async stepInto(sessionId: string): Promise<DebugResult> {
+  async stepInto(sessionId: string, options: StepOptions = {}): Promise<DebugResult> {
     const session = this._getSessionById(sessionId);
 
     // Check if session is terminated
@@ -973,12 +2314,16 @@ export class SessionManagerOperations extends SessionManagerData {
       return { success: false, error: 'No current thread ID', state: session.state };
     }
 
-    this.logger.info(`[SM stepInto ${sessionId}] Sending DAP 'stepIn' for threadId ${threadId}`);
+    this.logger.info(
+      `[SM stepInto ${sessionId}] Sending DAP 'stepIn' for threadId ${threadId} (granularity: ${options.granularity ?? 'default'}, target: ${options.targetId ?? 'first call'})`
+    );
 
     try {
       return await this._executeStepOperation(session, sessionId, {
         command: 'stepIn',
         threadId,
+        granularity: options.granularity,
+        targetId: options.targetId,
         logTag: 'stepInto',
         successMessage: 'Step into completed.',
       });
@@ -990,7 +2335,62 @@ export class SessionManagerOperations extends SessionManagerData {
     }
   }
 
-  async stepOut(sessionId: string): Promise<DebugResult> {
+  /**
+   * List the calls on a frame's current line (top frame by default) that
+   * stepInto can enter directly via StepOptions.targetId
+   */
+  async getStepInTargets(sessionId: string, frameId?: number): Promise<StepInTargetsResult> {
+    const session = this._getSessionById(sessionId);
+
+    // Check if session is terminated
+    if (session.sessionLifecycle === SessionLifecycleState.TERMINATED) {
+      throw new SessionTerminatedError(sessionId);
+    }
+    if (!session.proxyManager || !session.proxyManager.isRunning()) {
+      throw new ProxyNotRunningError(sessionId, 'get step-in targets');
+    }
+    const unsupported = session.unsupportedFeatures?.[DebugFeature.STEP_IN_TARGETS_REQUEST];
+    if (unsupported) {
+      return { success: false, targets: [], error: unsupported };
+    }
+    if (session.state !== SessionState.PAUSED) {
+      return { success: false, targets: [], error: 'Not paused' };
+    }
+
+    try {
+      let targetFrameId = frameId;
+      if (targetFrameId === undefined) {
+        const frames = await this.getStackTrace(sessionId);
+        if (frames.length === 0) {
+          return { success: false, targets: [], error: 'No stack frames available' };
+        }
+        targetFrameId = frames[0].id;
+      }
+
+      const response = await session.proxyManager.sendDapRequest<DebugProtocol.StepInTargetsResponse>(
+        'stepInTargets',
+        { frameId: targetFrameId }
+      );
+      const targets: StepInTarget[] = (response?.body?.targets ?? []).map(target => ({
+        id: target.id,
+        label: target.label,
+        line: target.line,
+        column: target.column,
+        endLine: target.endLine,
+        endColumn: target.endColumn,
+      }));
+      this.logger.info(
+        `[SM getStepInTargets ${sessionId}] ${targets.length} target(s) in frame ${targetFrameId}: ${targets.map(t => t.label).join(', ')}`
+      );
+      return { success: true, frameId: targetFrameId, targets };
+    } catch (error) {
+      const errorMessage = error instanceof Error ? error.message : String(error);
+      this.logger.error(`[SM getStepInTargets ${sessionId}] Error:`, error);
+      return { success: false, targets: [], error: errorMessage };
+    }
+  }
+
+  async stepOut(sessionId: string, options: StepOptions = {}): Promise<DebugResult> {
     const session = this._getSessionById(sessionId);
 
     // Check if session is terminated
@@ -1015,12 +2415,15 @@ export class SessionManagerOperations extends SessionManagerData {
       return { success: false, error: 'No current thread ID', state: session.state };
     }
 
-    this.logger.info(`[SM stepOut ${sessionId}] Sending DAP 'stepOut' for threadId ${threadId}`);
+    this.logger.info(
+      `[SM stepOut ${sessionId}] Sending DAP 'stepOut' for threadId ${threadId} (granularity: ${options.granularity ?? 'default'})`
+    );
 
     try {
       return await this._executeStepOperation(session, sessionId, {
         command: 'stepOut',
         threadId,
+        granularity: options.granularity,
         logTag: 'stepOut',
         successMessage: 'Step out completed.',
       });
@@ -1038,6 +2441,8 @@ export class SessionManagerOperations extends SessionManagerData {
     options: {
       command: 'next' | 'stepIn' | 'stepOut';
       threadId: number;
+      granularity?: SteppingGranularity;
+      targetId?: number;
       logTag: string;
       successMessage: string;
       terminatedMessage?: string;
@@ -1054,6 +2459,18 @@ export class SessionManagerOperations extends SessionManagerData {
       });
     }
 
+    // Otherwise the adapter would do a line step that we report as an instruction step
+    const granularityUnsupported = options.granularity
+      ? session.unsupportedFeatures?.[DebugFeature.STEPPING_GRANULARITY]
+      : undefined;
+    if (granularityUnsupported) {
+      return Promise.resolve({
+        success: false,
+        error: `Cannot step by ${options.granularity}: ${granularityUnsupported}`,
+        state: session.state,
+      });
+    }
+
     const terminatedMessage =
       options.terminatedMessage ?? 'Step completed as session terminated.';
     const exitedMessage = options.exitedMessage ?? 'Step completed as session exited.';
@@ -1078,12 +2495,30 @@ export class SessionManagerOperations extends SessionManagerData {
         resolve(result);
       };
 
-      const success = (message: string, location?: { file: string; line: number; column?: number }) => {
+      const success = (
+        message: string,
+        location?: { file: string; line: number; column?: number },
+        exception?: ExceptionStopInfo,
+        instruction?: DisassembledInstruction
+      ) => {
         this.logger.info(`[SM ${options.logTag} ${sessionId}] ${message} Current state: ${session.state}`);
-        const data: { message: string; location?: { file: string; line: number; column?: number } } = { message };
+        const data: {
+          message: string;
+          location?: { file: string; line: number; column?: number };
+          exception?: ExceptionStopInfo;
+          instructionPointer?: string;
+          instruction?: DisassembledInstruction;
+        } = { message };
         if (location) {
           data.location = location;
         }
+        if (exception) {
+          data.exception = exception;
+        }
+        if (instruction) {
+          data.instructionPointer = instruction.address;
+          data.instruction = instruction;
+        }
         settle({
           success: true,
           state: session.state,
@@ -1091,9 +2526,10 @@ export class SessionManagerOperations extends SessionManagerData {
         });
       };
 
-      const onStopped = async () => {
+      const onStopped = async (_threadId?: number, reason?: string) => {
         // Try to get current location from stack trace
         let location: { file: string; line: number; column?: number } | undefined;
+        let exception: ExceptionStopInfo | undefined;
         try {
           // Wait a brief moment for state to settle after stopped event
           await new Promise(resolve => setTimeout(resolve, 10));
@@ -1112,7 +2548,26 @@ export class SessionManagerOperations extends SessionManagerData {
           // Log but don't fail the step operation if we can't get location
           this.logger.debug(`[SM ${options.logTag} ${sessionId}] Could not capture location:`, error);
         }
-        success(options.successMessage, location);
+        if (reason === 'exception') {
+          // Stepped into a panic/exception: report the raise site instead of runtime internals
+          exception = (await this.getExceptionStopInfo(sessionId).catch(() => null)) ?? undefined;
+          if (exception?.location) {
+            location = exception.location;
+          }
+        }
+        let instruction: DisassembledInstruction | undefined;
+        if (options.granularity === 'instruction') {
+          // Source location alone does not show progress within a line, so report the instruction too
+          const disassembly = await this.disassemble(sessionId, { instructionsBefore: 0, instructionsAfter: 0 })
+            .catch(() => undefined);
+          instruction = disassembly?.instructions.find(i => i.isCurrent);
+          if (!instruction) {
+            this.logger.debug(
+              `[SM ${options.logTag} ${sessionId}] Could not disassemble instruction: ${disassembly?.error ?? 'no instruction at pointer'}`
+            );
+          }
+        }
+        success(options.successMessage, location, exception, instruction);
       };
 
       const onTerminated = () => success(terminatedMessage);
@@ -1137,8 +2592,18 @@ export class SessionManagerOperations extends SessionManagerData {
 
       this._updateSessionState(session, SessionState.RUNNING);
 
+      const stepArgs: { threadId: number; granularity?: SteppingGranularity; targetId?: number } = {
+        threadId: options.threadId,
+      };
+      if (options.granularity) {
+        stepArgs.granularity = options.granularity;
+      }
+      if (options.targetId !== undefined) {
+        stepArgs.targetId = options.targetId;
+      }
+
       proxyManager
-        .sendDapRequest(options.command, { threadId: options.threadId })
+        .sendDapRequest(options.command, stepArgs)
         .catch((error: unknown) => {
           const errorMessage = error instanceof Error ? error.message : String(error);
           this.logger.error(
@@ -1308,6 +2773,22 @@ export class SessionManagerOperations extends SessionManagerData {
     }
 
     try {
+      // Languages can render values in their own syntax, e.g. `vec![1, 2, 3]` for Rust
+      const proxyManager = session.proxyManager;
+      const languagePreview = await this.selectPolicy(session.language).buildValuePreview?.(
+        { name: '', value: rawResult, type, variablesReference, namedVariables, indexedVariables },
+        async (reference: number) => {
+          const children = await proxyManager.sendDapRequest<DebugProtocol.VariablesResponse>(
+            'variables',
+            { variablesReference: reference }
+          );
+          return children?.body?.variables ?? [];
+        }
+      );
+      if (languagePreview !== undefined) {
+        return this.truncateValue(languagePreview, PREVIEW_MAX_TOTAL_LENGTH);
+      }
+
       // Fetch child variables
       const response = await session.proxyManager.sendDapRequest<DebugProtocol.VariablesResponse>(
         'variables',
@@ -1808,6 +3289,9 @@ export class SessionManagerOperations extends SessionManagerData {
           indexedVariables: body.indexedVariables,
           presentationHint: body.presentationHint,
         };
+        if (body.memoryReference) {
+          result.memoryReference = body.memoryReference;
+        }
 
         // Log the evaluation result with structured logging
         this.logger.info('debug:evaluate', {
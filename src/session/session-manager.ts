@@ -17,7 +17,22 @@ export type {
   DebugResult
 } from './session-manager-core.js';
 
-export type { EvaluateResult } from './session-manager-operations.js';
+export type {
+  AsyncTasksResult,
+  BreakpointOptions,
+  DisassembleResult,
+  EvaluateResult,
+  ExceptionBreakpointsResult,
+  FunctionBreakpointResult,
+  FunctionBreakpointRemovalResult,
+  DataBreakpointResult,
+  InstructionBreakpointResult,
+  MemoryReadResult,
+  SetVariableResult,
+  MemoryWriteResult,
+  StepOptions,
+  StepInTargetsResult
+} from './session-manager-operations.js';
 
 // Re-export the operations class for any direct usage needs
 export { SessionManagerOperations } from './session-manager-operations.js';
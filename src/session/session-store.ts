@@ -13,6 +13,9 @@ import {
   ExecutionState,
   DebugSessionInfo,
   Breakpoint,
+  DataBreakpoint,
+  FunctionBreakpoint,
+  InstructionBreakpoint,
   AdapterPolicy,
   DefaultAdapterPolicy,
   PythonAdapterPolicy,
@@ -20,6 +23,7 @@ import {
   RustAdapterPolicy,
   MockAdapterPolicy
 } from '@debugmcp/shared';
+import type { DebugProtocol } from '@vscode/debugprotocol';
 import { SessionNotFoundError } from '../errors/debug-errors.js';
 
 /**
@@ -42,6 +46,21 @@ export interface ToolchainValidationState {
   binaryInfo?: Record<string, unknown>;
 }
 
+/**
+ * A single DAP 'output' event captured for a session (program output, logpoints)
+ */
+export interface SessionOutputEntry {
+  /** Monotonic sequence number within the session, starting at 1 */
+  seq: number;
+  /** DAP output category, e.g. 'console', 'stdout', 'stderr' */
+  category: string;
+  output: string;
+  timestamp: number;
+  /** Source location reported by the adapter, if any */
+  file?: string;
+  line?: number;
+}
+
 /**
  * Internal session representation with full details
  */
@@ -54,6 +73,28 @@ export interface ManagedSession extends DebugSessionInfo {
   executionState?: ExecutionState;
   logDir?: string;
   toolchainValidation?: ToolchainValidationState;
+  // DAP exception breakpoint filters (e.g. 'rust_panic'), re-applied on every launch
+  exceptionFilters?: string[];
+  // Function breakpoints keyed by ID, re-applied on every launch
+  functionBreakpoints?: Map<string, FunctionBreakpoint>;
+  // Data breakpoints (watchpoints) keyed by ID; cleared on every launch since addresses change
+  dataBreakpoints?: Map<string, DataBreakpoint>;
+  // Instruction breakpoints keyed by ID; cleared on every launch for the same reason
+  instructionBreakpoints?: Map<string, InstructionBreakpoint>;
+  // Features the adapter reported as unavailable (feature -> reason), e.g. version-gated ones
+  unsupportedFeatures?: Record<string, string>;
+  // Architecture of the debugged program (e.g. 'x86_64'), when the adapter can tell
+  targetArchitecture?: string;
+  // Captured 'output' events (bounded; oldest entries are dropped first)
+  output?: SessionOutputEntry[];
+  // Sequence number of the most recently captured output entry
+  outputSeq?: number;
+  // Most recent 'stopped' event, used to report exception/panic details
+  lastStop?: {
+    threadId: number;
+    reason: string;
+    body?: DebugProtocol.StoppedEvent['body'];
+  };
 }
 
 /**
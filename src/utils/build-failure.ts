@@ -0,0 +1,37 @@
+/**
+ * Response payload for a failed build of the debug target, shared by
+ * start_debugging and debug_rust_test.
+ */
+
+import { AdapterErrorCode } from '@debugmcp/shared';
+import type { AdapterError, CompilerDiagnostic } from '@debugmcp/shared';
+
+export interface BuildFailureData {
+  message: string;
+  diagnostics: CompilerDiagnostic[];
+  /** Build output, only when there are no diagnostics */
+  output?: string;
+}
+
+/**
+ * Build the `data` payload for an error if it is a build failure.
+ * Matches by error code rather than class: adapters may load their own
+ * copy of @debugmcp/shared, so `instanceof BuildFailedError` is not reliable.
+ *
+ * @returns The payload, or undefined if the error is not a build failure
+ */
+export function getBuildFailureData(error: unknown): BuildFailureData | undefined {
+  if ((error as AdapterError | undefined)?.code !== AdapterErrorCode.BUILD_FAILED) {
+    return undefined;
+  }
+  const { message, diagnostics, output } = error as AdapterError & {
+    diagnostics?: CompilerDiagnostic[];
+    output?: string;
+  };
+  return {
+    message,
+    diagnostics: diagnostics ?? [],
+    // Linker and build script failures have no diagnostics; keep the output
+    ...(!diagnostics?.length && output ? { output } : {})
+  };
+}
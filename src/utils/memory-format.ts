@@ -0,0 +1,94 @@
+/**
+ * Formatting helpers for raw memory returned by DAP readMemory requests.
+ * Multi-byte interpretations follow the session's target architecture and
+ * default to a little-endian target with 8-byte pointers when it is unknown.
+ */
+import { getTargetPointerSize, isTargetBigEndian } from '@debugmcp/shared';
+
+const BYTES_PER_LINE = 16;
+
+/**
+ * Typed views of a memory block. Trailing bytes that do not fill a whole
+ * element are left out of that view.
+ */
+export interface MemoryInterpretations {
+  u8: number[];
+  u32: number[];
+  /** NaN and infinities are given as strings so they survive JSON serialization */
+  f64: Array<number | string>;
+  /** Decimal strings, since usize values can exceed Number.MAX_SAFE_INTEGER */
+  usize: string[];
+}
+
+/**
+ * Parse a raw address (`0x...` or decimal) and normalize it to lowercase hex.
+ * Returns undefined when the text is not a non-negative integer.
+ */
+export function normalizeAddress(address: string): string | undefined {
+  const text = address.trim();
+  if (!/^(0x[0-9a-f]+|\d+)$/i.test(text)) {
+    return undefined;
+  }
+  return `0x${BigInt(text).toString(16)}`;
+}
+
+/**
+ * Parse a hex byte string such as `de ad be ef`, `deadbeef` or `0xde,0xad`.
+ * Returns undefined when the text is empty or not whole bytes of hex.
+ */
+export function parseHexBytes(text: string): Buffer | undefined {
+  const digits = text.replace(/0x/gi, '').replace(/[\s,]/g, '');
+  if (digits.length === 0 || digits.length % 2 !== 0 || !/^[0-9a-f]+$/i.test(digits)) {
+    return undefined;
+  }
+  return Buffer.from(digits, 'hex');
+}
+
+/**
+ * Render bytes as hex dump lines: address, 16 hex bytes, then printable ASCII.
+ * Lines are labelled with offsets from 0 when the adapter's address is not numeric.
+ */
+export function formatHexDump(bytes: Buffer, baseAddress: string): string[] {
+  const normalized = normalizeAddress(baseAddress);
+  const base = normalized === undefined ? 0n : BigInt(normalized);
+  const width = Math.max(base + BigInt(bytes.length), 1n).toString(16).length;
+  const lines: string[] = [];
+
+  for (let offset = 0; offset < bytes.length; offset += BYTES_PER_LINE) {
+    const chunk = bytes.subarray(offset, offset + BYTES_PER_LINE);
+    const address = `0x${(base + BigInt(offset)).toString(16).padStart(width, '0')}`;
+    const hex = Array.from(chunk, b => b.toString(16).padStart(2, '0')).join(' ');
+    const ascii = Array.from(chunk, b => (b >= 0x20 && b < 0x7f ? String.fromCharCode(b) : '.')).join('');
+    lines.push(`${address}  ${hex.padEnd(BYTES_PER_LINE * 3 - 1)}  |${ascii}|`);
+  }
+
+  return lines;
+}
+
+/**
+ * Interpret bytes as u8, u32, f64 and usize arrays in the target's byte order,
+ * with usize as wide as the target's pointers.
+ */
+export function interpretMemory(bytes: Buffer, targetArchitecture?: string): MemoryInterpretations {
+  const bigEndian = isTargetBigEndian(targetArchitecture);
+  const pointerSize = getTargetPointerSize(targetArchitecture);
+
+  const u32: number[] = [];
+  for (let offset = 0; offset + 4 <= bytes.length; offset += 4) {
+    u32.push(bigEndian ? bytes.readUInt32BE(offset) : bytes.readUInt32LE(offset));
+  }
+  const f64: Array<number | string> = [];
+  for (let offset = 0; offset + 8 <= bytes.length; offset += 8) {
+    const value = bigEndian ? bytes.readDoubleBE(offset) : bytes.readDoubleLE(offset);
+    f64.push(Number.isFinite(value) ? value : String(value));
+  }
+  const usize: string[] = [];
+  for (let offset = 0; offset + pointerSize <= bytes.length; offset += pointerSize) {
+    if (pointerSize === 4) {
+      usize.push(String(u32[offset / 4]));
+    } else {
+      usize.push((bigEndian ? bytes.readBigUInt64BE(offset) : bytes.readBigUInt64LE(offset)).toString());
+    }
+  }
+  return { u8: Array.from(bytes), u32, f64, usize };
+}
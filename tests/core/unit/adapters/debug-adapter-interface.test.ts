@@ -63,15 +63,16 @@ describe('debug-adapter-interface', () => {
       expect(AdapterErrorCode.DEBUGGER_ERROR).toBe('DEBUGGER_ERROR');
       expect(AdapterErrorCode.SCRIPT_NOT_FOUND).toBe('SCRIPT_NOT_FOUND');
       expect(AdapterErrorCode.PERMISSION_DENIED).toBe('PERMISSION_DENIED');
+      expect(AdapterErrorCode.BUILD_FAILED).toBe('BUILD_FAILED');
     });
 
     it('should have generic error code', () => {
       expect(AdapterErrorCode.UNKNOWN_ERROR).toBe('UNKNOWN_ERROR');
     });
 
-    it('should have exactly 13 error codes', () => {
+    it('should have exactly 14 error codes', () => {
       const errorCodes = Object.values(AdapterErrorCode);
-      expect(errorCodes).toHaveLength(13);
+      expect(errorCodes).toHaveLength(14);
     });
   });
 
@@ -97,11 +98,12 @@ describe('debug-adapter-interface', () => {
       expect(DebugFeature.STEP_BACK).toBe('stepBack');
       expect(DebugFeature.REVERSE_DEBUGGING).toBe('reverseDebugging');
       expect(DebugFeature.STEP_IN_TARGETS_REQUEST).toBe('stepInTargetsRequest');
+      expect(DebugFeature.STEPPING_GRANULARITY).toBe('steppingGranularity');
     });
 
-    it('should have exactly 20 features', () => {
+    it('should have exactly 21 features', () => {
       const features = Object.values(DebugFeature);
-      expect(features).toHaveLength(20);
+      expect(features).toHaveLength(21);
     });
   });
 
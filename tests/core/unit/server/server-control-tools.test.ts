@@ -7,7 +7,8 @@ import { StdioServerTransport } from '@modelcontextprotocol/sdk/server/stdio.js'
 import { ErrorCode as McpErrorCode, McpError } from '@modelcontextprotocol/sdk/types.js';
 import { DebugMcpServer } from '../../../../src/server.js';
 import { SessionManager } from '../../../../src/session/session-manager.js';
-import { Breakpoint } from '@debugmcp/shared';
+import { Breakpoint, BuildFailedError, DebugLanguage } from '@debugmcp/shared';
+import { listRustTargets, resolveRustTest } from '@debugmcp/adapter-rust';
 import { createProductionDependencies } from '../../../../src/container/dependencies.js';
 import {
   createMockDependencies,
@@ -22,6 +23,10 @@ vi.mock('@modelcontextprotocol/sdk/server/index.js');
 vi.mock('@modelcontextprotocol/sdk/server/stdio.js');
 vi.mock('../../../../src/session/session-manager.js');
 vi.mock('../../../../src/container/dependencies.js');
+vi.mock('@debugmcp/adapter-rust', () => ({
+  listRustTargets: vi.fn(),
+  resolveRustTest: vi.fn()
+}));
 
 describe('Server Control Tools Tests', () => {
   let debugServer: DebugMcpServer;
@@ -83,6 +88,7 @@ describe('Server Control Tools Tests', () => {
         'test-session',
         expect.stringContaining('test.py'),
         10,
+        undefined,
         undefined
       );
       
@@ -125,8 +131,86 @@ describe('Server Control Tools Tests', () => {
         'test-session',
         expect.stringContaining('test.py'),
         20,
-        'x > 10'
+        'x > 10',
+        undefined
+      );
+    });
+
+    it('should pass logMessage through and label the result as a logpoint', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.setBreakpoint.mockResolvedValue({
+        id: 'bp-3',
+        file: 'main.rs',
+        line: 12,
+        logMessage: 'i={i} total={total}',
+        verified: true
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_breakpoint',
+          arguments: { sessionId: 'test-session', file: 'main.rs', line: 12, logMessage: 'i={i} total={total}' }
+        }
+      });
+
+      expect(mockSessionManager.setBreakpoint).toHaveBeenCalledWith(
+        'test-session',
+        expect.stringContaining('main.rs'),
+        12,
+        undefined,
+        { logMessage: 'i={i} total={total}' }
       );
+      const content = JSON.parse(result.content[0].text);
+      expect(content.logMessage).toBe('i={i} total={total}');
+      expect(content.message).toContain('Logpoint set at');
+    });
+
+    it('should pass hitCondition through and report the hit count', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.setBreakpoint.mockResolvedValue({
+        id: 'bp-4',
+        file: 'main.rs',
+        line: 8,
+        hitCondition: '== 5',
+        hitConditionVerified: true,
+        hitCount: 0,
+        verified: true
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_breakpoint',
+          arguments: { sessionId: 'test-session', file: 'main.rs', line: 8, hitCondition: '== 5' }
+        }
+      });
+
+      expect(mockSessionManager.setBreakpoint).toHaveBeenCalledWith(
+        'test-session',
+        expect.stringContaining('main.rs'),
+        8,
+        undefined,
+        { logMessage: undefined, hitCondition: '== 5' }
+      );
+      const content = JSON.parse(result.content[0].text);
+      expect(content).toMatchObject({ hitCondition: '== 5', hitConditionVerified: true, hitCount: 0 });
+    });
+
+    it('should reject logMessage with unbalanced braces', async () => {
+      await expect(callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_breakpoint',
+          arguments: { sessionId: 'test-session', file: 'main.rs', line: 12, logMessage: 'i={i' }
+        }
+      })).rejects.toThrow("unmatched '{'");
     });
 
     it('should handle SessionManager errors', async () => {
@@ -152,6 +236,641 @@ describe('Server Control Tools Tests', () => {
     });
   });
 
+  describe('set_exception_breakpoints', () => {
+    it('should forward filters to the session manager', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.setExceptionBreakpoints.mockResolvedValue({
+        success: true,
+        filters: ['rust_panic'],
+        applied: true,
+        breakpoints: [{ filter: 'rust_panic', verified: true }]
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_exception_breakpoints',
+          arguments: { sessionId: 'test-session', filters: ['rust_panic'] }
+        }
+      });
+
+      expect(mockSessionManager.setExceptionBreakpoints).toHaveBeenCalledWith('test-session', ['rust_panic']);
+      const content = JSON.parse(result.content[0].text);
+      expect(content).toMatchObject({
+        success: true,
+        filters: ['rust_panic'],
+        applied: true,
+        breakpoints: [{ filter: 'rust_panic', verified: true }]
+      });
+    });
+
+    it('should reject non-array filters', async () => {
+      await expect(callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_exception_breakpoints',
+          arguments: { sessionId: 'test-session', filters: 'rust_panic' }
+        }
+      })).rejects.toThrow(McpError);
+    });
+
+    it('should report missing sessions without throwing', async () => {
+      mockSessionManager.getSession.mockReturnValue(null);
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_exception_breakpoints',
+          arguments: { sessionId: 'test-session', filters: ['rust_panic'] }
+        }
+      });
+
+      const content = JSON.parse(result.content[0].text);
+      expect(content.success).toBe(false);
+      expect(content.error).toContain('Session not found: test-session');
+    });
+  });
+
+  describe('disassemble', () => {
+    it('should interleave source lines with instructions', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockDependencies.fileSystem.readFile.mockResolvedValue('fn main() {\n    let total = add(1, 2);\n}\n');
+      mockSessionManager.disassemble.mockResolvedValue({
+        success: true,
+        frameId: 1000,
+        instructionPointer: '0x1004',
+        instructions: [
+          { address: '0x1000', instruction: 'mov edi, 0x1', file: '/work/src/main.rs', line: 2, isCurrent: false },
+          { address: '0x1004', instruction: 'call add', file: '/work/src/main.rs', line: 2, isCurrent: true },
+          { address: '0x1009', instruction: 'ret', isCurrent: false }
+        ]
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'disassemble',
+          arguments: { sessionId: 'test-session', instructionsContext: 4 }
+        }
+      });
+
+      expect(mockSessionManager.disassemble).toHaveBeenCalledWith('test-session', {
+        frameId: undefined,
+        instructionsBefore: 4,
+        instructionsAfter: 4
+      });
+      const content = JSON.parse(result.content[0].text);
+      expect(content.success).toBe(true);
+      expect(content.listing.map((entry: { kind: string }) => entry.kind)).toEqual([
+        'source',
+        'instruction',
+        'instruction',
+        'instruction'
+      ]);
+      expect(content.listing[0]).toMatchObject({ line: 2, source: '    let total = add(1, 2);' });
+      expect(content.listing[2]).toMatchObject({ address: '0x1004', isCurrent: true });
+    });
+
+    it('should reject an out-of-range instructionsContext', async () => {
+      await expect(callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'disassemble',
+          arguments: { sessionId: 'test-session', instructionsContext: 500 }
+        }
+      })).rejects.toThrow(McpError);
+    });
+  });
+
+  describe('list_async_tasks', () => {
+    it('should return the runtimes and their tasks', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      const task = {
+        id: 2,
+        state: 'idle',
+        cancelled: false,
+        future: 'async_example::async_task',
+        suspendState: 'Suspend0',
+        suspendedIn: 'tokio::time::sleep::Sleep',
+        awaitChain: ['async_example::async_task', 'tokio::time::sleep::Sleep']
+      };
+      mockSessionManager.listAsyncTasks.mockResolvedValue({
+        success: true,
+        runtimes: [{ flavor: 'multi_thread', tasks: [task] }]
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: { name: 'list_async_tasks', arguments: { sessionId: 'test-session' } }
+      });
+
+      expect(mockSessionManager.listAsyncTasks).toHaveBeenCalledWith('test-session');
+      const content = JSON.parse(result.content[0].text);
+      expect(content).toEqual({ success: true, runtimes: [{ flavor: 'multi_thread', tasks: [task] }] });
+    });
+
+    it('should report why tasks could not be listed', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.listAsyncTasks.mockResolvedValue({
+        success: false,
+        runtimes: [],
+        error: 'Async task inspection is not supported for python'
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: { name: 'list_async_tasks', arguments: { sessionId: 'test-session' } }
+      });
+
+      expect(JSON.parse(result.content[0].text)).toEqual({
+        success: false,
+        error: 'Async task inspection is not supported for python'
+      });
+    });
+  });
+
+  describe('set_variable', () => {
+    it('should set a variable and return the session manager result', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.setVariable.mockResolvedValue({
+        success: true,
+        name: 'count',
+        previousValue: '3',
+        value: '42',
+        type: 'i32',
+        variablesReference: 0
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_variable',
+          arguments: { sessionId: 'test-session', variablesReference: 1001, name: 'count', value: '42' }
+        }
+      });
+
+      expect(mockSessionManager.setVariable).toHaveBeenCalledWith('test-session', 1001, 'count', '42');
+      const content = JSON.parse(result.content[0].text);
+      expect(content).toMatchObject({ success: true, previousValue: '3', value: '42' });
+    });
+
+    it('should require a variablesReference', async () => {
+      await expect(callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_variable',
+          arguments: { sessionId: 'test-session', name: 'count', value: '42' }
+        }
+      })).rejects.toThrow(McpError);
+    });
+  });
+
+  describe('debug_rust_test', () => {
+    it('should set a breakpoint on the test body and launch the harness', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        language: DebugLanguage.RUST,
+        sessionLifecycle: 'ACTIVE'
+      });
+      vi.mocked(resolveRustTest).mockResolvedValue({
+        testName: 'tests::it_adds',
+        executable: '/proj/target/debug/deps/calc-1111111111111111',
+        target: { name: 'calc', kind: ['lib'], src_path: '/proj/src/lib.rs' },
+        args: ['--exact', 'tests::it_adds', '--nocapture', '--test-threads=1'],
+        cwd: '/proj',
+        location: { file: '/proj/src/lib.rs', line: 12 }
+      });
+      mockSessionManager.setBreakpoint.mockResolvedValue({
+        id: 'bp-1',
+        file: '/proj/src/lib.rs',
+        line: 12,
+        verified: false
+      });
+      mockSessionManager.startDebugging.mockResolvedValue({ success: true, state: 'running' });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'debug_rust_test',
+          arguments: { sessionId: 'test-session', path: '/proj', testName: 'it_adds' }
+        }
+      });
+
+      expect(resolveRustTest).toHaveBeenCalledWith('/proj', 'it_adds', expect.objectContaining({ target: undefined }));
+      expect(mockSessionManager.setBreakpoint).toHaveBeenCalledWith(
+        'test-session', '/proj/src/lib.rs', 12, undefined, undefined
+      );
+      expect(mockSessionManager.startDebugging).toHaveBeenCalledWith(
+        'test-session',
+        '/proj/target/debug/deps/calc-1111111111111111',
+        ['--exact', 'tests::it_adds', '--nocapture', '--test-threads=1'],
+        undefined,
+        undefined,
+        { cwd: '/proj' },
+        undefined
+      );
+      const content = JSON.parse(result.content[0].text);
+      expect(content).toMatchObject({
+        success: true,
+        testName: 'tests::it_adds',
+        breakpoint: { id: 'bp-1', line: 12 }
+      });
+    });
+
+    it('should return resolution errors without launching', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        language: DebugLanguage.RUST,
+        sessionLifecycle: 'ACTIVE'
+      });
+      vi.mocked(resolveRustTest).mockRejectedValue(new Error('No test named "missing" found'));
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'debug_rust_test',
+          arguments: { sessionId: 'test-session', path: '/proj', testName: 'missing', testTarget: 'api' }
+        }
+      });
+
+      expect(resolveRustTest).toHaveBeenCalledWith('/proj', 'missing', expect.objectContaining({
+        target: { kind: 'test', name: 'api' }
+      }));
+      expect(mockSessionManager.startDebugging).not.toHaveBeenCalled();
+      expect(JSON.parse(result.content[0].text)).toEqual({ success: false, error: 'No test named "missing" found' });
+    });
+
+    it('should build tests with the cargo options and return build diagnostics', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        language: DebugLanguage.RUST,
+        sessionLifecycle: 'ACTIVE'
+      });
+      const diagnostic = {
+        level: 'error',
+        code: 'E0308',
+        message: 'mismatched types',
+        file: '/proj/src/lib.rs',
+        line: 3,
+        column: 5,
+        suggestions: []
+      };
+      vi.mocked(resolveRustTest).mockRejectedValue(
+        new BuildFailedError('Cargo test build failed: 1 error: E0308 mismatched types at /proj/src/lib.rs:3:5', [diagnostic as any])
+      );
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'debug_rust_test',
+          arguments: {
+            sessionId: 'test-session',
+            path: '/proj',
+            testName: 'it_adds',
+            dapLaunchArgs: { cargo: { release: true, features: ['fast'], target: 'aarch64-unknown-linux-gnu' } }
+          }
+        }
+      });
+
+      expect(resolveRustTest).toHaveBeenCalledWith('/proj', 'it_adds', expect.objectContaining({
+        build: expect.objectContaining({ release: true, features: ['fast'], target: 'aarch64-unknown-linux-gnu' }),
+        hooks: expect.any(Object)
+      }));
+      expect(mockSessionManager.startDebugging).not.toHaveBeenCalled();
+      const content = JSON.parse(result.content[0].text);
+      expect(content).toMatchObject({
+        success: false,
+        error: expect.stringContaining('E0308'),
+        data: { diagnostics: [expect.objectContaining({ code: 'E0308', line: 3 })] }
+      });
+      expect(content.data.output).toBeUndefined();
+    });
+
+    it('should reject non-Rust sessions', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        language: DebugLanguage.PYTHON,
+        sessionLifecycle: 'ACTIVE'
+      });
+
+      await expect(callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'debug_rust_test',
+          arguments: { sessionId: 'test-session', path: '/proj', testName: 'it_adds' }
+        }
+      })).rejects.toThrow('requires a Rust session');
+    });
+  });
+
+  describe('list_rust_targets', () => {
+    it('should list targets without a session', async () => {
+      vi.mocked(listRustTargets).mockResolvedValue({
+        workspaceRoot: '/ws',
+        targetDirectory: '/ws/target',
+        targets: [
+          {
+            package: 'app',
+            name: 'app',
+            kind: 'bin',
+            srcPath: '/ws/app/src/main.rs',
+            requiredFeatures: [],
+            debugBuild: '/ws/target/debug/app',
+            stale: false
+          },
+          {
+            package: 'app',
+            name: 'tool',
+            kind: 'bin',
+            srcPath: '/ws/app/src/bin/tool.rs',
+            requiredFeatures: ['cli'],
+            debugBuild: null,
+            stale: null
+          }
+        ]
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: { name: 'list_rust_targets', arguments: { path: '/ws' } }
+      });
+
+      expect(listRustTargets).toHaveBeenCalledWith('/ws');
+      expect(mockSessionManager.getSession).not.toHaveBeenCalled();
+      const content = JSON.parse(result.content[0].text);
+      expect(content).toMatchObject({ success: true, workspaceRoot: '/ws', count: 2 });
+      expect(content.targets[1]).toMatchObject({ name: 'tool', requiredFeatures: ['cli'], debugBuild: null });
+    });
+
+    it('should report cargo failures', async () => {
+      vi.mocked(listRustTargets).mockRejectedValue(new Error('cargo metadata failed for /tmp'));
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: { name: 'list_rust_targets', arguments: { path: '/tmp' } }
+      });
+
+      expect(JSON.parse(result.content[0].text)).toEqual({ success: false, error: 'cargo metadata failed for /tmp' });
+    });
+  });
+
+  describe('read_memory', () => {
+    it('should return a hex dump and typed interpretations for a raw address', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.readMemory.mockResolvedValue({
+        success: true,
+        address: '0x5000',
+        bytes: Buffer.from([0x2a, 0, 0, 0, 0x68, 0x69, 0, 0]),
+        unreadableBytes: 0
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'read_memory',
+          arguments: { sessionId: 'test-session', address: '20480', count: 8 }
+        }
+      });
+
+      expect(mockSessionManager.readMemory).toHaveBeenCalledWith('test-session', '0x5000', 8, 0);
+      const content = JSON.parse(result.content[0].text);
+      expect(content.success).toBe(true);
+      expect(content.hexDump[0]).toMatch(/^0x5000  2a 00 00 00 68 69 00 00 +\|\*\.\.\.hi\.\.\|$/);
+      expect(content.interpretations.u32).toEqual([42, 0x6968]);
+      expect(content.interpretations.usize).toEqual([(0x6968n * 2n ** 32n + 42n).toString()]);
+    });
+
+    it('should require exactly one of memoryReference and address', async () => {
+      await expect(callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'read_memory',
+          arguments: { sessionId: 'test-session', memoryReference: '0x10', address: '0x10' }
+        }
+      })).rejects.toThrow('Provide exactly one of memoryReference or address');
+    });
+  });
+
+  describe('write_memory', () => {
+    it('should decode hex data and write it at the memory reference', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.writeMemory.mockResolvedValue({ success: true, bytesWritten: 4 });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'write_memory',
+          arguments: { sessionId: 'test-session', memoryReference: '0x7ffc1000', offset: 8, data: '2a 00 00 00' }
+        }
+      });
+
+      expect(mockSessionManager.writeMemory).toHaveBeenCalledWith(
+        'test-session',
+        '0x7ffc1000',
+        Buffer.from([0x2a, 0, 0, 0]),
+        { offset: 8, allowPartial: undefined }
+      );
+      const content = JSON.parse(result.content[0].text);
+      expect(content).toMatchObject({ success: true, bytesWritten: 4, offset: 8 });
+    });
+
+    it('should reject data that is not whole hex bytes', async () => {
+      await expect(callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'write_memory',
+          arguments: { sessionId: 'test-session', address: '0x10', data: '2a0' }
+        }
+      })).rejects.toThrow(McpError);
+    });
+  });
+
+  describe('get_debug_output', () => {
+    it('should return captured entries and the last sequence number', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'TERMINATED'
+      });
+      mockSessionManager.getOutput.mockReturnValue({
+        entries: [{ seq: 4, category: 'console', output: 'i=3 total=6\n', timestamp: 1 }],
+        lastSeq: 4,
+        truncated: false
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'get_debug_output',
+          arguments: { sessionId: 'test-session', sinceSeq: 3, category: 'console' }
+        }
+      });
+
+      expect(mockSessionManager.getOutput).toHaveBeenCalledWith('test-session', {
+        sinceSeq: 3,
+        category: 'console',
+        limit: undefined
+      });
+      const content = JSON.parse(result.content[0].text);
+      expect(content).toMatchObject({ success: true, count: 1, lastSeq: 4, truncated: false });
+    });
+  });
+
+  describe('set_function_breakpoint', () => {
+    it('should return every resolved location', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.setFunctionBreakpoint.mockResolvedValue({
+        success: true,
+        applied: true,
+        breakpoint: {
+          id: 'fbp-1',
+          name: 'app::largest',
+          verified: true,
+          locations: [
+            { function: 'app::largest', file: 'main.rs', line: 4, address: '0x1000', verified: true },
+            { function: 'app::largest', file: 'main.rs', line: 4, address: '0x2000', verified: true }
+          ]
+        }
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_function_breakpoint',
+          arguments: { sessionId: 'test-session', name: 'app::largest' }
+        }
+      });
+
+      expect(mockSessionManager.setFunctionBreakpoint).toHaveBeenCalledWith('test-session', 'app::largest', undefined);
+      const content = JSON.parse(result.content[0].text);
+      expect(content).toMatchObject({ success: true, breakpointId: 'fbp-1', verified: true, applied: true });
+      expect(content.locations).toHaveLength(2);
+      expect(content.message).toContain('2 location(s)');
+    });
+
+    it('should reject an empty function name', async () => {
+      await expect(callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_function_breakpoint',
+          arguments: { sessionId: 'test-session', name: '  ' }
+        }
+      })).rejects.toThrow(McpError);
+    });
+
+    it('should remove a function breakpoint by ID', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.removeFunctionBreakpoint.mockResolvedValue({
+        success: true,
+        applied: true,
+        breakpoint: { id: 'fbp-1', name: 'app::largest', verified: true, locations: [] }
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'remove_function_breakpoint',
+          arguments: { sessionId: 'test-session', breakpointId: 'fbp-1' }
+        }
+      });
+
+      expect(mockSessionManager.removeFunctionBreakpoint).toHaveBeenCalledWith('test-session', 'fbp-1');
+      expect(JSON.parse(result.content[0].text)).toEqual({
+        success: true,
+        breakpointId: 'fbp-1',
+        name: 'app::largest',
+        applied: true,
+        message: 'Function breakpoint on app::largest removed'
+      });
+    });
+  });
+
+  describe('data breakpoints', () => {
+    beforeEach(() => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+    });
+
+    it('should default set_data_breakpoint to write access', async () => {
+      mockSessionManager.setDataBreakpoint.mockResolvedValue({
+        success: true,
+        name: 'loop_total',
+        accessTypes: ['write', 'read', 'readWrite'],
+        breakpoint: { id: 'dbp-1', dataId: '0x7ffc/4', name: 'loop_total', accessType: 'write', verified: true },
+        remainingSlots: 3
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_data_breakpoint',
+          arguments: { sessionId: 'test-session', name: 'loop_total' }
+        }
+      });
+
+      expect(mockSessionManager.setDataBreakpoint).toHaveBeenCalledWith('test-session', 'loop_total', 'write', {
+        variablesReference: undefined,
+        frameId: undefined,
+        condition: undefined
+      });
+      const content = JSON.parse(result.content[0].text);
+      expect(content).toMatchObject({ success: true, remainingSlots: 3, breakpoint: { id: 'dbp-1' } });
+    });
+
+    it('should reject unknown access types', async () => {
+      await expect(callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_data_breakpoint',
+          arguments: { sessionId: 'test-session', name: 'loop_total', accessType: 'execute' }
+        }
+      })).rejects.toThrow(McpError);
+    });
+
+    it('should remove a data breakpoint by id', async () => {
+      mockSessionManager.removeDataBreakpoint.mockResolvedValue({ success: true, name: 'loop_total', remainingSlots: 4 });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'remove_data_breakpoint',
+          arguments: { sessionId: 'test-session', breakpointId: 'dbp-1' }
+        }
+      });
+
+      expect(mockSessionManager.removeDataBreakpoint).toHaveBeenCalledWith('test-session', 'dbp-1');
+      expect(JSON.parse(result.content[0].text)).toMatchObject({ success: true, remainingSlots: 4 });
+    });
+  });
+
   describe('start_debugging', () => {
     it('should start debugging successfully', async () => {
       // Mock session validation
@@ -187,7 +906,8 @@ describe('Server Control Tools Tests', () => {
         ['--debug'],
         { stopOnEntry: true, justMyCode: false },
         undefined,
-        undefined
+        undefined,
+        expect.any(Object)
       );
       
       const content = JSON.parse(result.content[0].text);
@@ -225,13 +945,63 @@ describe('Server Control Tools Tests', () => {
         undefined,
         undefined,
         true,
-        undefined
+        undefined,
+        expect.any(Object)
       );
       
       const content = JSON.parse(result.content[0].text);
       expect(content.data.dryRun).toBe(true);
     });
 
+    it('should forward build progress and cancellation of the request', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.startDebugging.mockResolvedValue({
+        success: true,
+        state: 'running',
+        data: { message: 'Debugging started' }
+      });
+      const controller = new AbortController();
+      const sendNotification = vi.fn().mockResolvedValue(undefined);
+
+      await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'start_debugging',
+          arguments: { sessionId: 'test-session', scriptPath: 'src/main.rs' },
+          _meta: { progressToken: 'build-1' }
+        }
+      }, { signal: controller.signal, sendNotification });
+
+      const launchOptions = mockSessionManager.startDebugging.mock.calls[0][6];
+      expect(launchOptions.signal).toBe(controller.signal);
+      launchOptions.onProgress({ progress: 3, total: 28, message: 'Compiled tokio (lib "tokio")' });
+      expect(sendNotification).toHaveBeenCalledWith({
+        method: 'notifications/progress',
+        params: { progressToken: 'build-1', progress: 3, total: 28, message: 'Compiled tokio (lib "tokio")' }
+      });
+    });
+
+    it('should not report progress without a progress token', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.startDebugging.mockResolvedValue({ success: true, state: 'running' });
+
+      await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'start_debugging',
+          arguments: { sessionId: 'test-session', scriptPath: 'src/main.rs' }
+        }
+      }, { signal: new AbortController().signal, sendNotification: vi.fn() });
+
+      expect(mockSessionManager.startDebugging.mock.calls[0][6].onProgress).toBeUndefined();
+    });
+
     it('should handle SessionManager errors', async () => {
       // Mock getSession to return null - session not found
       mockSessionManager.getSession.mockReturnValue(null);
@@ -277,7 +1047,7 @@ describe('Server Control Tools Tests', () => {
         }
       });
       
-      expect(mockSessionManager[methodName]).toHaveBeenCalledWith('test-session');
+      expect(mockSessionManager[methodName]).toHaveBeenCalledWith('test-session', { granularity: undefined });
       const content = JSON.parse(result.content[0].text);
       expect(content.success).toBe(true);
       expect(content.message).toBe(expectedMessage);
@@ -331,6 +1101,192 @@ describe('Server Control Tools Tests', () => {
       expect(content.success).toBe(false);
       expect(content.error).toBe('Not paused');
     });
+
+    it('should include panic details when a step stops on an exception', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      const exception = {
+        reason: 'exception',
+        message: 'attempt to divide by zero',
+        location: { file: '/work/src/main.rs', line: 7 },
+        frames: [{ id: 3, name: 'hello_world::divide', file: '/work/src/main.rs', line: 7 }],
+        unwoundFrames: 2
+      };
+      mockSessionManager.stepOver.mockResolvedValue({
+        success: true,
+        state: 'paused',
+        data: { message: 'Step completed.', location: exception.location, exception }
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'step_over',
+          arguments: { sessionId: 'test-session' }
+        }
+      });
+
+      const content = JSON.parse(result.content[0].text);
+      expect(content.location).toEqual({ file: '/work/src/main.rs', line: 7 });
+      expect(content.exception).toEqual(exception);
+    });
+  });
+
+  describe('instruction stepping', () => {
+    it('should pass granularity through and report the instruction', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      const instruction = {
+        address: '0x1004',
+        instructionBytes: '48 89 e5',
+        instruction: 'mov rbp, rsp',
+        symbol: 'hello_world::main',
+        file: '/work/src/main.rs',
+        line: 3,
+        isCurrent: true
+      };
+      mockSessionManager.stepInto.mockResolvedValue({
+        success: true,
+        state: 'paused',
+        data: { message: 'Step into completed.', instructionPointer: '0x1004', instruction }
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'step_into',
+          arguments: { sessionId: 'test-session', granularity: 'instruction' }
+        }
+      });
+
+      expect(mockSessionManager.stepInto).toHaveBeenCalledWith('test-session', { granularity: 'instruction' });
+      const content = JSON.parse(result.content[0].text);
+      expect(content.instructionPointer).toBe('0x1004');
+      expect(content.instruction).toEqual({
+        address: '0x1004',
+        instructionBytes: '48 89 e5',
+        instruction: 'mov rbp, rsp',
+        symbol: 'hello_world::main'
+      });
+    });
+
+    it('should reject an unknown granularity', async () => {
+      await expect(callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'step_over',
+          arguments: { sessionId: 'test-session', granularity: 'byte' }
+        }
+      })).rejects.toThrow(McpError);
+    });
+  });
+
+  describe('step-in targets', () => {
+    it('should list step-in targets for the current line', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.getStepInTargets.mockResolvedValue({
+        success: true,
+        frameId: 1000,
+        targets: [
+          { id: 1, label: 'core::iter::traits::iterator::Iterator::map' },
+          { id: 2, label: 'hello_world::calculate_sum' }
+        ]
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'get_step_in_targets',
+          arguments: { sessionId: 'test-session' }
+        }
+      });
+
+      expect(mockSessionManager.getStepInTargets).toHaveBeenCalledWith('test-session', undefined);
+      const content = JSON.parse(result.content[0].text);
+      expect(content.count).toBe(2);
+      expect(content.targets[1]).toEqual({ id: 2, label: 'hello_world::calculate_sum' });
+    });
+
+    it('should pass targetId to step_into', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.stepInto.mockResolvedValue({ success: true, state: 'paused' });
+
+      await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'step_into',
+          arguments: { sessionId: 'test-session', targetId: 2 }
+        }
+      });
+
+      expect(mockSessionManager.stepInto).toHaveBeenCalledWith('test-session', { granularity: undefined, targetId: 2 });
+    });
+
+    it('should reject targetId on step_over', async () => {
+      await expect(callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'step_over',
+          arguments: { sessionId: 'test-session', targetId: 2 }
+        }
+      })).rejects.toThrow(McpError);
+    });
+  });
+
+  describe('set_instruction_breakpoint', () => {
+    it('should set an instruction breakpoint', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+      mockSessionManager.setInstructionBreakpoint.mockResolvedValue({
+        success: true,
+        breakpoint: {
+          id: 'ibp-1',
+          address: '0x1004',
+          verified: true,
+          location: { file: '/work/src/main.rs', line: 3, address: '0x1004', verified: true }
+        }
+      });
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_instruction_breakpoint',
+          arguments: { sessionId: 'test-session', address: ' 0x1004 ', hitCondition: '>= 2' }
+        }
+      });
+
+      expect(mockSessionManager.setInstructionBreakpoint).toHaveBeenCalledWith('test-session', '0x1004', {
+        condition: undefined,
+        hitCondition: '>= 2'
+      });
+      const content = JSON.parse(result.content[0].text);
+      expect(content.success).toBe(true);
+      expect(content.breakpointId).toBe('ibp-1');
+      expect(content.message).toBe('Instruction breakpoint set at 0x1004');
+      expect(content.location).toMatchObject({ file: '/work/src/main.rs', line: 3 });
+    });
+
+    it('should require an address', async () => {
+      await expect(callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'set_instruction_breakpoint',
+          arguments: { sessionId: 'test-session' }
+        }
+      })).rejects.toThrow(McpError);
+    });
   });
 
   describe('continue_execution', () => {
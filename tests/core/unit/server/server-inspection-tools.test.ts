@@ -207,6 +207,44 @@ describe('Server Inspection Tools Tests', () => {
       expect(content.stackFrames).toHaveLength(1);
     });
 
+    it('should report the await chain in async stack mode', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        proxyManager: {
+          getCurrentThreadId: vi.fn().mockReturnValue(1)
+        }
+      });
+      mockSessionManager.getStackTrace.mockResolvedValue([
+        {
+          id: 1,
+          name: 'async_example::fetch_data::{async_fn#0}',
+          file: '/ws/src/main.rs',
+          line: 46,
+          asyncBody: { kind: 'fn', function: 'async_example::fetch_data' }
+        },
+        {
+          id: 2,
+          name: 'async_example::main::{async_block#0}',
+          file: '/ws/src/main.rs',
+          line: 17,
+          asyncBody: { kind: 'block', function: 'async_example::main' }
+        },
+        { id: -1, name: '[block_on: 12 frames]', file: '/cargo/tokio/src/runtime/park.rs', line: 284, collapsedFrames: 12 }
+      ]);
+
+      const result = await callToolHandler({
+        method: 'tools/call',
+        params: {
+          name: 'get_stack_trace',
+          arguments: { sessionId: 'test-session', asyncStack: true }
+        }
+      });
+
+      expect(mockSessionManager.getStackTrace).toHaveBeenCalledWith('test-session', 1, false, true);
+      const content = JSON.parse(result.content[0].text);
+      expect(content.awaitChain).toEqual(['async_example::main', 'async_example::fetch_data']);
+      expect(content.stackFrames[2].collapsedFrames).toBe(12);
+    });
+
     it('should handle missing session', async () => {
       mockSessionManager.getSession.mockReturnValue(null);
 
@@ -328,6 +366,22 @@ describe('Server Inspection Tools Tests', () => {
       expect(content.scopes).toHaveLength(1);
     });
 
+    it('should reject collapsed frame placeholders', async () => {
+      mockSessionManager.getSession.mockReturnValue({
+        id: 'test-session',
+        sessionLifecycle: 'ACTIVE'
+      });
+
+      for (const [name, extra] of [['get_scopes', {}], ['evaluate_expression', { expression: 'x' }]] as const) {
+        await expect(callToolHandler({
+          method: 'tools/call',
+          params: { name, arguments: { sessionId: 'test-session', frameId: -1, ...extra } }
+        })).rejects.toThrow(/placeholder for collapsed runtime frames/);
+      }
+      expect(mockSessionManager.getScopes).not.toHaveBeenCalled();
+      expect(mockSessionManager.evaluateExpression).not.toHaveBeenCalled();
+    });
+
     it('should handle SessionManager errors', async () => {
       // Mock getSession to return null - session not found
       mockSessionManager.getSession.mockReturnValue(null);
@@ -89,6 +89,22 @@ export function createMockSessionManager(mockAdapterRegistry: any) {
     closeSession: vi.fn(),
     closeAllSessions: vi.fn(),
     setBreakpoint: vi.fn(),
+    setExceptionBreakpoints: vi.fn(),
+    setFunctionBreakpoint: vi.fn(),
+    removeFunctionBreakpoint: vi.fn(),
+    setInstructionBreakpoint: vi.fn(),
+    getExceptionStopInfo: vi.fn().mockResolvedValue(null),
+    getOutput: vi.fn(),
+    getHitBreakpoints: vi.fn().mockResolvedValue([]),
+    disassemble: vi.fn(),
+    listAsyncTasks: vi.fn(),
+    readMemory: vi.fn(),
+    getStepInTargets: vi.fn(),
+    setVariable: vi.fn(),
+    writeMemory: vi.fn(),
+    getDataBreakpointInfo: vi.fn(),
+    setDataBreakpoint: vi.fn(),
+    removeDataBreakpoint: vi.fn(),
     startDebugging: vi.fn(),
     stepOver: vi.fn(),
     stepInto: vi.fn(),
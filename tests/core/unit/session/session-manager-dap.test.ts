@@ -3,7 +3,7 @@
  */
 import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
 import { SessionManager, SessionManagerConfig } from '../../../../src/session/session-manager.js';
-import { DebugLanguage } from '@debugmcp/shared';
+import { DebugLanguage, RustAdapterPolicy } from '@debugmcp/shared';
 import { createMockDependencies } from './session-manager-test-utils.js';
 import { ErrorMessages } from '../../../../src/utils/error-messages.js';
 import { ProxyNotRunningError } from '../../../../src/errors/debug-errors.js';
@@ -227,6 +227,719 @@ describe('SessionManager - DAP Operations', () => {
     });
   });
 
+  describe('Exception Breakpoints', () => {
+    it('should queue exception filters and pass them to the proxy at launch', async () => {
+      const session = await sessionManager.createSession({
+        language: DebugLanguage.MOCK,
+        executablePath: 'python'
+      });
+
+      const result = await sessionManager.setExceptionBreakpoints(session.id, ['rust_panic', 'rust_panic']);
+      expect(result).toMatchObject({ success: true, applied: false, filters: ['rust_panic'] });
+      expect(dependencies.mockProxyManager.dapRequestCalls).toHaveLength(0);
+
+      await sessionManager.startDebugging(session.id, 'test.py');
+      await vi.runAllTimersAsync();
+
+      expect(dependencies.mockProxyManager.startCalls[0].exceptionFilters).toEqual(['rust_panic']);
+    });
+
+    it('should send exception filters to an active session', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command) => {
+        if (command === 'setExceptionBreakpoints') {
+          return { success: true, body: { breakpoints: [{ verified: true }] } };
+        }
+        return { success: true };
+      });
+
+      const result = await sessionManager.setExceptionBreakpoints(session.id, ['rust_panic']);
+
+      expect(result).toMatchObject({
+        success: true,
+        applied: true,
+        breakpoints: [{ filter: 'rust_panic', verified: true }]
+      });
+      expect(dependencies.mockProxyManager.dapRequestCalls).toContainEqual({
+        command: 'setExceptionBreakpoints',
+        args: { filters: ['rust_panic'] }
+      });
+    });
+
+    it('should unwind to the first user frame when stopped on an exception', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command) => {
+        if (command === 'exceptionInfo') {
+          return { success: true, body: { exceptionId: 'rust_panic', description: 'called `Option::unwrap()` on a `None` value' } };
+        }
+        if (command === 'stackTrace') {
+          return {
+            success: true,
+            body: {
+              stackFrames: [
+                { id: 1, name: 'rust_panic', line: 0, column: 0 },
+                { id: 2, name: 'main', source: { path: 'test.py' }, line: 12, column: 5 }
+              ]
+            }
+          };
+        }
+        return { success: true };
+      });
+
+      expect(await sessionManager.getExceptionStopInfo(session.id)).toBeNull();
+
+      dependencies.mockProxyManager.simulateStopped(1, 'exception');
+      const info = await sessionManager.getExceptionStopInfo(session.id);
+
+      expect(info).toMatchObject({
+        reason: 'exception',
+        exceptionId: 'rust_panic',
+        message: 'called `Option::unwrap()` on a `None` value',
+        location: { file: 'test.py', line: 12, column: 5 },
+        unwoundFrames: 1
+      });
+      expect(info?.frames.map(f => f.name)).toEqual(['main']);
+    });
+  });
+
+  describe('Disassembly', () => {
+    it('should disassemble around the instruction pointer of the top frame', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command) => {
+        if (command === 'stackTrace') {
+          return {
+            success: true,
+            body: {
+              stackFrames: [
+                { id: 7, name: 'main', source: { path: 'test.py' }, line: 3, column: 1, instructionPointerReference: '0x1004' }
+              ]
+            }
+          };
+        }
+        if (command === 'disassemble') {
+          return {
+            success: true,
+            body: {
+              instructions: [
+                { address: '0x1000', instruction: 'push rbp', location: { path: 'test.py' }, line: 3 },
+                { address: '0x1004', instruction: 'mov rbp, rsp', line: 3 },
+                { address: '0x1007', instruction: 'nop' }
+              ]
+            }
+          };
+        }
+        return { success: true };
+      });
+
+      const result = await sessionManager.disassemble(session.id, { instructionsBefore: 1, instructionsAfter: 1 });
+
+      expect(result.success).toBe(true);
+      expect(result.instructionPointer).toBe('0x1004');
+      expect(dependencies.mockProxyManager.dapRequestCalls).toContainEqual({
+        command: 'disassemble',
+        args: { memoryReference: '0x1004', offset: 0, instructionOffset: -1, instructionCount: 3, resolveSymbols: true }
+      });
+      expect(result.instructions.map(i => i.isCurrent)).toEqual([false, true, false]);
+      // Location is carried forward from the previous instruction
+      expect(result.instructions[1]).toMatchObject({ file: 'test.py', line: 3 });
+      expect(result.instructions[2].file).toBeUndefined();
+    });
+
+    it('should fail when the frame has no instruction pointer', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command) => {
+        if (command === 'stackTrace') {
+          return { success: true, body: { stackFrames: [{ id: 7, name: 'main', source: { path: 'test.py' }, line: 3 }] } };
+        }
+        return { success: true };
+      });
+
+      const result = await sessionManager.disassemble(session.id);
+
+      expect(result.success).toBe(false);
+      expect(result.error).toContain('instruction pointer');
+    });
+  });
+
+  describe('Async Tasks', () => {
+    it('should list tasks with the command from the adapter policy', async () => {
+      const session = await createPausedSession();
+      vi.spyOn(sessionManager as unknown as { selectPolicy: () => unknown }, 'selectPolicy').mockReturnValue(RustAdapterPolicy);
+      dependencies.mockProxyManager.setDapRequestHandler(async (command) => {
+        if (command === 'evaluate') {
+          return {
+            success: true,
+            body: { result: '{"runtimes": [{"flavor": "current_thread", "tasks": [{"id": 1, "state": 4}]}]}' }
+          };
+        }
+        return { success: true };
+      });
+
+      const result = await sessionManager.listAsyncTasks(session.id);
+
+      expect(dependencies.mockProxyManager.dapRequestCalls).toContainEqual({
+        command: 'evaluate',
+        args: { expression: 'tokio-tasks', context: 'repl' }
+      });
+      expect(result.success).toBe(true);
+      expect(result.runtimes[0].tasks[0]).toMatchObject({ id: 1, state: 'notified', awaitChain: [] });
+    });
+
+    it('should report languages without async task support', async () => {
+      const session = await createPausedSession();
+
+      const result = await sessionManager.listAsyncTasks(session.id);
+
+      expect(result).toEqual({ success: false, runtimes: [], error: 'Async task inspection is not supported for mock' });
+      expect(dependencies.mockProxyManager.dapRequestCalls).toEqual([]);
+    });
+  });
+
+  describe('Instruction Breakpoints', () => {
+    it('should normalize the address and send every instruction breakpoint', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command, args) => {
+        if (command === 'setInstructionBreakpoints') {
+          const requested = (args as { breakpoints: unknown[] }).breakpoints;
+          return {
+            success: true,
+            body: {
+              breakpoints: requested.map(() => ({ verified: true, source: { path: 'test.py' }, line: 3 }))
+            }
+          };
+        }
+        return { success: true };
+      });
+
+      await sessionManager.setInstructionBreakpoint(session.id, '0x1000');
+      const result = await sessionManager.setInstructionBreakpoint(session.id, '4100', { condition: 'x > 1' });
+
+      expect(result.success).toBe(true);
+      expect(result.breakpoint).toMatchObject({
+        address: '0x1004',
+        verified: true,
+        location: { file: 'test.py', line: 3, address: '0x1004' }
+      });
+      expect(dependencies.mockProxyManager.dapRequestCalls.at(-1)).toEqual({
+        command: 'setInstructionBreakpoints',
+        args: {
+          breakpoints: [
+            { instructionReference: '0x1000', condition: undefined, hitCondition: undefined },
+            { instructionReference: '0x1004', condition: 'x > 1', hitCondition: undefined }
+          ]
+        }
+      });
+    });
+
+    it('should keep the previous conditions of an existing breakpoint when the update fails', async () => {
+      const session = await createPausedSession();
+      let fail = false;
+      dependencies.mockProxyManager.setDapRequestHandler(async (command, args) => {
+        if (command === 'setInstructionBreakpoints') {
+          if (fail) {
+            throw new Error('adapter rejected the request');
+          }
+          const requested = (args as { breakpoints: unknown[] }).breakpoints;
+          return { success: true, body: { breakpoints: requested.map(() => ({ verified: true })) } };
+        }
+        return { success: true };
+      });
+
+      await sessionManager.setInstructionBreakpoint(session.id, '0x1000', { condition: 'x > 1' });
+      fail = true;
+      const failed = await sessionManager.setInstructionBreakpoint(session.id, '0x1000', { condition: 'y' });
+      const failedNew = await sessionManager.setInstructionBreakpoint(session.id, '0x2000');
+      fail = false;
+      await sessionManager.setInstructionBreakpoint(session.id, '0x3000');
+
+      expect(failed).toMatchObject({ success: false, error: 'adapter rejected the request' });
+      expect(failedNew.success).toBe(false);
+      expect(dependencies.mockProxyManager.dapRequestCalls.at(-1)).toEqual({
+        command: 'setInstructionBreakpoints',
+        args: {
+          breakpoints: [
+            { instructionReference: '0x1000', condition: 'x > 1', hitCondition: undefined },
+            { instructionReference: '0x3000', condition: undefined, hitCondition: undefined }
+          ]
+        }
+      });
+    });
+
+    it('should reject an invalid address', async () => {
+      const session = await createPausedSession();
+
+      await expect(sessionManager.setInstructionBreakpoint(session.id, 'main+4')).rejects.toThrow('Invalid instruction address');
+    });
+  });
+
+  describe('Set Variable', () => {
+    it('should set the variable and return its new value and children', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command, args) => {
+        if (command === 'variables') {
+          return args.variablesReference === 1001
+            ? { success: true, body: { variables: [{ name: 'point', value: '{x:1, y:2}', type: 'Point', variablesReference: 2001 }] } }
+            : { success: true, body: { variables: [{ name: 'x', value: '5', type: 'i32', variablesReference: 0 }] } };
+        }
+        if (command === 'setVariable') {
+          return { success: true, body: { value: '{x:5, y:2}', type: 'Point', variablesReference: 2001 } };
+        }
+        return { success: true };
+      });
+
+      const result = await sessionManager.setVariable(session.id, 1001, 'point', '{x:5, y:2}');
+
+      expect(dependencies.mockProxyManager.dapRequestCalls).toContainEqual({
+        command: 'setVariable',
+        args: { variablesReference: 1001, name: 'point', value: '{x:5, y:2}' }
+      });
+      expect(result).toMatchObject({
+        success: true,
+        previousValue: '{x:1, y:2}',
+        value: '{x:5, y:2}',
+        type: 'Point',
+        variablesReference: 2001
+      });
+      expect(result.children?.map(child => child.name)).toEqual(['x']);
+    });
+
+    it('should fail when the variable is not in the given scope', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async () => ({ success: true, body: { variables: [] } }));
+
+      const result = await sessionManager.setVariable(session.id, 1001, 'missing', '1');
+
+      expect(result.success).toBe(false);
+      expect(result.error).toBe("Variable 'missing' not found in variablesReference 1001");
+      expect(dependencies.mockProxyManager.dapRequestCalls.map(call => call.command)).not.toContain('setVariable');
+    });
+  });
+
+  describe('Memory Access', () => {
+    it('should read memory and decode the base64 payload', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command) => {
+        if (command === 'readMemory') {
+          return {
+            success: true,
+            body: { address: '0x5008', data: Buffer.from([1, 2, 3]).toString('base64'), unreadableBytes: 5 }
+          };
+        }
+        return { success: true };
+      });
+
+      const result = await sessionManager.readMemory(session.id, '0x5000', 8, 8);
+
+      expect(dependencies.mockProxyManager.dapRequestCalls).toContainEqual({
+        command: 'readMemory',
+        args: { memoryReference: '0x5000', offset: 8, count: 8 }
+      });
+      expect(result).toEqual({
+        success: true,
+        address: '0x5008',
+        bytes: Buffer.from([1, 2, 3]),
+        unreadableBytes: 5
+      });
+    });
+
+    it('should write memory as base64', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async () => ({ success: true, body: {} }));
+
+      const result = await sessionManager.writeMemory(session.id, '0x5000', Buffer.from([0x2a, 0, 0, 0]));
+
+      expect(dependencies.mockProxyManager.dapRequestCalls).toContainEqual({
+        command: 'writeMemory',
+        args: { memoryReference: '0x5000', offset: 0, allowPartial: false, data: 'KgAAAA==' }
+      });
+      expect(result).toEqual({ success: true, bytesWritten: 4 });
+    });
+
+    it('should refuse memory access while running', async () => {
+      const session = await sessionManager.createSession({
+        language: DebugLanguage.MOCK,
+        executablePath: 'python'
+      });
+      await sessionManager.startDebugging(session.id, 'test.py', [], { stopOnEntry: false });
+      await vi.runAllTimersAsync();
+
+      const result = await sessionManager.readMemory(session.id, '0x5000', 8);
+
+      expect(result).toEqual({ success: false, error: 'Not paused' });
+    });
+  });
+
+  describe('Hit Conditions', () => {
+    it('should send hitCondition and report it as verified', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command) => {
+        if (command === 'setBreakpoints') {
+          return { success: true, body: { breakpoints: [{ verified: true, line: 8 }] } };
+        }
+        return { success: true };
+      });
+
+      const bp = await sessionManager.setBreakpoint(session.id, 'test.py', 8, undefined, { hitCondition: ' >= 3 ' });
+
+      expect(bp).toMatchObject({ hitCondition: '>= 3', hitConditionVerified: true, hitCount: 0 });
+      expect(dependencies.mockProxyManager.dapRequestCalls).toContainEqual({
+        command: 'setBreakpoints',
+        args: { source: { path: 'test.py' }, breakpoints: [{ line: 8, condition: undefined, hitCondition: '>= 3', logMessage: undefined }] }
+      });
+    });
+
+    it('should report a hit condition the adapter rejected', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command) => {
+        if (command === 'setBreakpoints') {
+          return { success: true, body: { breakpoints: [{ verified: false, message: 'Invalid hit condition: >= x' }] } };
+        }
+        return { success: true };
+      });
+
+      const bp = await sessionManager.setBreakpoint(session.id, 'test.py', 8, undefined, { hitCondition: '>= 3' });
+
+      expect(bp).toMatchObject({ hitConditionVerified: false, hitConditionError: 'Invalid hit condition: >= x' });
+    });
+
+    it('should leave the hit condition unconfirmed while the breakpoint is pending', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command) => {
+        if (command === 'setBreakpoints') {
+          return { success: true, body: { breakpoints: [{ verified: false }] } };
+        }
+        return { success: true };
+      });
+
+      const bp = await sessionManager.setBreakpoint(session.id, 'test.py', 8, undefined, { hitCondition: '>= 3' });
+
+      expect(bp.hitConditionVerified).toBeUndefined();
+      expect(bp.hitConditionError).toBeUndefined();
+    });
+
+    it('should reject an empty hitCondition', async () => {
+      const session = await createPausedSession();
+
+      await expect(
+        sessionManager.setBreakpoint(session.id, 'test.py', 8, undefined, { hitCondition: '  ' })
+      ).rejects.toThrow('hitCondition must not be empty');
+    });
+
+    it('should return the breakpoints at the stop location', async () => {
+      const session = await createPausedSession();
+      const bp = await sessionManager.setBreakpoint(session.id, '/work/test.py', 8, undefined, { hitCondition: '== 5' });
+      await sessionManager.setBreakpoint(session.id, '/work/test.py', 20);
+
+      expect(await sessionManager.getHitBreakpoints(session.id, { file: '/work/test.py', line: 8 })).toEqual([]);
+
+      dependencies.mockProxyManager.simulateStopped(1, 'breakpoint');
+      const hits = await sessionManager.getHitBreakpoints(session.id, { file: 'test.py', line: 8 });
+
+      expect(hits.map(h => h.id)).toEqual([bp.id]);
+    });
+  });
+
+  describe('Logpoints', () => {
+    it('should send logMessage with the breakpoint and capture output events', async () => {
+      const session = await createPausedSession();
+
+      const bp = await sessionManager.setBreakpoint(session.id, 'test.py', 12, undefined, { logMessage: 'i={i}' });
+
+      expect(bp.logMessage).toBe('i={i}');
+      expect(dependencies.mockProxyManager.dapRequestCalls).toContainEqual({
+        command: 'setBreakpoints',
+        args: { source: { path: 'test.py' }, breakpoints: [{ line: 12, condition: undefined, logMessage: 'i={i}' }] }
+      });
+
+      dependencies.mockProxyManager.simulateEvent('dap-event', 'output', { category: 'console', output: 'i=1\n' });
+      dependencies.mockProxyManager.simulateEvent('dap-event', 'output', { category: 'stdout', output: 'hello\n' });
+      dependencies.mockProxyManager.simulateEvent('dap-event', 'output', { category: 'console', output: 'i=2\n' });
+
+      const all = sessionManager.getOutput(session.id);
+      expect(all.entries.map(e => e.output)).toEqual(['i=1\n', 'hello\n', 'i=2\n']);
+
+      const newer = sessionManager.getOutput(session.id, { sinceSeq: 1, category: 'console' });
+      expect(newer.entries).toHaveLength(1);
+      expect(newer.entries[0]).toMatchObject({ seq: 3, output: 'i=2\n' });
+      expect(newer.lastSeq).toBe(3);
+    });
+
+    it('should reject logpoints when the adapter reported them unsupported', async () => {
+      const session = await createPausedSession();
+      sessionManager.getSession(session.id)!.unsupportedFeatures = {
+        logPoints: 'logPoints requires CodeLLDB 1.6.0+ (found 1.5.3)'
+      };
+
+      await expect(
+        sessionManager.setBreakpoint(session.id, 'test.py', 12, undefined, { logMessage: 'i={i}' })
+      ).rejects.toThrow('1.6.0');
+      expect(dependencies.mockProxyManager.dapRequestCalls).toHaveLength(0);
+    });
+  });
+
+  describe('Function Breakpoints', () => {
+    it('should queue function breakpoints and pass them to the proxy at launch', async () => {
+      const session = await sessionManager.createSession({
+        language: DebugLanguage.MOCK,
+        executablePath: 'python'
+      });
+
+      const result = await sessionManager.setFunctionBreakpoint(session.id, 'calculate_sum', 'n > 2');
+      expect(result).toMatchObject({ success: true, applied: false, breakpoint: { name: 'calculate_sum', verified: false } });
+
+      await sessionManager.startDebugging(session.id, 'test.py');
+      await vi.runAllTimersAsync();
+
+      expect(dependencies.mockProxyManager.startCalls[0].initialFunctionBreakpoints).toEqual([
+        { name: 'calculate_sum', condition: 'n > 2' }
+      ]);
+    });
+
+    it('should send all function breakpoints and report resolved locations', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command, args) => {
+        if (command === 'setFunctionBreakpoints') {
+          const { breakpoints } = args as { breakpoints: unknown[] };
+          return {
+            success: true,
+            body: {
+              breakpoints: breakpoints.map((_, index) => ({
+                id: index + 1,
+                verified: true,
+                source: { path: 'test.py' },
+                line: 10 + index
+              }))
+            }
+          };
+        }
+        return { success: true };
+      });
+
+      await sessionManager.setFunctionBreakpoint(session.id, 'main');
+      const result = await sessionManager.setFunctionBreakpoint(session.id, 'calculate_sum');
+
+      expect(result.applied).toBe(true);
+      expect(result.breakpoint!.verified).toBe(true);
+      expect(result.breakpoint!.locations).toEqual([{ file: 'test.py', line: 11, column: undefined, address: undefined, verified: true }]);
+      expect(dependencies.mockProxyManager.dapRequestCalls.at(-1)).toEqual({
+        command: 'setFunctionBreakpoints',
+        args: { breakpoints: [{ name: 'main', condition: undefined }, { name: 'calculate_sum', condition: undefined }] }
+      });
+    });
+
+    it('should remove a function breakpoint by re-sending the set without it', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command, args) => {
+        if (command === 'setFunctionBreakpoints') {
+          const { breakpoints } = args as { breakpoints: unknown[] };
+          return { success: true, body: { breakpoints: breakpoints.map(() => ({ verified: true })) } };
+        }
+        return { success: true };
+      });
+
+      const main = await sessionManager.setFunctionBreakpoint(session.id, 'main');
+      await sessionManager.setFunctionBreakpoint(session.id, 'calculate_sum');
+      const removed = await sessionManager.removeFunctionBreakpoint(session.id, main.breakpoint!.id);
+
+      expect(removed).toMatchObject({ success: true, applied: true, breakpoint: { name: 'main' } });
+      expect(dependencies.mockProxyManager.dapRequestCalls.at(-1)).toEqual({
+        command: 'setFunctionBreakpoints',
+        args: { breakpoints: [{ name: 'calculate_sum', condition: undefined }] }
+      });
+      await expect(sessionManager.removeFunctionBreakpoint(session.id, main.breakpoint!.id)).resolves.toMatchObject({
+        success: false,
+        error: `Function breakpoint not found: ${main.breakpoint!.id}`
+      });
+    });
+
+    it('should take verified state and locations from the launch results', async () => {
+      const session = await sessionManager.createSession({
+        language: DebugLanguage.MOCK,
+        executablePath: 'python'
+      });
+      const queued = await sessionManager.setFunctionBreakpoint(session.id, 'calculate_sum');
+
+      await sessionManager.startDebugging(session.id, 'test.py');
+      await vi.runAllTimersAsync();
+      dependencies.mockProxyManager.emit('function-breakpoints-set', [
+        { name: 'calculate_sum', breakpoint: { verified: true, source: { path: 'test.py' }, line: 10 } }
+      ]);
+      await vi.runAllTimersAsync();
+
+      expect(sessionManager.getSession(session.id)!.functionBreakpoints!.get(queued.breakpoint!.id)).toMatchObject({
+        verified: true,
+        locations: [{ file: 'test.py', line: 10, column: undefined, address: undefined, verified: true }]
+      });
+    });
+
+    it('should restore the previous condition when the update fails', async () => {
+      const session = await createPausedSession();
+      let fail = false;
+      dependencies.mockProxyManager.setDapRequestHandler(async (command, args) => {
+        if (command === 'setFunctionBreakpoints') {
+          if (fail) {
+            throw new Error('adapter busy');
+          }
+          const { breakpoints } = args as { breakpoints: unknown[] };
+          return { success: true, body: { breakpoints: breakpoints.map(() => ({ verified: true })) } };
+        }
+        return { success: true };
+      });
+      const existing = await sessionManager.setFunctionBreakpoint(session.id, 'main', 'x > 1');
+      fail = true;
+
+      const updated = await sessionManager.setFunctionBreakpoint(session.id, 'main', 'x > 2');
+      const added = await sessionManager.setFunctionBreakpoint(session.id, 'calculate_sum');
+
+      expect(updated).toMatchObject({ success: false, error: 'adapter busy' });
+      expect(added).toMatchObject({ success: false, error: 'adapter busy' });
+      const stored = Array.from(sessionManager.getSession(session.id)!.functionBreakpoints!.values());
+      expect(stored).toEqual([expect.objectContaining({ id: existing.breakpoint!.id, condition: 'x > 1' })]);
+    });
+
+    it('should not store function breakpoints the adapter does not support', async () => {
+      const session = await createPausedSession();
+      sessionManager.getSession(session.id)!.unsupportedFeatures = {
+        functionBreakpoints: 'functionBreakpoints are not supported by this adapter'
+      };
+
+      const result = await sessionManager.setFunctionBreakpoint(session.id, 'main');
+
+      expect(result).toEqual({ success: false, applied: false, error: 'functionBreakpoints are not supported by this adapter' });
+      expect(sessionManager.getSession(session.id)!.functionBreakpoints?.size ?? 0).toBe(0);
+      expect(dependencies.mockProxyManager.dapRequestCalls).toHaveLength(0);
+    });
+
+    it('should drop a queued function breakpoint before launch', async () => {
+      const session = await sessionManager.createSession({
+        language: DebugLanguage.MOCK,
+        executablePath: 'python'
+      });
+
+      const queued = await sessionManager.setFunctionBreakpoint(session.id, 'calculate_sum');
+      const removed = await sessionManager.removeFunctionBreakpoint(session.id, queued.breakpoint!.id);
+      expect(removed).toMatchObject({ success: true, applied: false });
+
+      await sessionManager.startDebugging(session.id, 'test.py');
+      await vi.runAllTimersAsync();
+
+      expect(dependencies.mockProxyManager.startCalls[0].initialFunctionBreakpoints).toBeUndefined();
+    });
+  });
+
+  describe('Data Breakpoints', () => {
+    it('should watch a variable and re-send the full set on removal', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command, args) => {
+        if (command === 'dataBreakpointInfo') {
+          return {
+            success: true,
+            body: { dataId: '0x7ffc1000/8', description: 'loop_total (8 bytes)', accessTypes: ['write', 'read', 'readWrite'] }
+          };
+        }
+        if (command === 'setDataBreakpoints') {
+          const { breakpoints } = args as { breakpoints: unknown[] };
+          return { success: true, body: { breakpoints: breakpoints.map(() => ({ verified: true })) } };
+        }
+        return { success: true };
+      });
+
+      const result = await sessionManager.setDataBreakpoint(session.id, 'loop_total', 'write', { variablesReference: 5 });
+
+      expect(result.success).toBe(true);
+      expect(result.accessTypes).toEqual(['write', 'read', 'readWrite']);
+      expect(result.breakpoint).toMatchObject({ name: 'loop_total', accessType: 'write', verified: true });
+      expect(dependencies.mockProxyManager.dapRequestCalls).toContainEqual({
+        command: 'dataBreakpointInfo',
+        args: { variablesReference: 5, name: 'loop_total', frameId: undefined }
+      });
+      expect(dependencies.mockProxyManager.dapRequestCalls).toContainEqual({
+        command: 'setDataBreakpoints',
+        args: { breakpoints: [{ dataId: '0x7ffc1000/8', accessType: 'write', condition: undefined }] }
+      });
+
+      const removed = await sessionManager.removeDataBreakpoint(session.id, result.breakpoint!.id);
+      expect(removed.success).toBe(true);
+      expect(dependencies.mockProxyManager.dapRequestCalls.at(-1)).toEqual({
+        command: 'setDataBreakpoints',
+        args: { breakpoints: [] }
+      });
+    });
+
+    it('should not keep a watch the adapter did not verify', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command, args) => {
+        if (command === 'dataBreakpointInfo') {
+          return { success: true, body: { dataId: '0x1/64', description: 'buffer', accessTypes: ['write'] } };
+        }
+        if (command === 'setDataBreakpoints') {
+          const { breakpoints } = args as { breakpoints: unknown[] };
+          return { success: true, body: { breakpoints: breakpoints.map(() => ({ verified: false, message: 'Too large' })) } };
+        }
+        return { success: true };
+      });
+
+      const result = await sessionManager.setDataBreakpoint(session.id, 'buffer', 'write', { variablesReference: 5 });
+
+      expect(result).toMatchObject({ success: false, error: 'Too large' });
+      expect(sessionManager.getSession(session.id)!.dataBreakpoints?.size).toBe(0);
+    });
+
+    it('should restore the previous watches when the update fails', async () => {
+      const session = await createPausedSession();
+      let fail = false;
+      dependencies.mockProxyManager.setDapRequestHandler(async (command, args) => {
+        if (command === 'dataBreakpointInfo') {
+          return { success: true, body: { dataId: '0x7ffc1000/8', description: 'total', accessTypes: ['write', 'read'] } };
+        }
+        if (command === 'setDataBreakpoints') {
+          if (fail) {
+            throw new Error('adapter busy');
+          }
+          const { breakpoints } = args as { breakpoints: unknown[] };
+          return { success: true, body: { breakpoints: breakpoints.map(() => ({ verified: true })) } };
+        }
+        return { success: true };
+      });
+      const first = await sessionManager.setDataBreakpoint(session.id, 'total', 'write', { variablesReference: 5 });
+      fail = true;
+
+      const result = await sessionManager.setDataBreakpoint(session.id, 'total', 'read', { variablesReference: 5 });
+
+      expect(result).toMatchObject({ success: false, error: 'adapter busy' });
+      const watches = Array.from(sessionManager.getSession(session.id)!.dataBreakpoints!.values());
+      expect(watches).toEqual([expect.objectContaining({ id: first.breakpoint!.id, accessType: 'write' })]);
+    });
+
+    it('should reject access types the adapter does not allow', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command) => {
+        if (command === 'dataBreakpointInfo') {
+          return { success: true, body: { dataId: '0x1/4', description: 'flag', accessTypes: ['write'] } };
+        }
+        return { success: true };
+      });
+
+      const result = await sessionManager.setDataBreakpoint(session.id, 'flag', 'read', { variablesReference: 5 });
+
+      expect(result.success).toBe(false);
+      expect(result.error).toContain("'read'");
+      expect(dependencies.mockProxyManager.dapRequestCalls.map(c => c.command)).not.toContain('setDataBreakpoints');
+    });
+
+    it('should report unsupported features recorded at launch', async () => {
+      const session = await createPausedSession();
+      sessionManager.getSession(session.id)!.unsupportedFeatures = {
+        dataBreakpoints: 'dataBreakpoints requires CodeLLDB 1.7.0+ (found 1.6.2)'
+      };
+
+      const result = await sessionManager.getDataBreakpointInfo(session.id, 'loop_total', 5);
+
+      expect(result).toEqual({ success: false, error: 'dataBreakpoints requires CodeLLDB 1.7.0+ (found 1.6.2)' });
+      expect(dependencies.mockProxyManager.dapRequestCalls).toHaveLength(0);
+    });
+  });
+
   describe('Step Operations', () => {
     it('should handle step over correctly', async () => {
       const session = await createPausedSession();
@@ -267,6 +980,105 @@ describe('SessionManager - DAP Operations', () => {
       });
     });
 
+    it('should step by instruction and report the instruction at the new pointer', async () => {
+      const session = await createPausedSession();
+      // The mock adapter does not advertise supportsSteppingGranularity
+      sessionManager.getSession(session.id)!.unsupportedFeatures = {};
+      dependencies.mockProxyManager.setDapRequestHandler(async (command) => {
+        if (command === 'stepIn') {
+          process.nextTick(() => dependencies.mockProxyManager.simulateStopped(1, 'step'));
+          return { success: true };
+        }
+        if (command === 'stackTrace') {
+          return {
+            success: true,
+            body: {
+              stackFrames: [
+                { id: 7, name: 'main', source: { path: 'test.py' }, line: 3, column: 1, instructionPointerReference: '0x1004' }
+              ]
+            }
+          };
+        }
+        if (command === 'disassemble') {
+          return {
+            success: true,
+            body: { instructions: [{ address: '0x1004', instruction: 'mov rbp, rsp', line: 3, location: { path: 'test.py' } }] }
+          };
+        }
+        return { success: true };
+      });
+
+      const stepPromise = sessionManager.stepInto(session.id, { granularity: 'instruction' });
+      await vi.runAllTimersAsync();
+      const result = await stepPromise;
+
+      expect(result.success).toBe(true);
+      expect(dependencies.mockProxyManager.dapRequestCalls).toContainEqual({
+        command: 'stepIn',
+        args: { threadId: 1, granularity: 'instruction' }
+      });
+      expect(result.data).toMatchObject({
+        instructionPointer: '0x1004',
+        instruction: { address: '0x1004', instruction: 'mov rbp, rsp', isCurrent: true }
+      });
+    });
+
+    it('should refuse a granularity the adapter does not support', async () => {
+      const session = await createPausedSession();
+
+      const result = await sessionManager.stepOver(session.id, { granularity: 'instruction' });
+
+      expect(result.success).toBe(false);
+      expect(result.error).toContain('Cannot step by instruction');
+      expect(dependencies.mockProxyManager.dapRequestCalls.map(c => c.command)).not.toContain('next');
+    });
+
+    it('should list step-in targets for the top frame and step into the chosen one', async () => {
+      const session = await createPausedSession();
+      dependencies.mockProxyManager.setDapRequestHandler(async (command) => {
+        if (command === 'stackTrace') {
+          return { success: true, body: { stackFrames: [{ id: 7, name: 'main', source: { path: 'test.py' }, line: 3, column: 1 }] } };
+        }
+        if (command === 'stepInTargets') {
+          return {
+            success: true,
+            body: { targets: [{ id: 1, label: 'Iterator::map' }, { id: 2, label: 'calculate_sum', line: 3, column: 17 }] }
+          };
+        }
+        if (command === 'stepIn') {
+          process.nextTick(() => dependencies.mockProxyManager.simulateStopped(1, 'step'));
+        }
+        return { success: true };
+      });
+
+      const targets = await sessionManager.getStepInTargets(session.id);
+
+      expect(dependencies.mockProxyManager.dapRequestCalls).toContainEqual({ command: 'stepInTargets', args: { frameId: 7 } });
+      expect(targets.frameId).toBe(7);
+      expect(targets.targets.map(t => t.label)).toEqual(['Iterator::map', 'calculate_sum']);
+
+      const stepPromise = sessionManager.stepInto(session.id, { targetId: targets.targets[1].id });
+      await vi.runAllTimersAsync();
+      const result = await stepPromise;
+
+      expect(result.success).toBe(true);
+      expect(dependencies.mockProxyManager.dapRequestCalls).toContainEqual({
+        command: 'stepIn',
+        args: { threadId: 1, targetId: 2 }
+      });
+    });
+
+    it('should report step-in targets as unsupported when the adapter lacks them', async () => {
+      const session = await createPausedSession();
+      sessionManager.getSession(session.id)!.unsupportedFeatures = {
+        stepInTargetsRequest: 'stepInTargetsRequest is not supported'
+      };
+
+      const result = await sessionManager.getStepInTargets(session.id);
+
+      expect(result).toEqual({ success: false, targets: [], error: 'stepInTargetsRequest is not supported' });
+    });
+
     it('should reject step operations when not paused', async () => {
       const session = await sessionManager.createSession({
         language: DebugLanguage.MOCK,
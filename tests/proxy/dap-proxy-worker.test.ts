@@ -572,6 +572,41 @@ describe('DapProxyWorker', () => {
       expect(worker.getState()).toBe(ProxyState.CONNECTED);
     });
 
+    it('handleInitializedEvent should report the results of initial function breakpoints', async () => {
+      const resolved = { id: 1, verified: true, source: { path: '/src/main.rs' }, line: 12 };
+      const connectionStub = {
+        setBreakpoints: vi.fn().mockResolvedValue(undefined),
+        setFunctionBreakpoints: vi.fn().mockResolvedValue({ body: { breakpoints: [resolved] } }),
+        sendConfigurationDone: vi.fn().mockResolvedValue(undefined)
+      };
+
+      (worker as any).logger = mockLogger;
+      (worker as any).dapClient = mockDapClient;
+      (worker as any).connectionManager = connectionStub;
+      (worker as any).currentInitPayload = {
+        cmd: 'init',
+        sessionId: 'rust-session',
+        executablePath: 'codelldb',
+        adapterHost: 'localhost',
+        adapterPort: 5678,
+        logDir: '/logs',
+        scriptPath: '/src/main.rs',
+        initialFunctionBreakpoints: [{ name: 'app::largest' }, { name: 'missing' }]
+      };
+
+      await (worker as any).handleInitializedEvent();
+
+      const statusCall = mockMessageSender.send.mock.calls.find(
+        ([message]) => message.type === 'status' && message.status === 'adapter_configured_and_launched'
+      );
+      expect(statusCall?.[0].data).toEqual({
+        functionBreakpoints: [
+          { name: 'app::largest', breakpoint: resolved },
+          { name: 'missing', breakpoint: undefined }
+        ]
+      });
+    });
+
     it('ensureInitialStop should pause when threads available', async () => {
       (worker as any).dapClient = mockDapClient;
       const sendRequestMock = mockDapClient.sendRequest as Mock;
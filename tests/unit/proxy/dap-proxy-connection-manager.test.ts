@@ -253,6 +253,7 @@ describe('DapConnectionManager', () => {
         linesStartAt1: true,
         columnsStartAt1: true,
         supportsVariableType: true,
+        supportsMemoryReferences: true,
         supportsRunInTerminalRequest: false,
         locale: 'en-US'
       });
@@ -6,7 +6,7 @@
 import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
 import path from 'path';
 import { SessionManagerOperations } from '../../src/session/session-manager-operations';
-import { SessionLifecycleState, SessionState } from '@debugmcp/shared';
+import { BuildFailedError, SessionLifecycleState, SessionState } from '@debugmcp/shared';
 import { DebugProtocol } from '@vscode/debugprotocol';
 import { 
   SessionNotFoundError,
@@ -278,6 +278,128 @@ describe('Session Manager Operations Coverage - Error Paths and Edge Cases', ()
       );
       expect(adapterStub.resolveExecutablePath).not.toHaveBeenCalled();
     });
+
+    it('rethrows build failures from transformLaunchConfig', async () => {
+      mockSession.language = 'rust';
+      const buildError = new BuildFailedError('Cargo build failed: 1 error: E0308 mismatched types', []);
+      const adapterStub = {
+        transformLaunchConfig: vi.fn().mockRejectedValue(buildError),
+        resolveExecutablePath: vi.fn(),
+        buildAdapterCommand: vi.fn()
+      };
+      mockDependencies.adapterRegistry.create.mockResolvedValue(adapterStub);
+
+      await expect((operations as any).startProxyManager(mockSession, 'src/main.rs')).rejects.toBe(buildError);
+      expect(adapterStub.resolveExecutablePath).not.toHaveBeenCalled();
+    });
+
+    it('passes launch preparation options to transformLaunchConfig', async () => {
+      mockSession.language = 'rust';
+      const buildError = new BuildFailedError('Cargo build cancelled', []);
+      const adapterStub = {
+        transformLaunchConfig: vi.fn().mockRejectedValue(buildError),
+        resolveExecutablePath: vi.fn(),
+        buildAdapterCommand: vi.fn()
+      };
+      mockDependencies.adapterRegistry.create.mockResolvedValue(adapterStub);
+      const launchOptions = { onProgress: vi.fn(), signal: new AbortController().signal };
+
+      await expect((operations as any).startProxyManager(
+        mockSession, 'src/main.rs', undefined, undefined, undefined, undefined, launchOptions
+      )).rejects.toBe(buildError);
+      expect(adapterStub.transformLaunchConfig).toHaveBeenCalledWith(expect.any(Object), launchOptions);
+    });
+  });
+
+  describe('startDebugging build failures', () => {
+    it('returns compiler diagnostics in the error payload', async () => {
+      mockSession.proxyManager = undefined as any;
+      mockSession.language = 'rust';
+      const diagnostics = [
+        {
+          level: 'error',
+          code: 'E0308',
+          message: 'mismatched types',
+          file: '/ws/app/src/main.rs',
+          line: 18,
+          column: 18,
+          endLine: 18,
+          endColumn: 24,
+          label: 'expected `u32`, found `&str`',
+          suggestions: []
+        }
+      ];
+      const startProxySpy = vi
+        .spyOn(operations as any, 'startProxyManager')
+        .mockRejectedValue(new BuildFailedError(
+          'Cargo build failed: 1 error: E0308 mismatched types at /ws/app/src/main.rs:18:18',
+          diagnostics,
+          'error[E0308]: mismatched types'
+        ));
+
+      try {
+        const result = await operations.startDebugging('test-session', '/ws/app/src/main.rs');
+
+        expect(result.success).toBe(false);
+        expect(result.error).toBe('Cargo build failed: 1 error: E0308 mismatched types at /ws/app/src/main.rs:18:18');
+        expect(result.errorType).toBe('BuildFailedError');
+        expect(result.data).toEqual({
+          message: result.error,
+          diagnostics
+        });
+        expect(mockSession.state).toBe(SessionState.CREATED);
+      } finally {
+        startProxySpy.mockRestore();
+      }
+    });
+
+    it('keeps the build output when there are no diagnostics', async () => {
+      mockSession.proxyManager = undefined as any;
+      mockSession.language = 'rust';
+      const startProxySpy = vi
+        .spyOn(operations as any, 'startProxyManager')
+        .mockRejectedValue(new BuildFailedError(
+          'Cargo build failed: error: linking with `cc` failed',
+          [],
+          'error: linking with `cc` failed: exit status: 1'
+        ));
+
+      try {
+        const result = await operations.startDebugging('test-session', '/ws/app/src/main.rs');
+        expect(result.data).toEqual(expect.objectContaining({
+          diagnostics: [],
+          output: 'error: linking with `cc` failed: exit status: 1'
+        }));
+      } finally {
+        startProxySpy.mockRestore();
+      }
+    });
+
+    it('recognizes build failures from another copy of the shared package by code', async () => {
+      mockSession.proxyManager = undefined as any;
+      mockSession.language = 'rust';
+      // Not an instance of this module's BuildFailedError
+      const foreignError = Object.assign(new Error('Cargo build failed: error: linking with `cc` failed'), {
+        code: 'BUILD_FAILED',
+        diagnostics: [],
+        output: 'error: linking with `cc` failed: exit status: 1'
+      });
+      const startProxySpy = vi
+        .spyOn(operations as any, 'startProxyManager')
+        .mockRejectedValue(foreignError);
+
+      try {
+        const result = await operations.startDebugging('test-session', '/ws/app/src/main.rs');
+        expect(result.data).toEqual({
+          message: 'Cargo build failed: error: linking with `cc` failed',
+          diagnostics: [],
+          output: 'error: linking with `cc` failed: exit status: 1'
+        });
+        expect(mockSession.state).toBe(SessionState.CREATED);
+      } finally {
+        startProxySpy.mockRestore();
+      }
+    });
   });
 
   describe('startDebugging toolchain handling', () => {
@@ -546,6 +668,33 @@ describe('Session Manager Operations Coverage - Error Paths and Edge Cases', ()
       // Returns empty array on error
       expect(result).toEqual([]);
     });
+
+    it('should collapse async runtime frames for Rust when asyncStack is set', async () => {
+      mockSession.state = SessionState.PAUSED;
+      mockSession.language = 'rust';
+      const runtime = '/cargo/registry/src/tokio-1.53.2/src/runtime';
+      mockProxyManager.sendDapRequest.mockResolvedValue({
+        body: {
+          stackFrames: [
+            { id: 1, name: 'async_example::fetch_data::{async_fn#0}', source: { path: '/ws/src/main.rs' }, line: 46, column: 5 },
+            { id: 2, name: 'async_example::main::{async_block#0}', source: { path: '/ws/src/main.rs' }, line: 17, column: 32 },
+            { id: 3, name: 'tokio::runtime::park::CachedParkThread::block_on', source: { path: `${runtime}/park.rs` }, line: 284, column: 31 },
+            { id: 4, name: 'tokio::runtime::runtime::Runtime::block_on', source: { path: `${runtime}/runtime.rs` }, line: 343, column: 18 },
+            { id: 5, name: 'async_example::main', source: { path: '/ws/src/main.rs' }, line: 40, column: 40 }
+          ]
+        }
+      });
+
+      const result = await operations.getStackTrace('test-session', 1, false, true);
+
+      expect(result.map(frame => frame.name)).toEqual([
+        'async_example::fetch_data::{async_fn#0}',
+        'async_example::main::{async_block#0}',
+        '[block_on: 3 frames]'
+      ]);
+      expect(result[0].asyncBody).toEqual({ kind: 'fn', function: 'async_example::fetch_data' });
+      expect(result[2]).toMatchObject({ id: -1, collapsedFrames: 3 });
+    });
   });
 
   describe('Get Scopes Error Scenarios', () => {
@@ -876,6 +1025,45 @@ describe('Session Manager Operations Coverage - Error Paths and Edge Cases', ()
       expect(result.preview).not.toContain('__class__');
       expect(result.preview).not.toContain('__dict__');
     });
+
+    it('uses the Rust preview for std collections', async () => {
+      mockSession.state = SessionState.PAUSED;
+      mockSession.language = 'rust';
+
+      mockProxyManager.sendDapRequest.mockImplementation(
+        async (command: string, args: unknown) => {
+          if (command === 'stackTrace') {
+            return { body: { stackFrames: [{ id: 1 }] } };
+          }
+          if (command === 'evaluate') {
+            return {
+              body: {
+                result: 'size=6',
+                type: 'alloc::vec::Vec<i32, alloc::alloc::Global>',
+                variablesReference: 400,
+                indexedVariables: 6,
+              },
+            };
+          }
+          if (command === 'variables' && (args as { variablesReference: number }).variablesReference === 400) {
+            return {
+              body: {
+                variables: [1, 2, 3, 4, 5, 6].map(n => ({
+                  name: `[${n - 1}]`, value: String(n), type: 'i32', variablesReference: 0
+                })),
+              },
+            };
+          }
+          return {};
+        }
+      );
+
+      const result = await operations.evaluateExpression('test-session', 'numbers');
+
+      expect(result.success).toBe(true);
+      expect(result.result).toBe('size=6');
+      expect(result.preview).toBe('vec![1, 2, 3, … (6 items)]');
+    });
   });
 
   describe('Evaluate Expression Error Info', () => {
@@ -0,0 +1,31 @@
+import { describe, it, expect } from 'vitest';
+import { AdapterError, AdapterErrorCode, BuildFailedError } from '@debugmcp/shared';
+import { getBuildFailureData } from '../../../src/utils/build-failure.js';
+
+describe('getBuildFailureData', () => {
+  it('returns diagnostics and drops the output when there are any', () => {
+    const diagnostic = { level: 'error', code: 'E0308', message: 'mismatched types', suggestions: [] };
+    const error = new BuildFailedError('cargo build failed', [diagnostic], 'full output');
+
+    expect(getBuildFailureData(error)).toEqual({ message: 'cargo build failed', diagnostics: [diagnostic] });
+  });
+
+  it('keeps the output for failures without diagnostics', () => {
+    const error = new BuildFailedError('linking failed', [], 'error: linker `cc` not found');
+
+    expect(getBuildFailureData(error)).toEqual({
+      message: 'linking failed',
+      diagnostics: [],
+      output: 'error: linker `cc` not found'
+    });
+  });
+
+  it('matches build failures by code and ignores other errors', () => {
+    const foreign = Object.assign(new Error('build failed'), { code: AdapterErrorCode.BUILD_FAILED });
+
+    expect(getBuildFailureData(foreign)).toEqual({ message: 'build failed', diagnostics: [] });
+    expect(getBuildFailureData(new AdapterError('missing', AdapterErrorCode.EXECUTABLE_NOT_FOUND))).toBeUndefined();
+    expect(getBuildFailureData(new Error('boom'))).toBeUndefined();
+    expect(getBuildFailureData(undefined)).toBeUndefined();
+  });
+});
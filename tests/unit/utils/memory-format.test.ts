@@ -0,0 +1,83 @@
+import { describe, it, expect } from 'vitest';
+import {
+  formatHexDump,
+  interpretMemory,
+  normalizeAddress,
+  parseHexBytes
+} from '../../../src/utils/memory-format.js';
+
+describe('memory-format', () => {
+  it('normalizes hex and decimal addresses', () => {
+    expect(normalizeAddress('0x00007FFF0000A010')).toBe('0x7fff0000a010');
+    expect(normalizeAddress(' 4096 ')).toBe('0x1000');
+    expect(normalizeAddress('-16')).toBeUndefined();
+    expect(normalizeAddress('main+4')).toBeUndefined();
+  });
+
+  it('parses hex byte strings in common spellings', () => {
+    expect(parseHexBytes('de ad be ef')).toEqual(Buffer.from([0xde, 0xad, 0xbe, 0xef]));
+    expect(parseHexBytes('0x2a,0x00')).toEqual(Buffer.from([0x2a, 0x00]));
+    expect(parseHexBytes('abc')).toBeUndefined();
+    expect(parseHexBytes('zz')).toBeUndefined();
+    expect(parseHexBytes('')).toBeUndefined();
+  });
+
+  it('formats a hex dump with addresses and ASCII', () => {
+    const bytes = Buffer.concat([Buffer.from('Hello, world!\n', 'latin1'), Buffer.from([0, 1, 0x41])]);
+
+    const lines = formatHexDump(bytes, '0x1000');
+
+    expect(lines).toEqual([
+      '0x1000  48 65 6c 6c 6f 2c 20 77 6f 72 6c 64 21 0a 00 01  |Hello, world!...|',
+      '0x1010  41                                               |A|'
+    ]);
+  });
+
+  it('interprets bytes as little-endian typed values', () => {
+    const bytes = Buffer.alloc(20);
+    bytes.writeUInt32LE(42, 0);
+    bytes.writeUInt32LE(7, 4);
+    bytes.writeDoubleLE(1.5, 8);
+
+    const result = interpretMemory(bytes);
+
+    expect(result.u8).toHaveLength(20);
+    expect(result.u32).toEqual([42, 7, 0, 0x3ff80000, 0]);
+    expect(result.usize).toEqual([(7n * 2n ** 32n + 42n).toString(), '4609434218613702656']);
+    expect(result.f64[1]).toBe(1.5);
+  });
+
+  it('falls back to offsets when the address is not numeric', () => {
+    const lines = formatHexDump(Buffer.from([1, 2]), 'main+4');
+
+    expect(lines).toEqual([`0x0  01 02${' '.repeat(42)}  |..|`]);
+  });
+
+  it('reads usize with the target pointer width', () => {
+    const bytes = Buffer.alloc(8);
+    bytes.writeUInt32LE(42, 0);
+    bytes.writeUInt32LE(7, 4);
+
+    expect(interpretMemory(bytes, 'i686').usize).toEqual(['42', '7']);
+    expect(interpretMemory(bytes, 'armv7').usize).toEqual(['42', '7']);
+    expect(interpretMemory(bytes, 'x86_64').usize).toEqual([(7n * 2n ** 32n + 42n).toString()]);
+  });
+
+  it('reads big-endian targets most significant byte first', () => {
+    const bytes = Buffer.alloc(8);
+    bytes.writeUInt32BE(42, 0);
+    bytes.writeUInt32BE(7, 4);
+
+    const result = interpretMemory(bytes, 'powerpc64');
+
+    expect(result.u32).toEqual([42, 7]);
+    expect(result.usize).toEqual([(42n * 2n ** 32n + 7n).toString()]);
+  });
+
+  it('reports non-finite floats as strings', () => {
+    const bytes = Buffer.alloc(8);
+    bytes.writeDoubleLE(Number.NaN, 0);
+
+    expect(interpretMemory(bytes).f64).toEqual(['NaN']);
+  });
+});